@@ -199,6 +199,10 @@ pub enum FormatTrait {
     LowerExp,
     /// `{:E}`
     UpperExp,
+    /// `{:a}`
+    LowerHexFloat,
+    /// `{:A}`
+    UpperHexFloat,
     /// `{:o}`
     Octal,
     /// `{:p}`
@@ -332,15 +332,17 @@ fn filtered_float_lit(
     base: u32,
 ) -> Result<LitKind, LitError> {
     debug!("filtered_float_lit: {:?}, {:?}, {:?}", symbol, suffix, base);
-    if base != 10 {
+    if base != 10 && base != 16 {
         return Err(LitError::NonDecimalFloat(base));
     }
     Ok(match suffix {
         Some(suf) => LitKind::Float(
             symbol,
             ast::LitFloatType::Suffixed(match suf {
+                sym::f16 => ast::FloatTy::F16,
                 sym::f32 => ast::FloatTy::F32,
                 sym::f64 => ast::FloatTy::F64,
+                sym::f128 => ast::FloatTy::F128,
                 _ => return Err(LitError::InvalidFloatSuffix),
             }),
         ),
@@ -350,7 +352,13 @@ fn filtered_float_lit(
 
 fn float_lit(symbol: Symbol, suffix: Option<Symbol>) -> Result<LitKind, LitError> {
     debug!("float_lit: {:?}, {:?}", symbol, suffix);
-    filtered_float_lit(strip_underscores(symbol), suffix, 10)
+    let s = symbol.as_str();
+    let base = if s.starts_with("0x") { 16 } else { 10 };
+    // Hexadecimal floats are only supported with a binary exponent, like `0x1.8p3`.
+    if base == 16 && !s.contains(['p', 'P']) {
+        return Err(LitError::NonDecimalFloat(base));
+    }
+    filtered_float_lit(strip_underscores(symbol), suffix, base)
 }
 
 fn integer_lit(symbol: Symbol, suffix: Option<Symbol>) -> Result<LitKind, LitError> {
@@ -207,6 +207,8 @@ fn make_argument<'hir>(
             Format(Debug) => sym::new_debug,
             Format(LowerExp) => sym::new_lower_exp,
             Format(UpperExp) => sym::new_upper_exp,
+            Format(LowerHexFloat) => sym::new_lower_hex_float,
+            Format(UpperHexFloat) => sym::new_upper_hex_float,
             Format(Octal) => sym::new_octal,
             Format(Pointer) => sym::new_pointer,
             Format(Binary) => sym::new_binary,
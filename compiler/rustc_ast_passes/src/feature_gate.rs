@@ -1,6 +1,6 @@
 use rustc_ast as ast;
 use rustc_ast::visit::{self, AssocCtxt, FnCtxt, FnKind, Visitor};
-use rustc_ast::{attr, AssocConstraint, AssocConstraintKind, NodeId};
+use rustc_ast::{attr, token, AssocConstraint, AssocConstraintKind, NodeId};
 use rustc_ast::{PatKind, RangeEnd};
 use rustc_feature::{AttributeGate, BuiltinAttribute, Features, GateIssue, BUILTIN_ATTRIBUTE_MAP};
 use rustc_session::parse::{feature_err, feature_err_issue, feature_warn};
@@ -375,6 +375,15 @@ fn visit_expr(&mut self, e: &'a ast::Expr) {
             ast::ExprKind::TryBlock(_) => {
                 gate!(&self, try_blocks, e.span, "`try` expression is experimental");
             }
+            ast::ExprKind::Lit(token::Lit {
+                kind: token::LitKind::Float | token::LitKind::Integer,
+                suffix,
+                ..
+            }) => match suffix {
+                Some(sym::f16) => gate!(&self, f16, e.span, "the type `f16` is unstable"),
+                Some(sym::f128) => gate!(&self, f128, e.span, "the type `f128` is unstable"),
+                _ => {}
+            },
             _ => {}
         }
         visit::walk_expr(self, e)
@@ -516,6 +525,7 @@ macro_rules! gate_all {
         };
     }
     gate_all!(c_str_literals, "`c\"..\"` literals are experimental");
+    gate_all!(hex_float_literals, "hexadecimal float literals are experimental");
     gate_all!(
         if_let_guard,
         "`if let` guards are experimental",
@@ -752,6 +752,8 @@ fn reconstruct_format_args_template_string(pieces: &[FormatArgsPiece]) -> String
                     FormatTrait::Debug => "?",
                     FormatTrait::LowerExp => "e",
                     FormatTrait::UpperExp => "E",
+                    FormatTrait::LowerHexFloat => "a",
+                    FormatTrait::UpperHexFloat => "A",
                     FormatTrait::Octal => "o",
                     FormatTrait::Pointer => "p",
                     FormatTrait::Binary => "b",
@@ -232,6 +232,15 @@ builtin_macros_test_bad_fn = {$kind} functions cannot be used for tests
 
 builtin_macros_test_case_non_item = `#[test_case]` attribute is only allowed on items
 
+builtin_macros_test_fixture_custom_runner = `#[test_fixture]` is not supported with a custom `#![test_runner]`
+    .label = this fixture would never be run
+
+builtin_macros_test_fixture_non_fn = the `#[test_fixture]` attribute may only be used on a non-associated function
+
+builtin_macros_test_fixture_scope = `#[test_fixture(..)]` only accepts `global`
+
+builtin_macros_test_fixture_sig = functions used as test fixtures must have signature `fn() -> T`
+
 builtin_macros_test_runner_invalid = `test_runner` argument must be a path
 builtin_macros_test_runner_nargs = `#![test_runner(..)]` accepts exactly 1 argument
 
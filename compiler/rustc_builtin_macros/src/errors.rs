@@ -674,6 +674,27 @@ pub(crate) struct TestCaseNonItem {
     pub(crate) span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(builtin_macros_test_fixture_non_fn)]
+pub(crate) struct TestFixtureNonFn {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_test_fixture_scope)]
+pub(crate) struct TestFixtureScope {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_test_fixture_sig)]
+pub(crate) struct TestFixtureSig {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(builtin_macros_test_bad_fn)]
 pub(crate) struct TestBadFn {
@@ -846,6 +867,15 @@ pub(crate) struct TestRunnerNargs {
     pub(crate) span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(builtin_macros_test_fixture_custom_runner)]
+pub(crate) struct TestFixtureCustomRunner {
+    #[primary_span]
+    pub(crate) span: Span,
+    #[label]
+    pub(crate) fixture: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(builtin_macros_expected_register_class_or_explicit_register)]
 pub(crate) struct ExpectedRegisterClassOrExplicitRegister {
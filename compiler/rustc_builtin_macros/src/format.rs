@@ -12,8 +12,10 @@
     Applicability, DiagnosticBuilder, ErrorGuaranteed, MultiSpan, PResult, SingleLabelManySpans,
 };
 use rustc_expand::base::{self, *};
+use rustc_feature::GateIssue;
 use rustc_parse_format as parse;
-use rustc_span::symbol::{Ident, Symbol};
+use rustc_session::parse::feature_err_issue;
+use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::{BytePos, InnerSpan, Span};
 
 use rustc_lint_defs::builtin::NAMED_ARGUMENTS_USED_POSITIONALLY;
@@ -415,6 +417,8 @@ enum ArgRef<'a> {
                     "?" => FormatTrait::Debug,
                     "e" => FormatTrait::LowerExp,
                     "E" => FormatTrait::UpperExp,
+                    "a" => FormatTrait::LowerHexFloat,
+                    "A" => FormatTrait::UpperHexFloat,
                     "o" => FormatTrait::Octal,
                     "p" => FormatTrait::Pointer,
                     "b" => FormatTrait::Binary,
@@ -425,6 +429,18 @@ enum ArgRef<'a> {
                         FormatTrait::Display
                     }
                 };
+                if matches!(format_trait, FormatTrait::LowerHexFloat | FormatTrait::UpperHexFloat)
+                    && !ecx.ecfg.features.declared(sym::hex_float_fmt)
+                {
+                    feature_err_issue(
+                        &ecx.sess.parse_sess,
+                        sym::hex_float_fmt,
+                        span.unwrap_or(fmt_span),
+                        GateIssue::Library(None),
+                        "hexadecimal float formatting is experimental",
+                    )
+                    .emit();
+                }
 
                 let precision_span = format.precision_span.and_then(to_span);
                 let precision = match format.precision {
@@ -575,6 +591,8 @@ fn invalid_placeholder_type_error(
             ("?", "Debug"),
             ("e", "LowerExp"),
             ("E", "UpperExp"),
+            ("a", "LowerHexFloat"),
+            ("A", "UpperHexFloat"),
             ("o", "Octal"),
             ("p", "Pointer"),
             ("b", "Binary"),
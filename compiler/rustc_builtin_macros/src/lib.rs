@@ -113,6 +113,7 @@ pub fn register_builtin_macros(resolver: &mut dyn ResolverExpand) {
         global_allocator: global_allocator::expand,
         test: test::expand_test,
         test_case: test::expand_test_case,
+        test_fixture: test::expand_test_fixture,
     }
 
     register_derive! {
@@ -7,6 +7,8 @@
 use rustc_ast_pretty::pprust;
 use rustc_errors::Applicability;
 use rustc_expand::base::*;
+use rustc_feature::AttributeTemplate;
+use rustc_parse::validate_attr;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::{ErrorGuaranteed, FileNameDisplayPreference, Span};
 use std::iter;
@@ -94,6 +96,181 @@ pub fn expand_bench(
     expand_test_or_bench(cx, attr_sp, item, true)
 }
 
+/// #[test_fixture] registers a function to be run around tests. The value it returns is
+/// dropped to tear the fixture down. By default the fixture is set up again for each test of the
+/// module it's defined in, including submodules; `#[test_fixture(global)]` sets it up once for
+/// the whole test binary.
+///
+/// Like tests, the function is turned into a const marked with an inert attribute,
+/// "rustc_test_fixture_marker", which the test harness generation collects.
+pub fn expand_test_fixture(
+    cx: &mut ExtCtxt<'_>,
+    attr_sp: Span,
+    meta_item: &ast::MetaItem,
+    item: Annotatable,
+) -> Vec<Annotatable> {
+    let template = AttributeTemplate { word: true, list: Some("global"), ..Default::default() };
+    validate_attr::check_builtin_meta_item(
+        &cx.sess.parse_sess,
+        meta_item,
+        ast::AttrStyle::Outer,
+        sym::test_fixture,
+        template,
+    );
+    warn_on_duplicate_attribute(cx, &item, sym::test_fixture);
+
+    // If we're not in test configuration, remove the annotated item
+    if !cx.ecfg.should_test {
+        return vec![];
+    }
+
+    let is_global = match meta_item.meta_item_list() {
+        None => false,
+        Some([single]) if single.is_word() && single.has_name(sym::global) => true,
+        Some(_) => {
+            cx.emit_err(errors::TestFixtureScope { span: meta_item.span });
+            false
+        }
+    };
+
+    let (item, is_stmt) = match item {
+        Annotatable::Item(i) => (i, false),
+        Annotatable::Stmt(stmt) if let ast::StmtKind::Item(_) = stmt.kind => {
+            if let ast::StmtKind::Item(i) = stmt.into_inner().kind {
+                (i, true)
+            } else {
+                unreachable!()
+            }
+        }
+        other => {
+            cx.emit_err(errors::TestFixtureNonFn { span: attr_sp });
+            return vec![other];
+        }
+    };
+
+    let ast::ItemKind::Fn(fn_) = &item.kind else {
+        cx.emit_err(errors::TestFixtureNonFn { span: attr_sp });
+        return if is_stmt {
+            vec![Annotatable::Stmt(P(cx.stmt_item(item.span, item)))]
+        } else {
+            vec![Annotatable::Item(item)]
+        };
+    };
+
+    if check_fixture_signature(cx, &item, fn_).is_err() {
+        return if is_stmt {
+            vec![Annotatable::Stmt(P(cx.stmt_item(item.span, item)))]
+        } else {
+            vec![Annotatable::Item(item)]
+        };
+    }
+
+    let sp = cx.with_def_site_ctxt(item.span);
+    let attr_sp = cx.with_def_site_ctxt(attr_sp);
+
+    let test_id = Ident::new(sym::test, attr_sp);
+
+    // creates test::$name
+    let test_path = |name| cx.path(sp, vec![test_id, Ident::from_str_and_span(name, sp)]);
+
+    // creates $name: $expr
+    let field = |name, expr| cx.field_imm(sp, Ident::from_str_and_span(name, sp), expr);
+
+    // skip the name of the root module
+    let mod_path = &cx.current_expansion.module.mod_path[1..];
+    let fixture_path_symbol = Symbol::intern(&item_path(mod_path, &item.ident));
+
+    let scope = if is_global {
+        // test::FixtureScope::Global
+        cx.expr_path(cx.path(
+            sp,
+            vec![
+                test_id,
+                Ident::from_str_and_span("FixtureScope", sp),
+                Ident::from_str_and_span("Global", sp),
+            ],
+        ))
+    } else {
+        // test::FixtureScope::Module("path::to::module")
+        let module_path = mod_path.iter().map(|x| x.to_string()).collect::<Vec<_>>().join("::");
+        cx.expr_call(
+            sp,
+            cx.expr_path(cx.path(
+                sp,
+                vec![
+                    test_id,
+                    Ident::from_str_and_span("FixtureScope", sp),
+                    Ident::from_str_and_span("Module", sp),
+                ],
+            )),
+            thin_vec![cx.expr_str(sp, Symbol::intern(&module_path))],
+        )
+    };
+
+    // || test::fixture_guard($fixture_fn())
+    let setup = cx.lambda0(
+        sp,
+        cx.expr_call(
+            sp,
+            cx.expr_path(test_path("fixture_guard")),
+            thin_vec![cx.expr_call(
+                sp,
+                cx.expr_path(cx.path(sp, vec![item.ident])),
+                ThinVec::new(),
+            )],
+        ),
+    );
+
+    let fixture_const = cx.item(
+        sp,
+        Ident::new(item.ident.name, sp),
+        thin_vec![
+            // #[cfg(test)]
+            cx.attr_nested_word(sym::cfg, sym::test, attr_sp),
+            // #[rustc_test_fixture_marker = "fixture_sort_key"]
+            cx.attr_name_value_str(sym::rustc_test_fixture_marker, fixture_path_symbol, attr_sp),
+        ],
+        // const $ident: test::TestFixture =
+        ast::ItemKind::Const(
+            ast::ConstItem {
+                defaultness: ast::Defaultness::Final,
+                generics: ast::Generics::default(),
+                ty: cx.ty(sp, ast::TyKind::Path(None, test_path("TestFixture"))),
+                // test::TestFixture { scope: ..., setup: ... }
+                expr: Some(cx.expr_struct(
+                    sp,
+                    test_path("TestFixture"),
+                    thin_vec![field("scope", scope), field("setup", setup)],
+                )),
+            }
+            .into(),
+        ),
+    );
+    let fixture_const = fixture_const.map(|mut fc| {
+        fc.vis.kind = ast::VisibilityKind::Public;
+        fc
+    });
+
+    // extern crate test
+    let test_extern = cx.item(sp, test_id, ast::AttrVec::new(), ast::ItemKind::ExternCrate(None));
+
+    debug!("synthetic test fixture item:\n{}\n", pprust::item_to_string(&fixture_const));
+
+    if is_stmt {
+        vec![
+            Annotatable::Stmt(P(cx.stmt_item(sp, test_extern))),
+            Annotatable::Stmt(P(cx.stmt_item(sp, fixture_const))),
+            Annotatable::Stmt(P(cx.stmt_item(sp, item))),
+        ]
+    } else {
+        vec![
+            Annotatable::Item(test_extern),
+            Annotatable::Item(fixture_const),
+            Annotatable::Item(item),
+        ]
+    }
+}
+
 pub fn expand_test_or_bench(
     cx: &mut ExtCtxt<'_>,
     attr_sp: Span,
@@ -571,6 +748,30 @@ fn check_test_signature(
     Ok(())
 }
 
+fn check_fixture_signature(
+    cx: &ExtCtxt<'_>,
+    i: &ast::Item,
+    f: &ast::Fn,
+) -> Result<(), ErrorGuaranteed> {
+    let sd = &cx.sess.parse_sess.span_diagnostic;
+
+    if let ast::Unsafe::Yes(span) = f.sig.header.unsafety {
+        return Err(sd.emit_err(errors::TestBadFn { span: i.span, cause: span, kind: "unsafe" }));
+    }
+
+    if let ast::Async::Yes { span, .. } = f.sig.header.asyncness {
+        return Err(sd.emit_err(errors::TestBadFn { span: i.span, cause: span, kind: "async" }));
+    }
+
+    if !f.sig.decl.inputs.is_empty()
+        || f.generics.params.iter().any(|param| !matches!(param.kind, GenericParamKind::Lifetime))
+    {
+        return Err(sd.emit_err(errors::TestFixtureSig { span: i.span }));
+    }
+
+    Ok(())
+}
+
 fn check_bench_signature(
     cx: &ExtCtxt<'_>,
     i: &ast::Item,
@@ -35,6 +35,7 @@ struct TestCtxt<'a> {
     panic_strategy: PanicStrategy,
     def_site: Span,
     test_cases: Vec<Test>,
+    test_fixtures: Vec<Test>,
     reexport_test_harness_main: Option<Symbol>,
     test_runner: Option<ast::Path>,
 }
@@ -91,13 +92,21 @@ pub fn inject(
 struct TestHarnessGenerator<'a> {
     cx: TestCtxt<'a>,
     tests: Vec<Test>,
+    fixtures: Vec<Test>,
 }
 
 impl TestHarnessGenerator<'_> {
-    fn add_test_cases(&mut self, node_id: ast::NodeId, span: Span, prev_tests: Vec<Test>) {
+    fn add_test_cases(
+        &mut self,
+        node_id: ast::NodeId,
+        span: Span,
+        prev_tests: Vec<Test>,
+        prev_fixtures: Vec<Test>,
+    ) {
         let mut tests = mem::replace(&mut self.tests, prev_tests);
+        let mut fixtures = mem::replace(&mut self.fixtures, prev_fixtures);
 
-        if !tests.is_empty() {
+        if !tests.is_empty() || !fixtures.is_empty() {
             // Create an identifier that will hygienically resolve the test
             // case name, even in another module.
             let expn_id = self.cx.ext_cx.resolver.expansion_for_ast_pass(
@@ -106,13 +115,14 @@ fn add_test_cases(&mut self, node_id: ast::NodeId, span: Span, prev_tests: Vec<T
                 &[],
                 Some(node_id),
             );
-            for test in &mut tests {
+            for test in tests.iter_mut().chain(&mut fixtures) {
                 // See the comment on `mk_main` for why we're using
                 // `apply_mark` directly.
                 test.ident.span =
                     test.ident.span.apply_mark(expn_id.to_expn_id(), Transparency::Opaque);
             }
             self.cx.test_cases.extend(tests);
+            self.cx.test_fixtures.extend(fixtures);
         }
     }
 }
@@ -120,8 +130,9 @@ fn add_test_cases(&mut self, node_id: ast::NodeId, span: Span, prev_tests: Vec<T
 impl<'a> MutVisitor for TestHarnessGenerator<'a> {
     fn visit_crate(&mut self, c: &mut ast::Crate) {
         let prev_tests = mem::take(&mut self.tests);
+        let prev_fixtures = mem::take(&mut self.fixtures);
         noop_visit_crate(c, self);
-        self.add_test_cases(ast::CRATE_NODE_ID, c.spans.inner_span, prev_tests);
+        self.add_test_cases(ast::CRATE_NODE_ID, c.spans.inner_span, prev_tests, prev_fixtures);
 
         // Create a main function to run our tests
         c.items.push(mk_main(&mut self.cx));
@@ -134,6 +145,11 @@ fn visit_crate(&mut self, c: &mut ast::Crate) {
 
             let test = Test { span: item.span, ident: item.ident, name };
             self.tests.push(test);
+        } else if let Some(name) = get_test_fixture_name(&item) {
+            debug!("this is a test fixture item");
+
+            let fixture = Test { span: item.span, ident: item.ident, name };
+            self.fixtures.push(fixture);
         }
 
         // We don't want to recurse into anything other than mods, since
@@ -142,8 +158,9 @@ fn visit_crate(&mut self, c: &mut ast::Crate) {
             item.kind
         {
             let prev_tests = mem::take(&mut self.tests);
+            let prev_fixtures = mem::take(&mut self.fixtures);
             noop_visit_item_kind(&mut item.kind, self);
-            self.add_test_cases(item.id, span, prev_tests);
+            self.add_test_cases(item.id, span, prev_tests, prev_fixtures);
         } else {
             // But in those cases, we emit a lint to warn the user of these missing tests.
             walk_item(&mut InnerItemLinter { sess: self.cx.ext_cx.sess }, &item);
@@ -158,7 +175,9 @@ struct InnerItemLinter<'a> {
 
 impl<'a> Visitor<'a> for InnerItemLinter<'_> {
     fn visit_item(&mut self, i: &'a ast::Item) {
-        if let Some(attr) = attr::find_by_name(&i.attrs, sym::rustc_test_marker) {
+        if let Some(attr) = attr::find_by_name(&i.attrs, sym::rustc_test_marker)
+            .or_else(|| attr::find_by_name(&i.attrs, sym::rustc_test_fixture_marker))
+        {
             self.sess.parse_sess.buffer_lint(
                 UNNAMEABLE_TEST_ITEMS,
                 attr.span,
@@ -254,11 +273,12 @@ fn generate_test_harness(
         panic_strategy,
         def_site,
         test_cases: Vec::new(),
+        test_fixtures: Vec::new(),
         reexport_test_harness_main,
         test_runner,
     };
 
-    TestHarnessGenerator { cx, tests: Vec::new() }.visit_crate(krate);
+    TestHarnessGenerator { cx, tests: Vec::new(), fixtures: Vec::new() }.visit_crate(krate);
 }
 
 /// Creates a function item for use as the main function of a test build.
@@ -286,6 +306,9 @@ fn generate_test_harness(
 /// we remove the outer mark, and try resolving at its def-site, which will
 /// then resolve to `test_const`.
 ///
+/// If the crate has any `#[test_fixture]`s, `test::test_main_static_with_fixtures` is called
+/// instead, with a second slice containing the fixture consts.
+///
 /// The expansion here can be controlled by two attributes:
 ///
 /// [`TestCtxt::reexport_test_harness_main`] provides a different name for the `main`
@@ -296,11 +319,24 @@ fn mk_main(cx: &mut TestCtxt<'_>) -> P<ast::Item> {
     let ecx = &cx.ext_cx;
     let test_id = Ident::new(sym::test, sp);
 
-    let runner_name = match cx.panic_strategy {
-        PanicStrategy::Unwind => "test_main_static",
-        PanicStrategy::Abort => "test_main_static_abort",
+    let has_fixtures = !cx.test_fixtures.is_empty();
+    let runner_name = match (cx.panic_strategy, has_fixtures) {
+        (PanicStrategy::Unwind, false) => "test_main_static",
+        (PanicStrategy::Unwind, true) => "test_main_static_with_fixtures",
+        (PanicStrategy::Abort, false) => "test_main_static_abort",
+        (PanicStrategy::Abort, true) => "test_main_static_abort_with_fixtures",
     };
 
+    // Custom test runners only receive the tests, so the fixtures would never be run.
+    if let Some(test_runner) = &cx.test_runner {
+        for fixture in &cx.test_fixtures {
+            ecx.emit_err(errors::TestFixtureCustomRunner {
+                span: test_runner.span,
+                fixture: fixture.span,
+            });
+        }
+    }
+
     // test::test_main_static(...)
     let mut test_runner = cx
         .test_runner
@@ -310,7 +346,11 @@ fn mk_main(cx: &mut TestCtxt<'_>) -> P<ast::Item> {
     test_runner.span = sp;
 
     let test_main_path_expr = ecx.expr_path(test_runner);
-    let call_test_main = ecx.expr_call(sp, test_main_path_expr, thin_vec![mk_tests_slice(cx, sp)]);
+    let mut test_main_args = thin_vec![mk_tests_slice(cx, &cx.test_cases, sp)];
+    if has_fixtures && cx.test_runner.is_none() {
+        test_main_args.push(mk_tests_slice(cx, &cx.test_fixtures, sp));
+    }
+    let call_test_main = ecx.expr_call(sp, test_main_path_expr, test_main_args);
     let call_test_main = ecx.stmt_expr(call_test_main);
 
     // extern crate test
@@ -365,13 +405,13 @@ fn mk_main(cx: &mut TestCtxt<'_>) -> P<ast::Item> {
     cx.ext_cx.monotonic_expander().fully_expand_fragment(main).make_items().pop().unwrap()
 }
 
-/// Creates a slice containing every test like so:
+/// Creates a slice containing every test (or test fixture) like so:
 /// &[&test1, &test2]
-fn mk_tests_slice(cx: &TestCtxt<'_>, sp: Span) -> P<ast::Expr> {
-    debug!("building test vector from {} tests", cx.test_cases.len());
+fn mk_tests_slice(cx: &TestCtxt<'_>, tests: &[Test], sp: Span) -> P<ast::Expr> {
+    debug!("building test vector from {} tests", tests.len());
     let ecx = &cx.ext_cx;
 
-    let mut tests = cx.test_cases.clone();
+    let mut tests = tests.to_vec();
     tests.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
 
     ecx.expr_array_ref(
@@ -389,6 +429,10 @@ fn get_test_name(i: &ast::Item) -> Option<Symbol> {
     attr::first_attr_value_str_by_name(&i.attrs, sym::rustc_test_marker)
 }
 
+fn get_test_fixture_name(i: &ast::Item) -> Option<Symbol> {
+    attr::first_attr_value_str_by_name(&i.attrs, sym::rustc_test_fixture_marker)
+}
+
 fn get_test_runner(sd: &rustc_errors::Handler, krate: &ast::Crate) -> Option<ast::Path> {
     let test_attr = attr::find_by_name(&krate.attrs, sym::test_runner)?;
     let meta_list = test_attr.meta_item_list()?;
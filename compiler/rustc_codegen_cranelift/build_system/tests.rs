@@ -104,6 +104,10 @@ const fn jit_bin(config: &'static str, source: &'static str, args: &'static str)
         runner.run_out_command("polymorphize_coroutine", &[]);
     }),
     TestCase::build_bin_and_run("aot.neon", "example/neon.rs", &[]),
+    TestCase::custom("aot.merge-functions", &|runner| {
+        runner.run_rustc(["example/merge-functions.rs", "-Zmerge-functions=mir"]);
+        runner.run_out_command("merge-functions", &[]);
+    }),
     TestCase::custom("aot.gen_block_iterate", &|runner| {
         runner.run_rustc([
             "example/gen_block_iterate.rs",
@@ -0,0 +1,33 @@
+// Checks that instances merged by `-Zmerge-functions=mir`, which are emitted as
+// trampolines calling the instance they were merged into, still work.
+
+#[inline(never)]
+fn double_len<T>(v: &Vec<T>) -> usize {
+    v.len() * 2
+}
+
+#[inline(never)]
+fn first_and_len<T: Copy>(v: &[T], extra: usize) -> (T, usize) {
+    (v[0], v.len() + extra)
+}
+
+fn main() {
+    let unsigned: Vec<u32> = vec![1, 2, 3];
+    let signed: Vec<i32> = vec![-1, -2];
+
+    assert_eq!(double_len(&unsigned), 6);
+    assert_eq!(double_len(&signed), 4);
+    assert_eq!(first_and_len(&unsigned, 10), (1, 13));
+    assert_eq!(first_and_len(&signed, 20), (-1, 22));
+
+    // Through function pointers, which refer to each instance by its own symbol.
+    let double_len_u32: fn(&Vec<u32>) -> usize = std::hint::black_box(double_len);
+    let double_len_i32: fn(&Vec<i32>) -> usize = std::hint::black_box(double_len);
+    assert_eq!(double_len_u32(&unsigned), 6);
+    assert_eq!(double_len_i32(&signed), 4);
+
+    let first_and_len_u32: fn(&[u32], usize) -> (u32, usize) = std::hint::black_box(first_and_len);
+    let first_and_len_i32: fn(&[i32], usize) -> (i32, usize) = std::hint::black_box(first_and_len);
+    assert_eq!(first_and_len_u32(&unsigned, 0), (1, 3));
+    assert_eq!(first_and_len_i32(&signed, 0), (-1, 2));
+}
@@ -33,6 +33,7 @@ pub(crate) fn scalar_to_clif_type(tcx: TyCtxt<'_>, scalar: Scalar) -> Type {
             Integer::I64 => types::I64,
             Integer::I128 => types::I128,
         },
+        Primitive::F16 | Primitive::F128 => float_unsupported(tcx),
         Primitive::F32 => types::F32,
         Primitive::F64 => types::F64,
         // FIXME(erikdesjardins): handle non-default addrspace ptr sizes
@@ -40,6 +41,10 @@ pub(crate) fn scalar_to_clif_type(tcx: TyCtxt<'_>, scalar: Scalar) -> Type {
     }
 }
 
+fn float_unsupported(tcx: TyCtxt<'_>) -> ! {
+    tcx.sess.fatal("`f16` and `f128` are not yet supported by the Cranelift backend")
+}
+
 fn clif_type_from_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<types::Type> {
     Some(match ty.kind() {
         ty::Bool => types::I8,
@@ -61,6 +66,7 @@ fn clif_type_from_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<types::Typ
         },
         ty::Char => types::I32,
         ty::Float(size) => match size {
+            FloatTy::F16 | FloatTy::F128 => float_unsupported(tcx),
             FloatTy::F32 => types::F32,
             FloatTy::F64 => types::F64,
         },
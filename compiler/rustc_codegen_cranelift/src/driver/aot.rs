@@ -259,6 +259,45 @@ fn reuse_workproduct_for_cgu(
     })
 }
 
+/// Defines `instance` as a function forwarding its arguments to `target`, which
+/// has the same MIR. Cranelift can't emit symbol aliases, so this is the closest
+/// it gets to them.
+fn codegen_alias_trampoline<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    module: &mut dyn Module,
+    unwind_context: &mut UnwindContext,
+    instance: Instance<'tcx>,
+    target: Instance<'tcx>,
+) {
+    let func_id = import_function(tcx, module, instance);
+    let target_func_id = import_function(tcx, module, target);
+
+    let mut ctx = Context::new();
+    ctx.func.signature = get_function_sig(tcx, module.target_config().default_call_conv, instance);
+    {
+        let mut func_ctx = FunctionBuilderContext::new();
+        let mut bcx = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+
+        let block = bcx.create_block();
+        bcx.append_block_params_for_function_params(block);
+        bcx.switch_to_block(block);
+        let args = bcx.block_params(block).to_vec();
+
+        let target_func_ref = module.declare_func_in_func(target_func_id, &mut bcx.func);
+        let call_inst = bcx.ins().call(target_func_ref, &args);
+        let results = bcx.inst_results(call_inst).to_vec();
+        bcx.ins().return_(&results);
+        bcx.seal_all_blocks();
+        bcx.finalize();
+    }
+
+    if let Err(err) = module.define_function(func_id, &mut ctx) {
+        let name = tcx.symbol_name(instance).name;
+        tcx.sess.fatal(format!("symbol `{name}` defined multiple times: {err}"));
+    }
+    unwind_context.add_function(func_id, &ctx, module.isa());
+}
+
 fn module_codegen(
     tcx: TyCtxt<'_>,
     (backend_config, global_asm_config, cgu_name, token): (
@@ -287,6 +326,16 @@ fn module_codegen(
             for (mono_item, _) in mono_items {
                 match mono_item {
                     MonoItem::Fn(inst) => {
+                        if let Some(MonoItem::Fn(target)) = cgu.alias_target(&mono_item) {
+                            codegen_alias_trampoline(
+                                tcx,
+                                &mut module,
+                                &mut cx.unwind_context,
+                                inst,
+                                target,
+                            );
+                            continue;
+                        }
                         let codegened_function = crate::base::codegen_fn(
                             tcx,
                             &mut cx,
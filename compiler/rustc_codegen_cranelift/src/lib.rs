@@ -179,6 +179,9 @@ fn init(&self, sess: &Session) {
             Lto::No | Lto::ThinLocal => {}
             Lto::Thin | Lto::Fat => sess.warn("LTO is not supported. You may get a linker error."),
         }
+        if sess.opts.unstable_opts.emit_size_report.is_some() {
+            sess.warn("-Zemit-size-report is not supported. No size report will be written.");
+        }
 
         let mut config = self.config.borrow_mut();
         if config.is_none() {
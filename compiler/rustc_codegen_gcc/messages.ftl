@@ -5,6 +5,9 @@ codegen_gcc_unknown_ctarget_feature_prefix =
 codegen_gcc_invalid_minimum_alignment =
     invalid minimum global alignment: {$err}
 
+codegen_gcc_f16_f128_not_supported =
+    `f16` and `f128` are not yet supported by the GCC backend
+
 codegen_gcc_lto_not_supported =
     LTO is not supported. You may get a linker error.
 
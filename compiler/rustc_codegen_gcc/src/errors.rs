@@ -43,6 +43,10 @@ fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
     }
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_gcc_f16_f128_not_supported)]
+pub(crate) struct F16F128NotSupported;
+
 #[derive(Diagnostic)]
 #[diag(codegen_gcc_lto_not_supported)]
 pub(crate) struct LTONotSupported;
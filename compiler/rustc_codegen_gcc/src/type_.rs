@@ -7,6 +7,7 @@
 
 use crate::common::TypeReflection;
 use crate::context::CodegenCx;
+use crate::errors::F16F128NotSupported;
 use crate::type_of::LayoutGccExt;
 
 impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
@@ -83,8 +84,10 @@ pub fn type_vector(&self, ty: Type<'gcc>, len: u64) -> Type<'gcc> {
 
     pub fn type_float_from_ty(&self, t: ty::FloatTy) -> Type<'gcc> {
         match t {
+            ty::FloatTy::F16 => self.type_f16(),
             ty::FloatTy::F32 => self.type_f32(),
             ty::FloatTy::F64 => self.type_f64(),
+            ty::FloatTy::F128 => self.type_f128(),
         }
     }
 }
@@ -118,6 +121,10 @@ fn type_isize(&self) -> Type<'gcc> {
         self.isize_type
     }
 
+    fn type_f16(&self) -> Type<'gcc> {
+        self.sess().emit_fatal(F16F128NotSupported)
+    }
+
     fn type_f32(&self) -> Type<'gcc> {
         self.float_type
     }
@@ -126,6 +133,10 @@ fn type_f64(&self) -> Type<'gcc> {
         self.double_type
     }
 
+    fn type_f128(&self) -> Type<'gcc> {
+        self.sess().emit_fatal(F16F128NotSupported)
+    }
+
     fn type_func(&self, params: &[Type<'gcc>], return_type: Type<'gcc>) -> Type<'gcc> {
         self.context.new_function_pointer_type(None, return_type, params, false)
     }
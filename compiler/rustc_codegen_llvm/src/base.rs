@@ -26,9 +26,10 @@
 use rustc_codegen_ssa::traits::*;
 use rustc_codegen_ssa::{ModuleCodegen, ModuleKind};
 use rustc_data_structures::small_c_str::SmallCStr;
+use rustc_middle::bug;
 use rustc_middle::dep_graph;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrs;
-use rustc_middle::mir::mono::{Linkage, Visibility};
+use rustc_middle::mir::mono::{Linkage, MonoItem, Visibility};
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::DebugInfo;
 use rustc_span::symbol::Symbol;
@@ -87,12 +88,34 @@ fn module_codegen(tcx: TyCtxt<'_>, cgu_name: Symbol) -> ModuleCodegen<ModuleLlvm
             let cx = CodegenCx::new(tcx, cgu, &llvm_module);
             let mono_items = cx.codegen_unit.items_in_deterministic_order(cx.tcx);
             for &(mono_item, data) in &mono_items {
-                mono_item.predefine::<Builder<'_, '_, '_>>(&cx, data.linkage, data.visibility);
+                if cx.codegen_unit.alias_target(&mono_item).is_none() {
+                    mono_item.predefine::<Builder<'_, '_, '_>>(&cx, data.linkage, data.visibility);
+                }
+            }
+
+            // Items with the same MIR as another item are emitted as aliases of
+            // it, which needs the aliased item to be pre-defined.
+            for &(mono_item, data) in &mono_items {
+                if let Some(target) = cx.codegen_unit.alias_target(&mono_item) {
+                    let (MonoItem::Fn(instance), MonoItem::Fn(target)) = (mono_item, target) else {
+                        bug!("only functions can be aliases, found {mono_item:?} -> {target:?}");
+                    };
+                    let symbol_name = mono_item.symbol_name(cx.tcx).name;
+                    cx.predefine_fn_alias(
+                        instance,
+                        target,
+                        data.linkage,
+                        data.visibility,
+                        symbol_name,
+                    );
+                }
             }
 
             // ... and now that we have everything pre-defined, fill out those definitions.
             for &(mono_item, _) in &mono_items {
-                mono_item.define::<Builder<'_, '_, '_>>(&cx);
+                if cx.codegen_unit.alias_target(&mono_item).is_none() {
+                    mono_item.define::<Builder<'_, '_, '_>>(&cx);
+                }
             }
 
             // If this codegen unit contains the main function, also create the
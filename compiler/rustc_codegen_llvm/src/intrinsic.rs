@@ -156,6 +156,9 @@ fn codegen_intrinsic_call(
                             }
                             // `va_arg` should never be used with the return type f32.
                             Primitive::F32 => bug!("the va_arg intrinsic does not work with `f32`"),
+                            Primitive::F16 | Primitive::F128 => {
+                                bug!("the va_arg intrinsic does not work with `f16` or `f128`")
+                            }
                         }
                     }
                     _ => bug!("the va_arg intrinsic does not work with non-scalar types"),
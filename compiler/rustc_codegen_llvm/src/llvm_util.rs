@@ -95,7 +95,7 @@ fn llvm_arg_to_arg_name(full_arg: &str) -> &str {
 
         match sess.opts.unstable_opts.merge_functions.unwrap_or(sess.target.merge_functions) {
             MergeFunctions::Disabled | MergeFunctions::Trampolines => {}
-            MergeFunctions::Aliases => {
+            MergeFunctions::Aliases | MergeFunctions::Mir => {
                 add("-mergefunc-use-aliases", false);
             }
         }
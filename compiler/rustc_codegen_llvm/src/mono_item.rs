@@ -5,11 +5,13 @@
 use crate::llvm;
 use crate::type_of::LayoutLlvmExt;
 use rustc_codegen_ssa::traits::*;
+use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_middle::mir::mono::{Linkage, Visibility};
 use rustc_middle::ty::layout::{FnAbiOf, LayoutOf};
 use rustc_middle::ty::{self, Instance, TypeVisitableExt};
 use rustc_session::config::CrateType;
+use rustc_target::abi::HasDataLayout;
 use rustc_target::spec::RelocModel;
 
 impl<'tcx> PreDefineMethods<'tcx> for CodegenCx<'_, 'tcx> {
@@ -89,6 +91,48 @@ fn predefine_fn(
     }
 }
 
+impl<'ll, 'tcx> CodegenCx<'ll, 'tcx> {
+    /// Defines `instance` as an alias of `target`, which must have been
+    /// pre-defined already, for `-Z merge-functions=mir`.
+    pub(crate) fn predefine_fn_alias(
+        &self,
+        instance: Instance<'tcx>,
+        target: Instance<'tcx>,
+        linkage: Linkage,
+        visibility: Visibility,
+        symbol_name: &str,
+    ) {
+        if self.get_declared_value(symbol_name).is_some() {
+            let span = self.tcx.def_span(instance.def_id());
+            self.sess().emit_fatal(SymbolAlreadyDefined { span, symbol_name });
+        }
+
+        let aliasee = self.instances.borrow()[&target];
+        let address_space = self.data_layout().instruction_address_space;
+        let name = SmallCStr::new(symbol_name);
+        let llalias = unsafe {
+            llvm::LLVMAddAlias2(
+                self.llmod,
+                llvm::LLVMGlobalGetValueType(aliasee),
+                address_space.0,
+                aliasee,
+                name.as_ptr(),
+            )
+        };
+        unsafe {
+            llvm::LLVMRustSetLinkage(llalias, base::linkage_to_llvm(linkage));
+            llvm::LLVMRustSetVisibility(llalias, base::visibility_to_llvm(visibility));
+            if self.should_assume_dso_local(llalias, false) {
+                llvm::LLVMRustSetDSOLocal(llalias, true);
+            }
+        }
+
+        debug!("predefine_fn_alias: instance = {:?}, target = {:?}", instance, target);
+
+        self.instances.borrow_mut().insert(instance, llalias);
+    }
+}
+
 impl CodegenCx<'_, '_> {
     /// Whether a definition or declaration can be assumed to be local to a group of
     /// libraries that form a single DSO or executable.
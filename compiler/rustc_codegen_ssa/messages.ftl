@@ -211,6 +211,8 @@ codegen_ssa_select_cpp_build_tool_workload = in the Visual Studio installer, ens
 
 codegen_ssa_shuffle_indices_evaluation = could not evaluate shuffle_indices at compile time
 
+codegen_ssa_size_report_write = failed to write size report to `{$path}`: {$error}
+
 codegen_ssa_specify_libraries_to_link = use the `-l` flag to specify native libraries to link
 
 codegen_ssa_static_library_native_artifacts = Link against the following native artifacts when linking against this static library. The order and any duplication can be significant on some platforms.
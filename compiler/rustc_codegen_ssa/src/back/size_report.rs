@@ -0,0 +1,277 @@
+//! Attribution of the bytes in the emitted object files to the mono items that
+//! were codegened into them, for `-Z emit-size-report`.
+//!
+//! The items of every codegen unit are gathered right after partitioning, and
+//! matched against the symbols of the object file of their codegen unit once
+//! codegen is done. Code that got inlined is accounted to the function it was
+//! inlined into, so an item that was inlined everywhere shows up with zero
+//! bytes. Bytes not covered by any symbol, like headers, relocations and
+//! debuginfo, are only reported per codegen unit.
+
+use std::cmp;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use object::{Object, ObjectSection, ObjectSymbol, SectionIndex, SymbolKind};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_middle::mir::mono::{CodegenUnit, MonoItem};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::TyCtxt;
+
+use crate::CompiledModule;
+
+/// The mono items of all codegen units, with the names they are reported under.
+pub struct SizeReportItems {
+    codegen_units: Vec<(String, Vec<SizeReportItem>)>,
+}
+
+struct SizeReportItem {
+    symbol: String,
+    krate: String,
+    module: String,
+    function: String,
+    instance: String,
+    size_estimate: usize,
+}
+
+#[derive(serde::Serialize)]
+struct SizeReport {
+    total_bytes: u64,
+    attributed_bytes: u64,
+    codegen_units: Vec<CodegenUnitSize>,
+    crates: Vec<GroupSize>,
+    modules: Vec<GroupSize>,
+    functions: Vec<GroupSize>,
+    instances: Vec<InstanceSize>,
+}
+
+#[derive(serde::Serialize)]
+struct CodegenUnitSize {
+    name: String,
+    object_bytes: u64,
+    attributed_bytes: u64,
+    /// Whether the object file couldn't be read, so that its bytes were split
+    /// among its items by their size estimates.
+    estimated: bool,
+}
+
+#[derive(serde::Serialize)]
+struct GroupSize {
+    name: String,
+    bytes: u64,
+    instances: usize,
+}
+
+#[derive(serde::Serialize)]
+struct InstanceSize {
+    name: String,
+    symbol: String,
+    codegen_unit: String,
+    bytes: u64,
+    size_estimate: usize,
+}
+
+impl SizeReportItems {
+    pub fn collect<'tcx>(tcx: TyCtxt<'tcx>, codegen_units: &[CodegenUnit<'tcx>]) -> Self {
+        let codegen_units = codegen_units
+            .iter()
+            .map(|cgu| {
+                let items = cgu
+                    .items_in_deterministic_order(tcx)
+                    .into_iter()
+                    .map(|(mono_item, _)| SizeReportItem::new(tcx, mono_item))
+                    .collect();
+                (cgu.name().to_string(), items)
+            })
+            .collect();
+        SizeReportItems { codegen_units }
+    }
+
+    /// Writes the report for the given compiled modules, which must still have
+    /// their object files around, to `path`.
+    pub fn write(&self, modules: &[CompiledModule], path: &Path) -> io::Result<()> {
+        let mut objects = Vec::new();
+        let mut object_by_cgu = FxHashMap::default();
+        for module in modules {
+            let Some(object) = &module.object else { continue };
+            let data = fs::read(object)?;
+            object_by_cgu.insert(&*module.name, objects.len());
+            objects.push((data.len() as u64, symbol_sizes(&data)));
+        }
+
+        let mut report = SizeReport {
+            total_bytes: objects.iter().map(|(len, _)| len).sum(),
+            attributed_bytes: 0,
+            codegen_units: Vec::new(),
+            crates: Vec::new(),
+            modules: Vec::new(),
+            functions: Vec::new(),
+            instances: Vec::new(),
+        };
+        // A symbol is only accounted once per object file. Items of codegen
+        // units without an object file of their own have been merged into
+        // another one by LTO, so their symbols are looked up in all of them.
+        let mut claimed = FxHashSet::default();
+        for (cgu_name, items) in &self.codegen_units {
+            let own_object = object_by_cgu.get(&**cgu_name).copied();
+            let estimated = own_object.is_some_and(|index| objects[index].1.is_none());
+            let mut bytes_by_item: Vec<u64> = items
+                .iter()
+                .map(|item| {
+                    let candidates = match own_object {
+                        Some(index) => index..index + 1,
+                        None => 0..objects.len(),
+                    };
+                    candidates
+                        .filter_map(|index| {
+                            let size = lookup_symbol(objects[index].1.as_ref()?, &item.symbol)?;
+                            claimed.insert((index, &*item.symbol)).then_some(size)
+                        })
+                        .next()
+                        .unwrap_or(0)
+                })
+                .collect();
+            if estimated {
+                let object_bytes = objects[own_object.unwrap()].0;
+                let total_estimate = items.iter().map(|item| item.size_estimate as u64).sum();
+                for (bytes, item) in bytes_by_item.iter_mut().zip(items) {
+                    *bytes = (object_bytes * item.size_estimate as u64)
+                        .checked_div(total_estimate)
+                        .unwrap_or(0);
+                }
+            }
+
+            let attributed_bytes = bytes_by_item.iter().sum();
+            report.attributed_bytes += attributed_bytes;
+            report.codegen_units.push(CodegenUnitSize {
+                name: cgu_name.clone(),
+                object_bytes: own_object.map_or(0, |index| objects[index].0),
+                attributed_bytes,
+                estimated,
+            });
+            for (item, bytes) in items.iter().zip(bytes_by_item) {
+                report.instances.push(InstanceSize {
+                    name: item.instance.clone(),
+                    symbol: item.symbol.clone(),
+                    codegen_unit: cgu_name.clone(),
+                    bytes,
+                    size_estimate: item.size_estimate,
+                });
+            }
+        }
+
+        let items = self.codegen_units.iter().flat_map(|(_, items)| items);
+        let instance_bytes = report.instances.iter().map(|instance| instance.bytes);
+        report.crates = group(items.clone().zip(instance_bytes.clone()), |item| &item.krate);
+        report.modules = group(items.clone().zip(instance_bytes.clone()), |item| &item.module);
+        report.functions = group(items.zip(instance_bytes), |item| &item.function);
+        report.instances.sort_by_key(|instance| cmp::Reverse(instance.bytes));
+
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, &report)?;
+        Ok(())
+    }
+}
+
+impl SizeReportItem {
+    fn new<'tcx>(tcx: TyCtxt<'tcx>, mono_item: MonoItem<'tcx>) -> Self {
+        let def_id = mono_item.def_id();
+        let mut module = def_id;
+        while tcx.def_kind(module) != DefKind::Mod {
+            module = tcx.parent(module);
+        }
+        SizeReportItem {
+            symbol: mono_item.symbol_name(tcx).name.to_string(),
+            krate: tcx.crate_name(def_id.krate).to_string(),
+            module: path_str(tcx, module),
+            function: path_str(tcx, def_id),
+            instance: with_no_trimmed_paths!(mono_item.to_string()),
+            size_estimate: mono_item.size_estimate(tcx),
+        }
+    }
+}
+
+/// The path of `def_id`, starting with the name of its crate.
+fn path_str(tcx: TyCtxt<'_>, def_id: DefId) -> String {
+    let path = with_no_trimmed_paths!(tcx.def_path_str(def_id));
+    if !def_id.is_local() {
+        path
+    } else if path.is_empty() {
+        tcx.crate_name(def_id.krate).to_string()
+    } else {
+        format!("{}::{path}", tcx.crate_name(def_id.krate))
+    }
+}
+
+/// Sums up the bytes of the items per name, from the largest to the smallest.
+fn group<'a>(
+    items: impl Iterator<Item = (&'a SizeReportItem, u64)>,
+    name: impl Fn(&'a SizeReportItem) -> &'a String,
+) -> Vec<GroupSize> {
+    let mut groups: FxIndexMap<&String, GroupSize> = FxIndexMap::default();
+    for (item, bytes) in items {
+        let group = groups.entry(name(item)).or_insert_with(|| GroupSize {
+            name: name(item).clone(),
+            bytes: 0,
+            instances: 0,
+        });
+        group.bytes += bytes;
+        group.instances += 1;
+    }
+    let mut groups: Vec<_> = groups.into_values().collect();
+    groups.sort_by_key(|group| cmp::Reverse(group.bytes));
+    groups
+}
+
+/// Returns the sizes of the symbols defined in an object file, or `None` if
+/// it isn't in a format that can be read.
+fn symbol_sizes(data: &[u8]) -> Option<FxHashMap<String, u64>> {
+    let file = object::File::parse(data).ok()?;
+
+    let mut symbols_by_section: FxHashMap<SectionIndex, Vec<_>> = FxHashMap::default();
+    for symbol in file.symbols() {
+        if symbol.is_undefined() || !matches!(symbol.kind(), SymbolKind::Text | SymbolKind::Data) {
+            continue;
+        }
+        let (Some(section), Ok(name)) = (symbol.section_index(), symbol.name()) else {
+            continue;
+        };
+        symbols_by_section.entry(section).or_default().push((
+            symbol.address(),
+            symbol.size(),
+            name,
+        ));
+    }
+
+    // Mach-O and COFF don't record the size of a symbol, so it's taken to
+    // extend up to the next symbol in the same section instead.
+    let mut sizes = FxHashMap::default();
+    for (index, mut symbols) in symbols_by_section {
+        let Ok(section) = file.section_by_index(index) else { continue };
+        let section_end = section.address() + section.size();
+        symbols.sort_unstable();
+        for (i, &(address, size, name)) in symbols.iter().enumerate() {
+            let size = if size != 0 {
+                size
+            } else {
+                let end = symbols[i + 1..]
+                    .iter()
+                    .map(|&(next, ..)| next)
+                    .find(|&next| next > address)
+                    .unwrap_or(section_end);
+                end.saturating_sub(address)
+            };
+            *sizes.entry(name.to_owned()).or_default() += size;
+        }
+    }
+    Some(sizes)
+}
+
+/// Looks up the size of a symbol, which may have been prefixed with an
+/// underscore on targets that mangle C symbol names that way.
+fn lookup_symbol(sizes: &FxHashMap<String, u64>, symbol: &str) -> Option<u64> {
+    sizes.get(symbol).or_else(|| sizes.get(&format!("_{symbol}"))).copied()
+}
@@ -1,5 +1,6 @@
 use super::link::{self, ensure_removed};
 use super::lto::{self, SerializedModule};
+use super::size_report::SizeReportItems;
 use super::symbol_export::symbol_name_for_instance_in_crate;
 
 use crate::errors;
@@ -257,7 +258,7 @@ macro_rules! if_regular {
                 .unwrap_or(sess.target.merge_functions)
             {
                 MergeFunctions::Disabled => false,
-                MergeFunctions::Trampolines | MergeFunctions::Aliases => {
+                MergeFunctions::Trampolines | MergeFunctions::Aliases | MergeFunctions::Mir => {
                     use config::OptLevel::*;
                     match sess.opts.optimize {
                         Aggressive | Default | SizeMin | Size => true,
@@ -451,6 +452,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
     target_cpu: String,
     metadata: EncodedMetadata,
     metadata_module: Option<CompiledModule>,
+    size_report: Option<SizeReportItems>,
 ) -> OngoingCodegen<B> {
     let (coordinator_send, coordinator_receive) = channel();
     let sess = tcx.sess;
@@ -490,6 +492,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
         metadata,
         metadata_module,
         crate_info,
+        size_report,
 
         codegen_worker_receive,
         shared_emitter_main,
@@ -1943,6 +1946,8 @@ pub struct OngoingCodegen<B: ExtraBackendMethods> {
     pub metadata: EncodedMetadata,
     pub metadata_module: Option<CompiledModule>,
     pub crate_info: CrateInfo,
+    /// The mono items to attribute the object file sizes to, for `-Z emit-size-report`.
+    pub size_report: Option<SizeReportItems>,
     pub codegen_worker_receive: Receiver<CguMessage>,
     pub shared_emitter_main: SharedEmitterMain,
     pub output_filenames: Arc<OutputFilenames>,
@@ -1967,6 +1972,16 @@ pub fn join(self, sess: &Session) -> (CodegenResults, FxIndexMap<WorkProductId,
 
         sess.abort_if_errors();
 
+        // This needs the object files, some of which are removed right after.
+        if let (Some(size_report), Some(path)) =
+            (&self.size_report, &sess.opts.unstable_opts.emit_size_report)
+        {
+            let _timer = sess.timer("write_size_report");
+            if let Err(error) = size_report.write(&compiled_modules.modules, path) {
+                sess.emit_fatal(errors::SizeReportWrite { path, error });
+            }
+        }
+
         let work_products =
             copy_all_cgu_workproducts_to_incr_comp_cache_dir(sess, &compiled_modules);
         produce_final_output_artifacts(sess, &compiled_modules, &self.output_filenames);
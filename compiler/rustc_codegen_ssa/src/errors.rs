@@ -650,6 +650,13 @@ pub struct MetadataObjectFileWrite {
     pub error: Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_size_report_write)]
+pub struct SizeReportWrite<'a> {
+    pub path: &'a Path,
+    pub error: Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_invalid_windows_subsystem)]
 pub struct InvalidWindowsSubsystem {
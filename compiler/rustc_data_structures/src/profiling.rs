@@ -79,6 +79,13 @@
 //! invocation) and allocate the corresponding strings together with a mapping
 //! for `DepNodeIndex as StringId`.
 //!
+//!
+//! ## Built-in Outputs
+//!
+//! For when the `measureme` tools aren't at hand, the compiler can also keep
+//! all events in memory and turn them into a Chrome trace or a summary table
+//! by itself once profiling is done, see the `recorder` module.
+//!
 //! [mm]: https://github.com/rust-lang/measureme/
 
 use crate::fx::FxHashMap;
@@ -95,11 +102,18 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use measureme::event_id::SEPARATOR_BYTE;
 pub use measureme::EventId;
-use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
+use measureme::{Profiler, SerializableString, StringComponent, StringId};
 use parking_lot::RwLock;
 use smallvec::SmallVec;
 
+use self::recorder::{EventRecorder, RecordedId, RecordedInterval};
+
+mod chrome;
+mod recorder;
+mod summary;
+
 bitflags::bitflags! {
     struct EventFilter: u16 {
         const GENERIC_ACTIVITIES  = 1 << 0;
@@ -155,6 +169,15 @@ pub enum TimePassesFormat {
     Json,
 }
 
+/// Which format to use for `-Z self-profile`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum SelfProfileFormat {
+    /// Emit the raw event data for the `measureme` tools
+    Measureme,
+    /// Also emit a trace in the Chrome trace event format
+    Chrome,
+}
+
 /// A reference to the SelfProfiler. It can be cloned and sent across thread
 /// boundaries at will.
 #[derive(Clone)]
@@ -274,7 +297,7 @@ pub fn generic_activity_with_arg<A>(
         A: Borrow<str> + Into<String>,
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
@@ -317,7 +340,7 @@ pub fn generic_activity_with_arg_recorder<F>(
     {
         // Ensure this event will only be recorded when self-profiling is turned on.
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
 
             // Ensure the closure to create event arguments will only be called when argument
@@ -356,7 +379,7 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
         A: Borrow<str> + Into<String>,
     {
         drop(self.exec(EventFilter::ARTIFACT_SIZES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(artifact_kind);
             let event_arg = profiler.get_or_alloc_cached_string(artifact_name);
             let event_id = builder.from_label_and_arg(event_label, event_arg);
@@ -368,6 +391,10 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
                 thread_id,
                 size,
             );
+            if let Some(recorder) = &profiler.recorder {
+                let id = RecordedId::String(event_id.to_string_id());
+                recorder.record_integer(profiler.artifact_size_event_kind, id, thread_id, size);
+            }
 
             TimingGuard::none()
         }))
@@ -380,7 +407,7 @@ pub fn generic_activity_with_args(
         event_args: &[String],
     ) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_args: Vec<_> = event_args
@@ -472,6 +499,10 @@ fn instant_query_event(
             EventId::from_virtual(event_id),
             thread_id,
         );
+        if let Some(recorder) = &profiler.recorder {
+            let id = RecordedId::Query(query_invocation_id.0);
+            recorder.record_instant(event_kind(profiler), id, thread_id);
+        }
     }
 
     pub fn with_profiler(&self, f: impl FnOnce(&SelfProfiler)) {
@@ -534,6 +565,10 @@ pub struct SelfProfiler {
     profiler: Profiler,
     event_filter_mask: EventFilter,
 
+    /// Keeps the events in memory as well, for the outputs that are produced
+    /// by the compiler itself when profiling is done.
+    recorder: Option<EventRecorder>,
+
     string_cache: RwLock<FxHashMap<String, StringId>>,
 
     query_event_kind: StringId,
@@ -551,6 +586,8 @@ pub fn new(
         crate_name: Option<&str>,
         event_filters: Option<&[String]>,
         counter_name: &str,
+        format: SelfProfileFormat,
+        print_summary: bool,
     ) -> Result<SelfProfiler, Box<dyn Error + Send + Sync>> {
         fs::create_dir_all(output_directory)?;
 
@@ -564,14 +601,26 @@ pub fn new(
         let profiler =
             Profiler::with_counter(&path, measureme::counters::Counter::by_name(counter_name)?)?;
 
-        let query_event_kind = profiler.alloc_string("Query");
-        let generic_activity_event_kind = profiler.alloc_string("GenericActivity");
-        let incremental_load_result_event_kind = profiler.alloc_string("IncrementalLoadResult");
-        let incremental_result_hashing_event_kind =
-            profiler.alloc_string("IncrementalResultHashing");
-        let query_blocked_event_kind = profiler.alloc_string("QueryBlocked");
-        let query_cache_hit_event_kind = profiler.alloc_string("QueryCacheHit");
-        let artifact_size_event_kind = profiler.alloc_string("ArtifactSize");
+        let recorder = (format == SelfProfileFormat::Chrome || print_summary).then(|| {
+            let trace_path = (format == SelfProfileFormat::Chrome)
+                .then(|| output_directory.join(format!("{crate_name}-{pid:07}.json")));
+            EventRecorder::new(trace_path, print_summary)
+        });
+        let alloc_string = |s: &str| {
+            let string_id = profiler.alloc_string(s);
+            if let Some(recorder) = &recorder {
+                recorder.record_string(string_id, &[StringComponent::Value(s)]);
+            }
+            string_id
+        };
+
+        let query_event_kind = alloc_string("Query");
+        let generic_activity_event_kind = alloc_string("GenericActivity");
+        let incremental_load_result_event_kind = alloc_string("IncrementalLoadResult");
+        let incremental_result_hashing_event_kind = alloc_string("IncrementalResultHashing");
+        let query_blocked_event_kind = alloc_string("QueryBlocked");
+        let query_cache_hit_event_kind = alloc_string("QueryCacheHit");
+        let artifact_size_event_kind = alloc_string("ArtifactSize");
 
         let mut event_filter_mask = EventFilter::empty();
 
@@ -609,6 +658,7 @@ pub fn new(
         Ok(SelfProfiler {
             profiler,
             event_filter_mask,
+            recorder,
             string_cache: RwLock::new(FxHashMap::default()),
             query_event_kind,
             generic_activity_event_kind,
@@ -622,8 +672,12 @@ pub fn new(
 
     /// Allocates a new string in the profiling data. Does not do any caching
     /// or deduplication.
-    pub fn alloc_string<STR: SerializableString + ?Sized>(&self, s: &STR) -> StringId {
-        self.profiler.alloc_string(s)
+    pub fn alloc_string<STR: ProfileString + ?Sized>(&self, s: &STR) -> StringId {
+        let string_id = self.profiler.alloc_string(s);
+        if let Some(recorder) = &self.recorder {
+            s.with_components(|components| recorder.record_string(string_id, components));
+        }
+        string_id
     }
 
     /// Gets a `StringId` for the given string. This method makes sure that
@@ -649,13 +703,16 @@ pub fn get_or_alloc_cached_string<A>(&self, s: A) -> StringId
         match string_cache.entry(s.into()) {
             Entry::Occupied(e) => *e.get(),
             Entry::Vacant(e) => {
-                let string_id = self.profiler.alloc_string(&e.key()[..]);
+                let string_id = self.alloc_string(&e.key()[..]);
                 *e.insert(string_id)
             }
         }
     }
 
     pub fn map_query_invocation_id_to_string(&self, from: QueryInvocationId, to: StringId) {
+        if let Some(recorder) = &self.recorder {
+            recorder.map_query(from.0, to);
+        }
         let from = StringId::new_virtual(from.0);
         self.profiler.map_virtual_to_concrete_string(from, to);
     }
@@ -664,8 +721,17 @@ pub fn bulk_map_query_invocation_id_to_single_string<I>(&self, from: I, to: Stri
     where
         I: Iterator<Item = QueryInvocationId> + ExactSizeIterator,
     {
-        let from = from.map(|qid| StringId::new_virtual(qid.0));
-        self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
+        if let Some(recorder) = &self.recorder {
+            let from: Vec<_> = from.collect();
+            for qid in &from {
+                recorder.map_query(qid.0, to);
+            }
+            let from = from.into_iter().map(|qid| StringId::new_virtual(qid.0));
+            self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
+        } else {
+            let from = from.map(|qid| StringId::new_virtual(qid.0));
+            self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
+        }
     }
 
     pub fn query_key_recording_enabled(&self) -> bool {
@@ -673,12 +739,57 @@ pub fn query_key_recording_enabled(&self) -> bool {
     }
 
     pub fn event_id_builder(&self) -> EventIdBuilder<'_> {
-        EventIdBuilder::new(&self.profiler)
+        EventIdBuilder { profiler: self }
+    }
+}
+
+/// A string that can be allocated in the profiling data.
+pub trait ProfileString: SerializableString {
+    /// Calls `f` with the parts of the string, for the events kept in memory.
+    fn with_components<R>(&self, f: impl FnOnce(&[StringComponent<'_>]) -> R) -> R;
+}
+
+impl ProfileString for str {
+    fn with_components<R>(&self, f: impl FnOnce(&[StringComponent<'_>]) -> R) -> R {
+        f(&[StringComponent::Value(self)])
+    }
+}
+
+impl ProfileString for [StringComponent<'_>] {
+    fn with_components<R>(&self, f: impl FnOnce(&[StringComponent<'_>]) -> R) -> R {
+        f(self)
+    }
+}
+
+/// Builds `EventId`s out of a label and arguments, like the builder of the
+/// same name in `measureme`, but with the strings going through the
+/// `SelfProfiler` so that they are also available to the events in memory.
+pub struct EventIdBuilder<'p> {
+    profiler: &'p SelfProfiler,
+}
+
+impl EventIdBuilder<'_> {
+    pub fn from_label(&self, label: StringId) -> EventId {
+        EventId::from_label(label)
+    }
+
+    pub fn from_label_and_arg(&self, label: StringId, arg: StringId) -> EventId {
+        self.from_label_and_args(label, &[arg])
+    }
+
+    pub fn from_label_and_args(&self, label: StringId, args: &[StringId]) -> EventId {
+        let mut components = SmallVec::<[StringComponent<'_>; 7]>::new();
+        components.push(StringComponent::Ref(label));
+        for &arg in args {
+            components.push(StringComponent::Value(SEPARATOR_BYTE));
+            components.push(StringComponent::Ref(arg));
+        }
+        EventId::from_label(self.profiler.alloc_string(&components[..]))
     }
 }
 
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>);
+pub struct TimingGuard<'a>(Option<(measureme::TimingGuard<'a>, Option<RecordedInterval<'a>>)>);
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -688,16 +799,24 @@ pub fn start(
         event_id: EventId,
     ) -> TimingGuard<'a> {
         let thread_id = get_thread_id();
+        let recorded = profiler.recorder.as_ref().map(|recorder| {
+            let id = RecordedId::String(event_id.to_string_id());
+            recorder.start_interval(event_kind, id, thread_id)
+        });
         let raw_profiler = &profiler.profiler;
         let timing_guard =
             raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
-        TimingGuard(Some(timing_guard))
+        TimingGuard(Some((timing_guard, recorded)))
     }
 
     #[inline]
     pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocationId) {
-        if let Some(guard) = self.0 {
+        if let Some((guard, recorded)) = self.0 {
             outline(|| {
+                let _recorded = recorded.map(|mut recorded| {
+                    recorded.id = RecordedId::Query(query_invocation_id.0);
+                    recorded
+                });
                 let event_id = StringId::new_virtual(query_invocation_id.0);
                 let event_id = EventId::from_virtual(event_id);
                 guard.finish_with_override_event_id(event_id);
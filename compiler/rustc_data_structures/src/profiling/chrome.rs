@@ -0,0 +1,111 @@
+//! Writing of recorded events in the Chrome trace event format, for
+//! `-Z self-profile-format=chrome`. The traces can be opened in
+//! `chrome://tracing`, Perfetto or Speedscope, without any `measureme` tools.
+//!
+//! Every thread gets a track of its own, interval events become complete
+//! (`X`) events, instant events become thread-scoped instant (`i`) events and
+//! integer events, like artifact sizes, become counter (`C`) events.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use super::recorder::{Event, Payload};
+use crate::fx::FxHashMap;
+
+pub(super) fn write_trace(
+    path: &Path,
+    events: &[Event],
+    thread_names: &FxHashMap<u32, String>,
+) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    let pid = process::id();
+
+    write!(file, r#"{{"traceEvents":["#)?;
+    let mut separator = "";
+
+    let mut threads: Vec<_> = thread_names.iter().collect();
+    threads.sort();
+    for (&tid, name) in threads {
+        write!(
+            file,
+            r#"{separator}
+{{"name":"thread_name","ph":"M","pid":{pid},"tid":{tid},"args":{{"name":{}}}}}"#,
+            JsonStr(name)
+        )?;
+        separator = ",";
+    }
+
+    for event in events {
+        let name = JsonStr(&event.label);
+        let cat = JsonStr(&event.kind);
+        let ts = Micros(event.start);
+        let tid = event.thread_id;
+        write!(file, "{separator}\n")?;
+        separator = ",";
+        write!(file, r#"{{"name":{name},"cat":{cat},"ts":{ts},"pid":{pid},"tid":{tid},"#)?;
+        match event.payload {
+            Payload::Interval { end } => {
+                let dur = Micros(end.saturating_sub(event.start));
+                write!(file, r#""ph":"X","dur":{dur},"args":{{"#)?;
+                write_args(&mut file, &event.args)?;
+                write!(file, "}}}}")?;
+            }
+            Payload::Instant => {
+                write!(file, r#""ph":"i","s":"t","args":{{"#)?;
+                write_args(&mut file, &event.args)?;
+                write!(file, "}}}}")?;
+            }
+            Payload::Integer(value) => {
+                // Each argument, like the name of an artifact, becomes a series
+                // of the counter named after the label.
+                let series = event.args.first().map_or(&event.label, |arg| arg);
+                write!(file, r#""ph":"C","args":{{{}:{value}}}}}"#, JsonStr(series))?;
+            }
+        }
+    }
+
+    writeln!(file, "\n]}}")?;
+    file.flush()
+}
+
+fn write_args(file: &mut impl Write, args: &[String]) -> io::Result<()> {
+    for (i, arg) in args.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        write!(file, r#"{separator}"arg{i}":{}"#, JsonStr(arg))?;
+    }
+    Ok(())
+}
+
+/// A timestamp or duration in microseconds, as used by the trace format.
+struct Micros(Duration);
+
+impl fmt::Display for Micros {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.3}", self.0.as_nanos() as f64 / 1000.0)
+    }
+}
+
+/// A string as a quoted JSON string literal.
+struct JsonStr<'a>(&'a str);
+
+impl fmt::Display for JsonStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        f.write_str("\"")
+    }
+}
@@ -0,0 +1,241 @@
+//! In-memory recording of self-profiling events, for the outputs the compiler
+//! produces by itself instead of leaving them to the `measureme` tools: the
+//! Chrome trace of `-Z self-profile-format=chrome` and the summary table of
+//! `-Z print-self-profile-summary`.
+//!
+//! The events are recorded alongside the ones written by `measureme`, with the
+//! same `StringId`s. The strings behind those are mirrored here as they are
+//! allocated, so that they can be resolved once profiling is done, including
+//! the query keys that are only allocated at the very end.
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use measureme::event_id::SEPARATOR_BYTE;
+use measureme::{StringComponent, StringId};
+use parking_lot::Mutex;
+
+use crate::fx::FxHashMap;
+
+use super::{chrome, summary};
+
+/// A part of a recorded string, like `measureme::StringComponent`.
+enum Component {
+    Value(String),
+    Ref(StringId),
+}
+
+/// What a recorded event refers to.
+#[derive(Clone, Copy)]
+pub(super) enum RecordedId {
+    String(StringId),
+    /// A query invocation, the string of which is only known once all
+    /// queries have run.
+    Query(u32),
+}
+
+pub(super) enum Payload {
+    Interval { end: Duration },
+    Instant,
+    Integer(u64),
+}
+
+struct RawEvent {
+    kind: StringId,
+    id: RecordedId,
+    thread_id: u32,
+    start: Duration,
+    payload: Payload,
+}
+
+/// A recorded event with its strings resolved.
+pub(super) struct Event {
+    pub kind: String,
+    pub label: String,
+    pub args: Vec<String>,
+    pub thread_id: u32,
+    /// The time since profiling started.
+    pub start: Duration,
+    pub payload: Payload,
+}
+
+#[derive(Default)]
+struct RecordedEvents {
+    events: Vec<RawEvent>,
+    thread_names: FxHashMap<u32, String>,
+}
+
+pub(super) struct EventRecorder {
+    start: Instant,
+    trace_path: Option<PathBuf>,
+    print_summary: bool,
+    strings: Mutex<FxHashMap<StringId, Vec<Component>>>,
+    queries: Mutex<FxHashMap<u32, StringId>>,
+    recorded: Mutex<RecordedEvents>,
+}
+
+impl EventRecorder {
+    pub(super) fn new(trace_path: Option<PathBuf>, print_summary: bool) -> EventRecorder {
+        EventRecorder {
+            start: Instant::now(),
+            trace_path,
+            print_summary,
+            strings: Default::default(),
+            queries: Default::default(),
+            recorded: Default::default(),
+        }
+    }
+
+    pub(super) fn record_string(&self, id: StringId, components: &[StringComponent<'_>]) {
+        let components = components
+            .iter()
+            .map(|component| match *component {
+                StringComponent::Value(s) => Component::Value(s.to_owned()),
+                StringComponent::Ref(id) => Component::Ref(id),
+            })
+            .collect();
+        self.strings.lock().insert(id, components);
+    }
+
+    pub(super) fn map_query(&self, query_invocation_id: u32, to: StringId) {
+        self.queries.lock().insert(query_invocation_id, to);
+    }
+
+    pub(super) fn start_interval(
+        &self,
+        kind: StringId,
+        id: RecordedId,
+        thread_id: u32,
+    ) -> RecordedInterval<'_> {
+        RecordedInterval { recorder: self, kind, id, thread_id, start: self.start.elapsed() }
+    }
+
+    pub(super) fn record_instant(&self, kind: StringId, id: RecordedId, thread_id: u32) {
+        let start = self.start.elapsed();
+        self.push(RawEvent { kind, id, thread_id, start, payload: Payload::Instant });
+    }
+
+    pub(super) fn record_integer(
+        &self,
+        kind: StringId,
+        id: RecordedId,
+        thread_id: u32,
+        value: u64,
+    ) {
+        let start = self.start.elapsed();
+        self.push(RawEvent { kind, id, thread_id, start, payload: Payload::Integer(value) });
+    }
+
+    fn push(&self, event: RawEvent) {
+        let mut recorded = self.recorded.lock();
+        // Name the track of each thread after the thread that recorded on it
+        // first, like the main thread or the workers of the parallel front-end.
+        recorded.thread_names.entry(event.thread_id).or_insert_with(|| {
+            thread::current()
+                .name()
+                .map_or_else(|| format!("thread {}", event.thread_id), str::to_owned)
+        });
+        recorded.events.push(event);
+    }
+
+    /// Resolves the strings of all events recorded so far.
+    fn resolve_events(&self, events: Vec<RawEvent>) -> Vec<Event> {
+        let strings = self.strings.lock();
+        let queries = self.queries.lock();
+        let mut cache = FxHashMap::default();
+
+        events
+            .into_iter()
+            .map(|event| {
+                let kind = resolve(&strings, &mut cache, event.kind);
+                let id = match event.id {
+                    RecordedId::String(id) => Some(id),
+                    RecordedId::Query(query_invocation_id) => {
+                        queries.get(&query_invocation_id).copied()
+                    }
+                };
+                let name = id.map_or_else(String::new, |id| resolve(&strings, &mut cache, id));
+
+                let mut parts = name.split(SEPARATOR_BYTE);
+                let label = match parts.next() {
+                    Some(label) if !label.is_empty() => label.to_owned(),
+                    // Events without a label of their own, like result
+                    // hashing, are named after their kind.
+                    _ => kind.clone(),
+                };
+                let args = parts.map(str::to_owned).collect();
+
+                Event {
+                    kind,
+                    label,
+                    args,
+                    thread_id: event.thread_id,
+                    start: event.start,
+                    payload: event.payload,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Drop for EventRecorder {
+    fn drop(&mut self) {
+        let RecordedEvents { events, thread_names } = std::mem::take(&mut *self.recorded.lock());
+        let events = self.resolve_events(events);
+
+        if let Some(path) = &self.trace_path {
+            if let Err(err) = chrome::write_trace(path, &events, &thread_names) {
+                eprintln!(
+                    "warning: failed to write self-profile trace `{}`: {err}",
+                    path.display()
+                );
+            }
+        }
+        if self.print_summary {
+            summary::print(&events);
+        }
+    }
+}
+
+/// Resolves a string with all the strings it refers to.
+fn resolve(
+    strings: &FxHashMap<StringId, Vec<Component>>,
+    cache: &mut FxHashMap<StringId, String>,
+    id: StringId,
+) -> String {
+    if let Some(s) = cache.get(&id) {
+        return s.clone();
+    }
+    let mut s = String::new();
+    for component in strings.get(&id).map_or(&[][..], |components| components) {
+        match component {
+            Component::Value(value) => s.push_str(value),
+            Component::Ref(id) => s.push_str(&resolve(strings, cache, *id)),
+        }
+    }
+    cache.insert(id, s.clone());
+    s
+}
+
+/// Records an interval event when dropped.
+pub(super) struct RecordedInterval<'a> {
+    recorder: &'a EventRecorder,
+    kind: StringId,
+    pub(super) id: RecordedId,
+    thread_id: u32,
+    start: Duration,
+}
+
+impl Drop for RecordedInterval<'_> {
+    fn drop(&mut self) {
+        let end = self.recorder.start.elapsed();
+        self.recorder.push(RawEvent {
+            kind: self.kind,
+            id: self.id,
+            thread_id: self.thread_id,
+            start: self.start,
+            payload: Payload::Interval { end },
+        });
+    }
+}
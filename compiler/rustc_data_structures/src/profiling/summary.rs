@@ -0,0 +1,146 @@
+//! The summary table of `-Z print-self-profile-summary`, which shows the same
+//! numbers as the `summarize` tool of `measureme` without having to install it.
+
+use std::cmp::Reverse;
+use std::time::Duration;
+
+use super::recorder::{Event, Payload};
+use crate::fx::FxHashMap;
+
+#[derive(Default)]
+struct Row {
+    /// The time spent in the item itself, excluding the items it started.
+    self_time: Duration,
+    time: Duration,
+    count: usize,
+    cache_hits: usize,
+    blocked_time: Duration,
+    incremental_load_time: Duration,
+}
+
+pub(super) fn print(events: &[Event]) {
+    let mut rows: Vec<_> = rows(events).into_iter().collect();
+    rows.sort_by_key(|&(label, ref row)| (Reverse(row.self_time), label));
+    let total_time: Duration = rows.iter().map(|(_, row)| row.self_time).sum();
+
+    let headers = [
+        "Item",
+        "Self time",
+        "% of total time",
+        "Time",
+        "Item count",
+        "Cache hits",
+        "Blocked time",
+        "Incremental load time",
+    ];
+    let table: Vec<[String; 8]> = rows
+        .iter()
+        .map(|(label, row)| {
+            let percent = if total_time.is_zero() {
+                0.0
+            } else {
+                row.self_time.as_secs_f64() / total_time.as_secs_f64() * 100.0
+            };
+            [
+                label.to_string(),
+                format!("{:.2?}", row.self_time),
+                format!("{percent:.3}"),
+                format!("{:.2?}", row.time),
+                row.count.to_string(),
+                row.cache_hits.to_string(),
+                format!("{:.2?}", row.blocked_time),
+                format!("{:.2?}", row.incremental_load_time),
+            ]
+        })
+        .collect();
+
+    let mut widths = headers.map(str::len);
+    for row in &table {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = widths.iter().map(|&width| "-".repeat(width + 2)).collect::<Vec<_>>().join("+");
+    println!("+{line}+");
+    print_row(&headers, &widths);
+    println!("+{line}+");
+    for row in &table {
+        print_row(row, &widths);
+    }
+    println!("+{line}+");
+    println!();
+    println!("Total cpu time: {total_time:?}");
+}
+
+fn print_row(cells: &[impl AsRef<str>], widths: &[usize]) {
+    let mut line = String::new();
+    for (i, (cell, &width)) in cells.iter().zip(widths).enumerate() {
+        let cell = cell.as_ref();
+        // The item names are aligned to the left, the numbers to the right.
+        if i == 0 {
+            line.push_str(&format!("| {cell:<width$} "));
+        } else {
+            line.push_str(&format!("| {cell:>width$} "));
+        }
+    }
+    println!("{line}|");
+}
+
+fn rows(events: &[Event]) -> FxHashMap<&str, Row> {
+    let mut rows: FxHashMap<&str, Row> = FxHashMap::default();
+
+    let mut intervals_by_thread: FxHashMap<u32, Vec<(Duration, Duration, &Event)>> =
+        FxHashMap::default();
+    for event in events {
+        match event.payload {
+            Payload::Interval { end } => {
+                intervals_by_thread.entry(event.thread_id).or_default().push((
+                    event.start,
+                    end,
+                    event,
+                ));
+            }
+            Payload::Instant if event.kind == "QueryCacheHit" => {
+                rows.entry(&event.label).or_default().cache_hits += 1;
+            }
+            Payload::Instant | Payload::Integer(_) => {}
+        }
+    }
+
+    for (_, mut intervals) in intervals_by_thread {
+        // The events of a thread are nested, so sorting them by their start,
+        // and the outer ones first when starting at the same time, puts every
+        // event right after the ones it is nested in.
+        intervals.sort_by_key(|&(start, end, _)| (start, Reverse(end)));
+
+        let mut self_times: Vec<Duration> =
+            intervals.iter().map(|&(start, end, _)| end.saturating_sub(start)).collect();
+        let mut stack: Vec<usize> = Vec::new();
+        for (i, &(start, end, _)) in intervals.iter().enumerate() {
+            while stack.last().is_some_and(|&parent| intervals[parent].1 <= start) {
+                stack.pop();
+            }
+            if let Some(&parent) = stack.last() {
+                self_times[parent] = self_times[parent].saturating_sub(end.saturating_sub(start));
+            }
+            stack.push(i);
+        }
+
+        for (&(start, end, event), self_time) in intervals.iter().zip(self_times) {
+            let row = rows.entry(&event.label).or_default();
+            let time = end.saturating_sub(start);
+            match &*event.kind {
+                "QueryBlocked" => row.blocked_time += time,
+                "IncrementalLoadResult" => row.incremental_load_time += time,
+                _ => {
+                    row.self_time += self_time;
+                    row.time += time;
+                    row.count += 1;
+                }
+            }
+        }
+    }
+
+    rows
+}
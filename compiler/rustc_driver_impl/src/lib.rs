@@ -384,6 +384,11 @@ fn run_compiler(
             return sess.compile_status();
         }
 
+        if let Some(action) = &sess.opts.unstable_opts.inspect_incremental {
+            inspect_incremental(&handler, sess, action);
+            return sess.compile_status();
+        }
+
         if sess.opts.unstable_opts.link_only {
             process_rlink(sess, compiler);
             return sess.compile_status();
@@ -696,6 +701,24 @@ fn list_metadata(
     }
 }
 
+fn inspect_incremental(
+    handler: &EarlyErrorHandler,
+    sess: &Session,
+    action: &config::InspectIncremental,
+) {
+    match sess.io.input {
+        Input::File(ref ifile) => {
+            let report = rustc_incremental::inspect_incremental_directory(sess, action, ifile);
+            if let Ok(report) = report {
+                safe_print!("{report}");
+            }
+        }
+        Input::Str { .. } => {
+            handler.early_error("cannot inspect incremental compilation data from stdin");
+        }
+    }
+}
+
 fn print_crate_info(
     handler: &EarlyErrorHandler,
     codegen_backend: &dyn CodegenBackend,
@@ -1253,7 +1276,11 @@ pub fn catch_fatal_errors<F: FnOnce() -> R, R>(f: F) -> Result<R, ErrorGuarantee
 
 /// Variant of `catch_fatal_errors` for the `interface::Result` return type
 /// that also computes the exit code.
+///
+/// This also prints the SARIF log of `--error-format=sarif` once every handler
+/// of the process is done, even if the closure ICEs.
 pub fn catch_with_exit_code(f: impl FnOnce() -> interface::Result<()>) -> i32 {
+    let _sarif_log = rustc_data_structures::defer(rustc_errors::sarif::emit_stderr_log);
     let result = catch_fatal_errors(f).and_then(|result| result);
     match result {
         Ok(()) => EXIT_SUCCESS,
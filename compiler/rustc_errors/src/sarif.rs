@@ -0,0 +1,598 @@
+//! A SARIF emitter for errors.
+//!
+//! [SARIF 2.1.0] is the static analysis interchange format understood by most
+//! code-scanning services. Unlike the JSON emitter, which streams one object per
+//! diagnostic, a SARIF log is a single document, so this emitter buffers every
+//! result instead of printing it.
+//!
+//! A compiler process uses several handlers (the early ones used before and
+//! while the session is created, then the session's), but must print a single
+//! log. So every emitter writing to stderr adds to one log shared by the whole
+//! process, which the driver prints with [`emit_stderr_log`] once it is done.
+//! An emitter with its own destination writes its own log when it is dropped.
+//!
+//! Each top-level diagnostic becomes one `result`:
+//!
+//! - the diagnostic level maps to the SARIF `level`,
+//! - the error code or lint name maps to the `ruleId`, and is described once in
+//!   the tool's `rules` using the explanation from the registry (if any),
+//! - primary spans become `locations` and secondary spans, as well as the spans
+//!   of sub-diagnostics, become `relatedLocations`,
+//! - every substitution of every suggestion becomes a separate entry in `fixes`.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{CodeSuggestion, DiagnosticId, FluentBundle, LazyFallbackBundle, Level, MultiSpan};
+use rustc_lint_defs::Applicability;
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_error_messages::FluentArgs;
+use rustc_span::Span;
+use std::error::Report;
+use std::io::{self, Write};
+use std::sync::{Mutex, PoisonError};
+
+use serde::Serialize;
+
+#[cfg(test)]
+mod tests;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The rules and results collected so far, and how to print them.
+struct Log {
+    pretty: bool,
+    ui_testing: bool,
+    tool_name: &'static str,
+    /// Rules referenced by `results`, keyed by rule id. The index of a rule in
+    /// this map is its `ruleIndex`.
+    rules: FxIndexMap<String, ReportingDescriptor>,
+    results: Vec<SarifResult>,
+}
+
+/// The log shared by every emitter writing to stderr, see [`emit_stderr_log`].
+static STDERR_LOG: Mutex<Option<Log>> = Mutex::new(None);
+
+impl Log {
+    fn new(pretty: bool) -> Log {
+        Log {
+            pretty,
+            ui_testing: false,
+            tool_name: "rustc",
+            rules: FxIndexMap::default(),
+            results: Vec::new(),
+        }
+    }
+
+    fn write(&self, dst: &mut dyn Write) -> io::Result<()> {
+        let version = if self.ui_testing { None } else { option_env!("CFG_VERSION") };
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: self.tool_name,
+                        version,
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: self.rules.values().collect(),
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        };
+        if self.pretty {
+            serde_json::to_writer_pretty(&mut *dst, &log)?
+        } else {
+            serde_json::to_writer(&mut *dst, &log)?
+        };
+        dst.write_all(b"\n")?;
+        dst.flush()
+    }
+
+    /// Returns the `ruleIndex` for `code`, registering the rule if it wasn't seen before.
+    fn rule_index(&mut self, registry: &Option<Registry>, code: &DiagnosticId) -> (String, usize) {
+        let id = match code {
+            DiagnosticId::Error(s) => s.clone(),
+            DiagnosticId::Lint { name, .. } => name.clone(),
+        };
+        let entry = self.rules.entry(id.clone());
+        let index = entry.index();
+        entry.or_insert_with(|| {
+            let explanation = registry
+                .as_ref()
+                .and_then(|registry| registry.try_find_description(&id).ok())
+                .map(|markdown| Message { text: markdown.to_owned(), markdown: None });
+            ReportingDescriptor { id: id.clone(), full_description: explanation }
+        });
+        (id, index)
+    }
+}
+
+/// Prints the log shared by the emitters created with [`SarifEmitter::stderr`]
+/// and [`SarifEmitter::basic`], if any was created.
+///
+/// This must be called once, when the process is done emitting diagnostics.
+pub fn emit_stderr_log() {
+    let log = STDERR_LOG.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(log) = log {
+        if let Err(e) = log.write(&mut io::BufWriter::new(io::stderr())) {
+            if !std::thread::panicking() {
+                panic!("failed to print SARIF log: {e:?}");
+            }
+        }
+    }
+}
+
+pub struct SarifEmitter {
+    /// Where this emitter writes its log when dropped, or `None` if it adds to
+    /// the log shared by the process instead.
+    dst: Option<IntoDynSyncSend<Box<dyn Write + Send>>>,
+    /// The log of this emitter, unused if `dst` is `None`.
+    log: Log,
+    registry: Option<Registry>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+}
+
+impl SarifEmitter {
+    pub fn stderr(
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+        pretty: bool,
+    ) -> SarifEmitter {
+        // Even a run without diagnostics prints an empty log.
+        STDERR_LOG
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(|| Log::new(pretty));
+        SarifEmitter {
+            dst: None,
+            log: Log::new(pretty),
+            registry,
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+        }
+    }
+
+    pub fn basic(
+        pretty: bool,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        SarifEmitter::stderr(
+            None,
+            Lrc::new(SourceMap::new(file_path_mapping)),
+            fluent_bundle,
+            fallback_bundle,
+            pretty,
+        )
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+        pretty: bool,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst: Some(IntoDynSyncSend(dst)),
+            log: Log::new(pretty),
+            registry,
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+        }
+    }
+
+    pub fn ui_testing(mut self, ui_testing: bool) -> Self {
+        self.with_log(|log, _| log.ui_testing = ui_testing);
+        self
+    }
+
+    /// Sets the name reported as the SARIF `tool.driver.name`, e.g. `rustdoc`.
+    pub fn tool_name(mut self, tool_name: &'static str) -> Self {
+        self.with_log(|log, _| log.tool_name = tool_name);
+        self
+    }
+
+    /// Runs `f` on the log this emitter adds to.
+    fn with_log<R>(&mut self, f: impl FnOnce(&mut Log, &Option<Registry>) -> R) -> R {
+        let SarifEmitter { dst, log, registry, .. } = self;
+        if dst.is_some() {
+            return f(log, registry);
+        }
+        let mut shared = STDERR_LOG.lock().unwrap_or_else(PoisonError::into_inner);
+        f(shared.get_or_insert_with(|| Log::new(log.pretty)), registry)
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        // The shared log is printed by `emit_stderr_log` instead.
+        let Some(dst) = &mut self.dst else { return };
+        if let Err(e) = self.log.write(&mut **dst) {
+            if !std::thread::panicking() {
+                panic!("failed to print SARIF log: {e:?}");
+            }
+        }
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        // "aborting due to N previous errors" and friends describe the session,
+        // not the code, so they have no place in the log.
+        if diag.level.is_failure_note() {
+            return;
+        }
+        let rule = diag
+            .code
+            .as_ref()
+            .map(|code| self.with_log(|log, registry| log.rule_index(registry, code)));
+        let result = SarifResult::from_errors_diagnostic(diag, rule, self);
+        self.with_log(|log, _| log.results.push(result));
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        // Explanations are attached to the rules instead.
+        false
+    }
+}
+
+// The following data types are provided just for serialisation. Field names
+// follow the SARIF 2.1.0 schema.
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'static str>,
+    information_uri: &'static str,
+    rules: Vec<&'a ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_description: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning", "note" or "none".
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    markdown: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    /// Only set for related locations, which must be uniquely identified
+    /// within a result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize, Clone, PartialEq, Eq)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, character offset.
+    start_column: usize,
+    end_line: usize,
+    /// 1-based, character offset, exclusive.
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Serialize)]
+struct FixProperties {
+    applicability: Applicability,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+impl SarifResult {
+    fn from_errors_diagnostic(
+        diag: &crate::Diagnostic,
+        rule: Option<(String, usize)>,
+        se: &SarifEmitter,
+    ) -> SarifResult {
+        let args = to_fluent_args(diag.args());
+        let mut text = se.translate_messages(&diag.message, &args).into_owned();
+
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        for span_label in diag.span.span_labels() {
+            let message = span_label
+                .label
+                .as_ref()
+                .map(|m| se.translate_message(m, &args).map_err(Report::new).unwrap().to_string());
+            let Some(physical_location) = PhysicalLocation::from_span(span_label.span, se) else {
+                continue;
+            };
+            if span_label.is_primary {
+                locations.push(Location {
+                    id: None,
+                    physical_location,
+                    message: message.map(Message::plain),
+                });
+            } else {
+                related_locations.push(Location {
+                    id: Some(related_locations.len()),
+                    physical_location,
+                    message: message.map(Message::plain),
+                });
+            }
+        }
+
+        // Notes and helps without a span of their own are folded into the
+        // message, the same way the human emitter prints them below the snippet.
+        for child in &diag.children {
+            let child_text = se.translate_messages(&child.message, &args);
+            let child_span = child.render_span.as_ref().unwrap_or(&child.span);
+            let child_locations = Self::locations_from_multispan(child_span, se);
+            if child_locations.is_empty() {
+                text.push_str(&format!("\n{}: {}", child.level.to_str(), child_text));
+            }
+            for physical_location in child_locations {
+                related_locations.push(Location {
+                    id: Some(related_locations.len()),
+                    physical_location,
+                    message: Some(Message::plain(format!(
+                        "{}: {}",
+                        child.level.to_str(),
+                        child_text
+                    ))),
+                });
+            }
+        }
+
+        let fixes = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .flat_map(|sugg| Fix::from_suggestion(sugg, &args, se))
+            .collect();
+
+        let (rule_id, rule_index) = match rule {
+            Some((id, index)) => (Some(id), Some(index)),
+            None => (None, None),
+        };
+        SarifResult {
+            rule_id,
+            rule_index,
+            level: sarif_level(diag.level),
+            message: Message::plain(text),
+            locations,
+            related_locations,
+            fixes,
+        }
+    }
+
+    fn locations_from_multispan(msp: &MultiSpan, se: &SarifEmitter) -> Vec<PhysicalLocation> {
+        msp.primary_spans()
+            .iter()
+            .filter_map(|&span| PhysicalLocation::from_span(span, se))
+            .collect()
+    }
+}
+
+impl Message {
+    fn plain(text: String) -> Message {
+        Message { text, markdown: None }
+    }
+}
+
+impl PhysicalLocation {
+    fn from_span(span: Span, se: &SarifEmitter) -> Option<PhysicalLocation> {
+        if span.is_dummy() {
+            return None;
+        }
+        let (artifact_location, region) = artifact_region(span, se);
+        Some(PhysicalLocation { artifact_location, region })
+    }
+}
+
+impl Fix {
+    /// Creates one fix per substitution of `suggestion`, since SARIF consumers
+    /// apply a fix as a whole.
+    fn from_suggestion(
+        suggestion: &CodeSuggestion,
+        args: &FluentArgs<'_>,
+        se: &SarifEmitter,
+    ) -> Vec<Fix> {
+        let description =
+            se.translate_message(&suggestion.msg, args).map_err(Report::new).unwrap().to_string();
+        suggestion
+            .substitutions
+            .iter()
+            .map(|substitution| {
+                let mut artifact_changes: Vec<ArtifactChange> = vec![];
+                for part in &substitution.parts {
+                    let (artifact_location, deleted_region) = artifact_region(part.span, se);
+                    let replacement = Replacement {
+                        deleted_region,
+                        inserted_content: ArtifactContent { text: part.snippet.clone() },
+                    };
+                    match artifact_changes
+                        .iter_mut()
+                        .find(|change| change.artifact_location == artifact_location)
+                    {
+                        Some(change) => change.replacements.push(replacement),
+                        None => artifact_changes.push(ArtifactChange {
+                            artifact_location,
+                            replacements: vec![replacement],
+                        }),
+                    }
+                }
+                Fix {
+                    description: Message::plain(description.clone()),
+                    artifact_changes,
+                    properties: FixProperties { applicability: suggestion.applicability },
+                }
+            })
+            .collect()
+    }
+}
+
+fn artifact_region(span: Span, se: &SarifEmitter) -> (ArtifactLocation, Region) {
+    let start = se.sm.lookup_char_pos(span.lo());
+    let end = se.sm.lookup_char_pos(span.hi());
+    let byte_start = start.file.original_relative_byte_pos(span.lo()).0;
+    let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+    let file_name = se.sm.filename_for_diagnostics(&start.file.name).to_string();
+    let region = Region {
+        start_line: start.line,
+        start_column: start.col.0 + 1,
+        end_line: end.line,
+        end_column: end.col.0 + 1,
+        byte_offset: byte_start,
+        byte_length: byte_end - byte_start,
+    };
+    (ArtifactLocation { uri: path_to_uri_reference(&file_name) }, region)
+}
+
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Bug | Level::DelayedBug | Level::Fatal | Level::Error { .. } => "error",
+        Level::Warning(_) => "warning",
+        Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp => "note",
+        Level::FailureNote | Level::Allow | Level::Expect(_) => "none",
+    }
+}
+
+/// Turns a file name as printed in diagnostics into a relative URI reference,
+/// percent-encoding anything that isn't allowed in a URI path.
+fn path_to_uri_reference(path: &str) -> String {
+    let mut uri = String::with_capacity(path.len());
+    // A colon in the first segment would make it look like a scheme, as in `C:/foo`.
+    let mut first_segment = true;
+    for b in path.bytes() {
+        match b {
+            b'\\' | b'/' => {
+                first_segment = false;
+                uri.push('/');
+            }
+            b':' if !first_segment => uri.push(':'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                uri.push(b as char)
+            }
+            _ => uri.push_str(&format!("%{b:02X}")),
+        }
+    }
+    uri
+}
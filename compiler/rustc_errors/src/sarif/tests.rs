@@ -0,0 +1,134 @@
+use super::*;
+
+use crate::sarif::SarifEmitter;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::{DiagnosticId, Handler};
+use rustc_span::{BytePos, Span};
+
+use std::path::Path;
+use std::str;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+/// Runs `f` against a handler backed by a `SarifEmitter` and returns the
+/// log written once the handler is dropped.
+fn with_sarif_log(code: &str, f: impl FnOnce(&Handler)) -> Value {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("src/my file.rs").to_owned().into(), code.to_owned());
+        let fallback_bundle =
+            crate::fallback_fluent_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let se = SarifEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            None,
+            fallback_bundle,
+            false,
+        )
+        .ui_testing(true);
+
+        let handler = Handler::with_emitter(Box::new(se));
+        f(&handler);
+        drop(handler);
+
+        let bytes = output.lock().unwrap();
+        serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap()
+    })
+}
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::with_root_ctxt(BytePos(lo), BytePos(hi))
+}
+
+#[test]
+fn empty_log() {
+    let log = with_sarif_log("", |_| {});
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "rustc");
+    assert_eq!(run["results"], Value::Array(vec![]));
+}
+
+#[test]
+fn result_locations() {
+    let log = with_sarif_log("let x = 1;\nlet y = x;\n", |handler| {
+        handler
+            .struct_span_err(span(15, 16), "foo")
+            .code(DiagnosticId::Error("E0999".to_owned()))
+            .span_label(span(15, 16), "primary")
+            .span_label(span(4, 5), "secondary")
+            .emit();
+    });
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "E0999");
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "E0999");
+    assert_eq!(result["ruleIndex"], 0);
+    assert_eq!(result["level"], "error");
+    assert_eq!(result["message"]["text"], "foo");
+
+    let location = &result["locations"][0];
+    assert_eq!(location["message"]["text"], "primary");
+    let physical = &location["physicalLocation"];
+    assert_eq!(physical["artifactLocation"]["uri"], "src/my%20file.rs");
+    assert_eq!(physical["region"]["startLine"], 2);
+    assert_eq!(physical["region"]["startColumn"], 5);
+    assert_eq!(physical["region"]["endColumn"], 6);
+    assert_eq!(physical["region"]["byteOffset"], 15);
+    assert_eq!(physical["region"]["byteLength"], 1);
+
+    let related = &result["relatedLocations"][0];
+    assert_eq!(related["id"], 0);
+    assert_eq!(related["message"]["text"], "secondary");
+    assert_eq!(related["physicalLocation"]["region"]["startLine"], 1);
+}
+
+#[test]
+fn suggestion_fixes() {
+    let log = with_sarif_log("let x = 1;\n", |handler| {
+        handler
+            .struct_span_warn(span(4, 5), "bar")
+            .span_suggestion(span(4, 5), "rename it", "_x", Applicability::MachineApplicable)
+            .emit();
+    });
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["level"], "warning");
+    assert_eq!(result["ruleId"], Value::Null);
+
+    let fix = &result["fixes"][0];
+    assert_eq!(fix["description"]["text"], "rename it");
+    assert_eq!(fix["properties"]["applicability"], "MachineApplicable");
+    let replacement = &fix["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["insertedContent"]["text"], "_x");
+    assert_eq!(replacement["deletedRegion"]["byteOffset"], 4);
+    assert_eq!(replacement["deletedRegion"]["byteLength"], 1);
+}
+
+#[test]
+fn uri_references() {
+    assert_eq!(path_to_uri_reference("src/my file.rs"), "src/my%20file.rs");
+    assert_eq!(path_to_uri_reference("src\\lib.rs"), "src/lib.rs");
+    assert_eq!(path_to_uri_reference("C:/foo/lib.rs"), "C%3A/foo/lib.rs");
+    assert_eq!(path_to_uri_reference("C:\\foo\\lib.rs"), "C%3A/foo/lib.rs");
+    assert_eq!(path_to_uri_reference("src/a:b.rs"), "src/a:b.rs");
+}
@@ -760,6 +760,10 @@ pub struct BuiltinAttribute {
         "the `#[rustc_reservation_impl]` attribute is internally used \
          for reserving for `for<T> From<!> for T` impl"
     ),
+    rustc_attr!(
+        rustc_test_fixture_marker, Normal, template!(NameValueStr: "name"), WarnFollowing,
+        "the `#[rustc_test_fixture_marker]` attribute is used internally to track test fixtures",
+    ),
     rustc_attr!(
         rustc_test_marker, Normal, template!(NameValueStr: "name"), WarnFollowing,
         "the `#[rustc_test_marker]` attribute is used internally to track tests",
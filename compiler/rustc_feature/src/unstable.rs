@@ -448,6 +448,10 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, extended_varargs_abi_support, "1.65.0", Some(100189), None),
     /// Allows defining `extern type`s.
     (unstable, extern_types, "1.23.0", Some(43467), None),
+    /// Allows the use of `f128` type.
+    (unstable, f128, "CURRENT_RUSTC_VERSION", None, None),
+    /// Allows the use of `f16` type.
+    (unstable, f16, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows the use of `#[ffi_const]` on foreign functions.
     (unstable, ffi_const, "1.45.0", Some(58328), None),
     /// Allows the use of `#[ffi_pure]` on foreign functions.
@@ -470,6 +474,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (incomplete, generic_const_items, "1.73.0", Some(113521), None),
     /// Allows using `..=X` as a patterns in slices.
     (unstable, half_open_range_patterns_in_slices, "1.66.0", Some(67264), None),
+    /// Allows hexadecimal float literals like `0x1.8p3`.
+    (unstable, hex_float_literals, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows `if let` guard in match arms.
     (unstable, if_let_guard, "1.47.0", Some(51114), None),
     /// Allows `impl Trait` to be used inside associated types (RFC 2515).
@@ -52,6 +52,11 @@ incremental_finalized_gc_failed =
 incremental_hard_link_failed =
     hard linking files in the incremental compilation cache failed. copying files instead. consider moving the cache directory to a file system which supports hard linking in session dir `{$path}`
 
+incremental_inspect_incompatible =
+    `{$path}` does not exist or was not produced by this version of the compiler
+
+incremental_inspect_read = failed to read incremental compilation data from `{$path}`: {$err}
+
 incremental_invalid_gc_failed =
     failed to garbage collect invalid incremental compilation session directory `{$path}`: {$err}
 
@@ -99,4 +104,7 @@ incremental_unrecognized_depnode_label = dep-node label `{$label}` not recognize
 
 incremental_write_dep_graph = failed to write dependency graph to `{$path}`: {$err}
 
+incremental_write_explanation =
+    failed to write the incremental compilation report to `{$path}`: {$err}
+
 incremental_write_new = failed to write {$name} to `{$path}`: {$err}
@@ -248,6 +248,19 @@ pub struct SessionGcFailed<'a> {
     pub err: std::io::Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(incremental_inspect_incompatible)]
+pub struct InspectIncompatible<'a> {
+    pub path: &'a Path,
+}
+
+#[derive(Diagnostic)]
+#[diag(incremental_inspect_read)]
+pub struct InspectRead<'a> {
+    pub path: &'a Path,
+    pub err: std::io::Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(incremental_assert_not_loaded)]
 pub struct AssertNotLoaded;
@@ -270,6 +283,13 @@ pub struct LoadDepGraph {
     pub err: std::io::Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(incremental_write_explanation)]
+pub struct WriteExplanation<'a> {
+    pub path: &'a Path,
+    pub err: std::io::Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(incremental_write_dep_graph)]
 pub struct WriteDepGraph<'a> {
@@ -22,6 +22,7 @@
 pub use persist::finalize_session_directory;
 pub use persist::in_incr_comp_dir;
 pub use persist::in_incr_comp_dir_sess;
+pub use persist::inspect_incremental_directory;
 pub use persist::load_query_result_cache;
 pub use persist::save_dep_graph;
 pub use persist::save_work_product_index;
@@ -0,0 +1,163 @@
+//! Writes the `-Z incremental-explain` report, which explains why queries had to be executed
+//! again instead of being reused from the incremental compilation cache.
+//!
+//! When the dependency graph of the previous session can be used, each recomputed query is
+//! traced back through the dependencies which prevented it from being marked green, up to the
+//! changed input at the root of the chain. Otherwise, the report only says why the previous
+//! session was discarded.
+
+use crate::errors;
+use rustc_middle::dep_graph::{DepContext, DepNode, FingerprintStyle, RecomputedNode};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::Session;
+use rustc_span::def_id::{DefId, DefPathHash, LocalDefId, LOCAL_CRATE};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Whether the dependency graph of the previous session could be used.
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PreviousSession {
+    Loaded,
+    /// There was no previous session, or it was written by another version of the compiler.
+    Unavailable,
+    /// The tracked command-line options changed, e.g. a `-C` flag.
+    CommandLineArgsChanged,
+}
+
+#[derive(Serialize)]
+struct Report {
+    previous_session: PreviousSession,
+    recomputed: Vec<Recomputed>,
+}
+
+#[derive(Serialize)]
+struct Recomputed {
+    query: String,
+    node: String,
+    result_changed: bool,
+    /// The chain of dependencies which prevented the query from being reused, the last one
+    /// being `root`.
+    causes: Vec<String>,
+    root: Root,
+}
+
+/// The changed input at the root of a chain of recomputed queries.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Root {
+    /// An item of the local crate, whose source changed.
+    Source { node: String, span: String },
+    /// Something from an upstream crate, whose crate hash changed.
+    UpstreamCrate { node: String, krate: String },
+    /// Any other input, e.g. the result of an `eval_always` query.
+    Other { node: String },
+}
+
+/// Writes the report for a session which could not use the dependency graph of the previous
+/// session, if it was requested.
+pub(crate) fn explain_discarded_cache(sess: &Session, previous_session: PreviousSession) {
+    if let Some(path) = &sess.opts.unstable_opts.incremental_explain {
+        let report = Report { previous_session, recomputed: Vec::new() };
+        if let Err(err) = write_report(path, &report) {
+            sess.emit_err(errors::WriteExplanation { path, err });
+        }
+    }
+}
+
+/// Writes the report for the queries recomputed during this session.
+pub(crate) fn explain_recomputed_queries(tcx: TyCtxt<'_>, path: &Path) {
+    // When nothing was recorded, `explain_discarded_cache` already wrote the report.
+    let Some(recomputed) = tcx.dep_graph.recomputed_nodes() else { return };
+
+    let recomputed = recomputed
+        .into_iter()
+        .filter_map(|RecomputedNode { node, result_changed, causes }| {
+            let root = causes.last().map(|&root| describe_root(tcx, root))?;
+            Some(Recomputed {
+                query: format!("{:?}", node.kind),
+                node: describe_node(tcx, node),
+                result_changed,
+                causes: causes.into_iter().map(|cause| describe_node(tcx, cause)).collect(),
+                root,
+            })
+        })
+        .collect();
+
+    let report = Report { previous_session: PreviousSession::Loaded, recomputed };
+    if let Err(err) = write_report(path, &report) {
+        tcx.sess.emit_err(errors::WriteExplanation { path, err });
+    }
+}
+
+fn write_report(path: &Path, report: &Report) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut file, report)?;
+    file.flush()
+}
+
+/// What the key of a node from the previous session refers to in this session.
+enum NodeKey {
+    Local(DefId),
+    Upstream(DefPathHash),
+    Unknown,
+}
+
+/// Looks up the key of a node from the previous session. Unlike `DepNode::extract_def_id`, this
+/// does not panic on items which were removed since the previous session.
+fn node_key(tcx: TyCtxt<'_>, node: DepNode) -> NodeKey {
+    if tcx.fingerprint_style(node.kind) != FingerprintStyle::DefPathHash {
+        return NodeKey::Unknown;
+    }
+
+    let hash = DefPathHash(node.hash.into());
+    if hash.stable_crate_id() != tcx.stable_crate_id(LOCAL_CRATE) {
+        return NodeKey::Upstream(hash);
+    }
+    match tcx.def_path_hash_to_def_index_map().get(&hash) {
+        Some(local_def_index) => NodeKey::Local(LocalDefId { local_def_index }.to_def_id()),
+        None => NodeKey::Unknown,
+    }
+}
+
+/// Formats the node like its `Debug` implementation, which cannot be used for nodes of the
+/// previous session.
+fn describe_node(tcx: TyCtxt<'_>, node: DepNode) -> String {
+    let key = match node_key(tcx, node) {
+        NodeKey::Local(def_id) => tcx.def_path_debug_str(def_id),
+        NodeKey::Upstream(hash) => match upstream_crate_name(tcx, hash) {
+            Some(krate) => format!("{krate}[{}]", node.hash),
+            None => node.hash.to_string(),
+        },
+        NodeKey::Unknown => {
+            tcx.dep_graph.dep_node_debug_str(node).unwrap_or_else(|| node.hash.to_string())
+        }
+    };
+    format!("{:?}({key})", node.kind)
+}
+
+fn describe_root(tcx: TyCtxt<'_>, root: DepNode) -> Root {
+    let node = describe_node(tcx, root);
+    match node_key(tcx, root) {
+        NodeKey::Local(def_id) => {
+            let span = tcx.sess.source_map().span_to_diagnostic_string(tcx.def_span(def_id));
+            Root::Source { node, span }
+        }
+        NodeKey::Upstream(hash) => match upstream_crate_name(tcx, hash) {
+            Some(krate) => Root::UpstreamCrate { node, krate },
+            None => Root::Other { node },
+        },
+        NodeKey::Unknown => Root::Other { node },
+    }
+}
+
+/// Returns the name of the upstream crate which defines `hash`, if it is still a dependency.
+fn upstream_crate_name(tcx: TyCtxt<'_>, hash: DefPathHash) -> Option<String> {
+    let stable_crate_id = hash.stable_crate_id();
+    tcx.crates(())
+        .iter()
+        .find(|&&cnum| tcx.stable_crate_id(cnum) == stable_crate_id)
+        .map(|&cnum| tcx.crate_name(cnum).to_string())
+}
@@ -124,9 +124,9 @@
 mod tests;
 
 const LOCK_FILE_EXT: &str = ".lock";
-const DEP_GRAPH_FILENAME: &str = "dep-graph.bin";
+pub(crate) const DEP_GRAPH_FILENAME: &str = "dep-graph.bin";
 const STAGING_DEP_GRAPH_FILENAME: &str = "dep-graph.part.bin";
-const WORK_PRODUCTS_FILENAME: &str = "work-products.bin";
+pub(crate) const WORK_PRODUCTS_FILENAME: &str = "work-products.bin";
 const QUERY_CACHE_FILENAME: &str = "query-cache.bin";
 
 // We encode integers using the following base, so they are shorter than decimal
@@ -561,7 +561,7 @@ fn is_finalized(directory_name: &str) -> bool {
     !directory_name.ends_with("-working")
 }
 
-fn is_session_directory(directory_name: &str) -> bool {
+pub(crate) fn is_session_directory(directory_name: &str) -> bool {
     directory_name.starts_with("s-") && !directory_name.ends_with(LOCK_FILE_EXT)
 }
 
@@ -569,7 +569,9 @@ fn is_session_directory_lock_file(file_name: &str) -> bool {
     file_name.starts_with("s-") && file_name.ends_with(LOCK_FILE_EXT)
 }
 
-fn extract_timestamp_from_session_dir(directory_name: &str) -> Result<SystemTime, &'static str> {
+pub(crate) fn extract_timestamp_from_session_dir(
+    directory_name: &str,
+) -> Result<SystemTime, &'static str> {
     if !is_session_directory(directory_name) {
         return Err("not a directory");
     }
@@ -634,6 +636,11 @@ pub(crate) fn garbage_collect_session_directories(sess: &Session) -> io::Result<
     );
 
     let crate_directory = session_directory.parent().unwrap();
+    garbage_collect_crate_directory(sess, crate_directory)
+}
+
+/// Runs garbage collection for all the sessions of a crate directory.
+fn garbage_collect_crate_directory(sess: &Session, crate_directory: &Path) -> io::Result<()> {
     debug!(
         "garbage_collect_session_directories() - crate directory: {}",
         crate_directory.display()
@@ -844,6 +851,92 @@ pub(crate) fn garbage_collect_session_directories(sess: &Session) -> io::Result<
     Ok(())
 }
 
+/// Runs garbage collection for every crate directory of the incremental directory `incr_dir`,
+/// which may be shared by many crates. If `max_age` is given, crates whose most recent session
+/// directory is older than that are deleted altogether.
+pub(crate) fn prune_incremental_directory(
+    sess: &Session,
+    incr_dir: &Path,
+    max_age: Option<Duration>,
+) -> io::Result<()> {
+    for dir_entry in incr_dir.read_dir()? {
+        let Ok(dir_entry) = dir_entry else {
+            // Ignore any errors
+            continue;
+        };
+        if !dir_entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            continue;
+        }
+
+        let crate_directory = dir_entry.path();
+        garbage_collect_crate_directory(sess, &crate_directory)?;
+
+        if let Some(max_age) = max_age {
+            delete_crate_directory_if_unused(sess, &crate_directory, max_age)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn delete_crate_directory_if_unused(
+    sess: &Session,
+    crate_directory: &Path,
+    max_age: Duration,
+) -> io::Result<()> {
+    let mut session_directories = Vec::new();
+    for dir_entry in crate_directory.read_dir()? {
+        let Ok(dir_entry) = dir_entry else {
+            // Ignore any errors
+            continue;
+        };
+
+        let entry_name = dir_entry.file_name();
+        let entry_name = entry_name.to_string_lossy();
+        if is_session_directory(&entry_name) {
+            let Ok(timestamp) = extract_timestamp_from_session_dir(&entry_name) else {
+                // Leave directories that we don't understand alone.
+                return Ok(());
+            };
+            session_directories.push((timestamp, dir_entry.path()));
+        }
+    }
+
+    let Some(deadline) = SystemTime::now().checked_sub(max_age) else { return Ok(()) };
+    if session_directories.iter().any(|&(timestamp, _)| timestamp >= deadline) {
+        return Ok(());
+    }
+
+    // Make sure that no other process is using any of the sessions, and hold on to the locks
+    // until the directory is gone.
+    let mut locks = Vec::with_capacity(session_directories.len());
+    for (_, session_directory) in &session_directories {
+        match flock::Lock::new(
+            &lock_file_path(session_directory),
+            false, // don't wait
+            false, // don't create the lock-file
+            true,  // get an exclusive lock
+        ) {
+            Ok(lock) => locks.push(lock),
+            Err(_) => {
+                debug!(
+                    "prune_incremental_directory() - not collecting `{}`, still in use",
+                    crate_directory.display()
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    debug!("prune_incremental_directory() - deleting `{}`", crate_directory.display());
+    if let Err(err) = safe_remove_dir_all(crate_directory) {
+        sess.emit_warning(errors::SessionGcFailed { path: crate_directory, err });
+    }
+    drop(locks);
+
+    Ok(())
+}
+
 fn delete_old(sess: &Session, path: &Path) {
     debug!("garbage_collect_session_directories() - deleting `{}`", path.display());
 
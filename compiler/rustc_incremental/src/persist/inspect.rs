@@ -0,0 +1,351 @@
+//! Human readable reports about incremental compilation directories, as requested with
+//! `-Z inspect-incremental`.
+//!
+//! The data files of a session directory can only be decoded by the compiler version which
+//! wrote them, so the reports must be produced by that same compiler.
+
+use crate::errors;
+use crate::persist::data::SerializedWorkProduct;
+use crate::persist::file_format;
+use crate::persist::fs::*;
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::memmap::Mmap;
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
+use rustc_middle::dep_graph::{
+    dep_kinds, DepKind, DepNode, DepsType, SerializedDepGraph, SerializedDepNodeIndex,
+};
+use rustc_serialize::opaque::MemDecoder;
+use rustc_serialize::Decodable;
+use rustc_session::config::InspectIncremental;
+use rustc_session::Session;
+use rustc_span::ErrorGuaranteed;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs as std_fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The maximum number of changed nodes listed for a single invalidated codegen unit.
+const MAX_CHANGES_PER_CGU: usize = 10;
+
+/// Runs the `-Z inspect-incremental` action on the directory `input`, and returns the report.
+pub fn inspect_incremental_directory(
+    sess: &Session,
+    action: &InspectIncremental,
+    input: &Path,
+) -> Result<String, ErrorGuaranteed> {
+    match action {
+        InspectIncremental::List => list_session(sess, input),
+        InspectIncremental::Diff(old) => diff_sessions(sess, old, input),
+        InspectIncremental::Prune { max_age_days } => {
+            let max_age = max_age_days.map(|days| Duration::from_secs(days * 24 * 60 * 60));
+            prune(sess, input, max_age)
+        }
+    }
+}
+
+/// The decoded contents of a session directory.
+struct SessionData {
+    commandline_args_hash: u64,
+    dep_graph: SerializedDepGraph,
+    work_products: Vec<SerializedWorkProduct>,
+}
+
+impl SessionData {
+    fn load(sess: &Session, session_dir: &Path) -> Result<SessionData, ErrorGuaranteed> {
+        let (bytes, start_pos) =
+            read_file(sess, &in_incr_comp_dir(session_dir, DEP_GRAPH_FILENAME))?;
+        let mut decoder = MemDecoder::new(&bytes, start_pos);
+        let commandline_args_hash = u64::decode(&mut decoder);
+        let dep_graph = SerializedDepGraph::decode::<DepsType>(&mut decoder);
+
+        // Sessions which did not reach code generation have no work products.
+        let work_products_path = in_incr_comp_dir(session_dir, WORK_PRODUCTS_FILENAME);
+        let work_products = if work_products_path.exists() {
+            let (bytes, start_pos) = read_file(sess, &work_products_path)?;
+            let mut decoder = MemDecoder::new(&bytes, start_pos);
+            let mut work_products: Vec<SerializedWorkProduct> = Decodable::decode(&mut decoder);
+            work_products.sort_by(|a, b| a.work_product.cgu_name.cmp(&b.work_product.cgu_name));
+            work_products
+        } else {
+            Vec::new()
+        };
+
+        Ok(SessionData { commandline_args_hash, dep_graph, work_products })
+    }
+
+    fn cgu_names(&self) -> impl Iterator<Item = &str> {
+        self.work_products.iter().map(|swp| &swp.work_product.cgu_name[..])
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = SerializedDepNodeIndex> {
+        (0..self.dep_graph.node_count()).map(SerializedDepNodeIndex::from_usize)
+    }
+}
+
+fn read_file(sess: &Session, path: &Path) -> Result<(Mmap, usize), ErrorGuaranteed> {
+    match file_format::read_file(path, false, sess.is_nightly_build(), sess.cfg_version) {
+        Ok(Some(data_and_pos)) => Ok(data_and_pos),
+        Ok(None) => Err(sess.emit_err(errors::InspectIncompatible { path })),
+        Err(err) => Err(sess.emit_err(errors::InspectRead { path, err })),
+    }
+}
+
+/// Returns the node of the dependency graph which represents the codegen unit `cgu_name`.
+///
+/// This mirrors `make_compile_codegen_unit`, whose key hashing does not depend on the
+/// `TyCtxt` it requires.
+fn cgu_dep_node(cgu_name: &str) -> DepNode {
+    let mut hasher = StableHasher::new();
+    cgu_name.hash_stable(&mut (), &mut hasher);
+    DepNode { kind: dep_kinds::CompileCodegenUnit, hash: hasher.finish::<Fingerprint>().into() }
+}
+
+fn list_session(sess: &Session, session_dir: &Path) -> Result<String, ErrorGuaranteed> {
+    let data = SessionData::load(sess, session_dir)?;
+    let mut report = String::new();
+
+    writeln!(report, "session directory `{}`", session_dir.display()).unwrap();
+    writeln!(report, "command-line arguments hash: {:016x}", data.commandline_args_hash).unwrap();
+
+    let mut files = list_files(session_dir);
+    files.sort();
+    writeln!(report, "\nfiles ({}):", files.len()).unwrap();
+    for (name, size) in &files {
+        writeln!(report, "{size:>14}  {name}").unwrap();
+    }
+    writeln!(report, "{:>14}  total", files.iter().map(|(_, size)| size).sum::<u64>()).unwrap();
+
+    writeln!(report, "\nwork products ({}):", data.work_products.len()).unwrap();
+    for swp in &data.work_products {
+        writeln!(report, "  {}", swp.work_product.cgu_name).unwrap();
+        for (kind, file) in swp.work_product.saved_files.items().into_sorted_stable_ord() {
+            let size = file_size(&in_incr_comp_dir(session_dir, file));
+            writeln!(report, "    {kind:<6} {file} ({size} bytes)").unwrap();
+        }
+    }
+
+    let graph = &data.dep_graph;
+    let mut kinds: FxHashMap<DepKind, usize> = FxHashMap::default();
+    for index in data.node_indices() {
+        *kinds.entry(graph.index_to_node(index).kind).or_default() += 1;
+    }
+    let mut kinds: Vec<_> =
+        kinds.into_iter().map(|(kind, count)| (format!("{kind:?}"), count)).collect();
+    kinds.sort_by(|(a_kind, a_count), (b_kind, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_kind.cmp(b_kind))
+    });
+    writeln!(report, "\ndependency graph nodes by kind ({}):", kinds.len()).unwrap();
+    for (kind, count) in kinds {
+        writeln!(report, "{count:>14}  {kind}").unwrap();
+    }
+
+    writeln!(report, "\ndependency graph nodes ({}):", graph.node_count()).unwrap();
+    for index in data.node_indices() {
+        writeln!(
+            report,
+            "{:>10}  {}  {:?} ({} edges)",
+            index.as_usize(),
+            graph.fingerprint_by_index(index).to_hex(),
+            graph.index_to_node(index),
+            graph.edge_targets_from(index).count(),
+        )
+        .unwrap();
+    }
+
+    Ok(report)
+}
+
+fn diff_sessions(
+    sess: &Session,
+    old_dir: &Path,
+    new_dir: &Path,
+) -> Result<String, ErrorGuaranteed> {
+    let old = SessionData::load(sess, old_dir)?;
+    let new = SessionData::load(sess, new_dir)?;
+    let mut report = String::new();
+
+    writeln!(report, "comparing `{}` with `{}`", old_dir.display(), new_dir.display()).unwrap();
+    if old.commandline_args_hash != new.commandline_args_hash {
+        writeln!(report, "the command-line arguments differ, every codegen unit was invalidated")
+            .unwrap();
+        return Ok(report);
+    }
+
+    let diff = GraphDiff::new(&old.dep_graph, &new.dep_graph);
+    let (changed, removed) =
+        old.node_indices().fold((0, 0), |(changed, removed), index| {
+            match diff.new_fingerprint(index) {
+                None => (changed, removed + 1),
+                Some(fingerprint) if fingerprint != old.dep_graph.fingerprint_by_index(index) => {
+                    (changed + 1, removed)
+                }
+                Some(_) => (changed, removed),
+            }
+        });
+    let added = new.node_indices().filter(|&index| {
+        old.dep_graph.node_to_index_opt(&new.dep_graph.index_to_node(index)).is_none()
+    });
+    writeln!(
+        report,
+        "dependency graph nodes: {changed} changed, {} added, {removed} removed",
+        added.count(),
+    )
+    .unwrap();
+
+    let old_cgus: FxHashSet<&str> = old.cgu_names().collect();
+    let new_cgus: FxHashSet<&str> = new.cgu_names().collect();
+    let all_cgus: BTreeSet<&str> = old_cgus.union(&new_cgus).copied().collect();
+
+    writeln!(report, "\ncodegen units ({}):", all_cgus.len()).unwrap();
+    for cgu_name in all_cgus {
+        if !new_cgus.contains(cgu_name) {
+            writeln!(report, "  {cgu_name}: removed").unwrap();
+            continue;
+        }
+        let old_index = old.dep_graph.node_to_index_opt(&cgu_dep_node(cgu_name));
+        let Some(old_index) = old_index.filter(|_| old_cgus.contains(cgu_name)) else {
+            writeln!(report, "  {cgu_name}: added").unwrap();
+            continue;
+        };
+
+        let changes = diff.root_changes(old_index);
+        if changes.is_empty() {
+            writeln!(report, "  {cgu_name}: reused").unwrap();
+            continue;
+        }
+
+        writeln!(report, "  {cgu_name}: invalidated by changes to").unwrap();
+        for &index in changes.iter().take(MAX_CHANGES_PER_CGU) {
+            let status = if diff.new_fingerprint(index).is_some() { "changed" } else { "removed" };
+            writeln!(report, "    {:?} ({status})", old.dep_graph.index_to_node(index)).unwrap();
+        }
+        if changes.len() > MAX_CHANGES_PER_CGU {
+            writeln!(report, "    and {} more", changes.len() - MAX_CHANGES_PER_CGU).unwrap();
+        }
+    }
+
+    Ok(report)
+}
+
+/// Compares the nodes of an older dependency graph with the ones of a newer graph.
+struct GraphDiff<'a> {
+    old: &'a SerializedDepGraph,
+    new: &'a SerializedDepGraph,
+}
+
+impl<'a> GraphDiff<'a> {
+    fn new(old: &'a SerializedDepGraph, new: &'a SerializedDepGraph) -> Self {
+        GraphDiff { old, new }
+    }
+
+    /// Returns the fingerprint of the old node `index` in the new graph, if it is still there.
+    fn new_fingerprint(&self, index: SerializedDepNodeIndex) -> Option<Fingerprint> {
+        let node = self.old.index_to_node(index);
+        self.new.node_to_index_opt(&node).map(|index| self.new.fingerprint_by_index(index))
+    }
+
+    fn is_changed(&self, index: SerializedDepNodeIndex) -> bool {
+        self.new_fingerprint(index) != Some(self.old.fingerprint_by_index(index))
+    }
+
+    /// Returns the changed nodes which `root` transitively depends on, but whose own
+    /// dependencies did not change. Those are the changes that caused `root` to be recomputed.
+    ///
+    /// Only changed nodes are traversed, since unchanged results stop the propagation of
+    /// changes. Nodes whose result is not hashed always have a zero fingerprint, so whether
+    /// they changed is unknown and they are traversed as well.
+    fn root_changes(&self, root: SerializedDepNodeIndex) -> Vec<SerializedDepNodeIndex> {
+        let mut changes = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut stack: Vec<_> = self.old.edge_targets_from(root).collect();
+
+        while let Some(index) = stack.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+
+            let is_changed = self.is_changed(index);
+            if !is_changed && self.old.fingerprint_by_index(index) != Fingerprint::ZERO {
+                continue;
+            }
+
+            let mut has_changed_dependency = false;
+            for dependency in self.old.edge_targets_from(index) {
+                has_changed_dependency |= self.is_changed(dependency);
+                stack.push(dependency);
+            }
+            if is_changed && !has_changed_dependency {
+                changes.push(index);
+            }
+        }
+
+        changes.sort();
+        changes
+    }
+}
+
+fn prune(
+    sess: &Session,
+    incr_dir: &Path,
+    max_age: Option<Duration>,
+) -> Result<String, ErrorGuaranteed> {
+    let before = list_session_directories(sess, incr_dir)?;
+    if let Err(err) = prune_incremental_directory(sess, incr_dir, max_age) {
+        return Err(sess.emit_err(errors::InspectRead { path: incr_dir, err }));
+    }
+
+    let mut report = String::new();
+    let mut freed = 0;
+    let mut removed = 0;
+    for (session_dir, size) in before {
+        if !session_dir.exists() {
+            writeln!(report, "removed `{}` ({size} bytes)", session_dir.display()).unwrap();
+            freed += size;
+            removed += 1;
+        }
+    }
+    writeln!(report, "removed {removed} session directories, freeing {freed} bytes").unwrap();
+
+    Ok(report)
+}
+
+/// Lists the session directories of every crate directory in `incr_dir`, along with their size.
+fn list_session_directories(
+    sess: &Session,
+    incr_dir: &Path,
+) -> Result<Vec<(PathBuf, u64)>, ErrorGuaranteed> {
+    let crate_dirs = match incr_dir.read_dir() {
+        Ok(crate_dirs) => crate_dirs,
+        Err(err) => return Err(sess.emit_err(errors::InspectRead { path: incr_dir, err })),
+    };
+
+    let mut session_dirs = Vec::new();
+    for crate_dir in crate_dirs.flatten() {
+        let Ok(entries) = crate_dir.path().read_dir() else { continue };
+        for entry in entries.flatten() {
+            if is_session_directory(&entry.file_name().to_string_lossy()) {
+                let path = entry.path();
+                let size = list_files(&path).into_iter().map(|(_, size)| size).sum();
+                session_dirs.push((path, size));
+            }
+        }
+    }
+    session_dirs.sort();
+
+    Ok(session_dirs)
+}
+
+/// Returns the name and size of the files in `dir`.
+fn list_files(dir: &Path) -> Vec<(String, u64)> {
+    let Ok(entries) = dir.read_dir() else { return Vec::new() };
+    entries
+        .flatten()
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), file_size(&entry.path())))
+        .collect()
+}
+
+fn file_size(path: &Path) -> u64 {
+    std_fs::metadata(path).map_or(0, |metadata| metadata.len())
+}
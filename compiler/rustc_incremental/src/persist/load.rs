@@ -13,6 +13,7 @@
 use std::path::{Path, PathBuf};
 
 use super::data::*;
+use super::explain::{self, PreviousSession};
 use super::file_format;
 use super::fs::*;
 use super::save::build_dep_graph;
@@ -140,8 +141,14 @@ fn load_dep_graph(sess: &Session) -> LoadResult<(SerializedDepGraph, WorkProduct
     let _prof_timer = prof.generic_activity("incr_comp_load_dep_graph");
 
     match load_data(&path, sess) {
-        LoadResult::DataOutOfDate => LoadResult::DataOutOfDate,
-        LoadResult::LoadDepGraph(path, err) => LoadResult::LoadDepGraph(path, err),
+        LoadResult::DataOutOfDate => {
+            explain::explain_discarded_cache(sess, PreviousSession::Unavailable);
+            LoadResult::DataOutOfDate
+        }
+        LoadResult::LoadDepGraph(path, err) => {
+            explain::explain_discarded_cache(sess, PreviousSession::Unavailable);
+            LoadResult::LoadDepGraph(path, err)
+        }
         LoadResult::Ok { data: (bytes, start_pos) } => {
             let mut decoder = MemDecoder::new(&bytes, start_pos);
             let prev_commandline_args_hash = u64::decode(&mut decoder);
@@ -155,6 +162,7 @@ fn load_dep_graph(sess: &Session) -> LoadResult<(SerializedDepGraph, WorkProduct
                 }
                 // We can't reuse the cache, purge it.
                 debug!("load_dep_graph_new: differing commandline arg hashes");
+                explain::explain_discarded_cache(sess, PreviousSession::CommandLineArgsChanged);
 
                 // No need to do any further work
                 return LoadResult::DataOutOfDate;
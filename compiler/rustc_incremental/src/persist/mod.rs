@@ -4,8 +4,10 @@
 
 mod data;
 mod dirty_clean;
+mod explain;
 mod file_format;
 mod fs;
+mod inspect;
 mod load;
 mod save;
 mod work_product;
@@ -13,6 +15,7 @@
 pub use fs::finalize_session_directory;
 pub use fs::in_incr_comp_dir;
 pub use fs::in_incr_comp_dir_sess;
+pub use inspect::inspect_incremental_directory;
 pub use load::load_query_result_cache;
 pub use load::setup_dep_graph;
 pub use load::LoadResult;
@@ -13,6 +13,7 @@
 
 use super::data::*;
 use super::dirty_clean;
+use super::explain;
 use super::file_format;
 use super::fs::*;
 use super::work_product;
@@ -47,6 +48,10 @@ pub fn save_dep_graph(tcx: TyCtxt<'_>) {
             tcx.dep_graph.print_incremental_info()
         }
 
+        if let Some(path) = &sess.opts.unstable_opts.incremental_explain {
+            explain::explain_recomputed_queries(tcx, path);
+        }
+
         join(
             move || {
                 sess.time("incr_comp_persist_dep_graph", || {
@@ -171,6 +176,10 @@ pub(crate) fn build_dep_graph(
     // First encode the commandline arguments hash
     sess.opts.dep_tracking_hash(false).encode(&mut encoder);
 
+    // Without a previous session, `load_dep_graph` already explained why nothing was reused.
+    let record_red_dependencies =
+        sess.opts.unstable_opts.incremental_explain.is_some() && prev_graph.node_count() > 0;
+
     Some(DepGraph::new(
         &sess.prof,
         prev_graph,
@@ -178,5 +187,6 @@ pub(crate) fn build_dep_graph(
         encoder,
         sess.opts.unstable_opts.query_dep_graph,
         sess.opts.unstable_opts.incremental_info,
+        record_red_dependencies,
     ))
 }
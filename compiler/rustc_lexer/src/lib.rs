@@ -600,6 +600,9 @@ fn number(&mut self, first_digit: char) -> LiteralKind {
                     if !self.eat_hexadecimal_digits() {
                         return Int { base, empty_int: true };
                     }
+                    if self.at_hex_float_rest() {
+                        return self.hex_float_rest();
+                    }
                 }
                 // Not a base prefix; consume additional digits.
                 '0'..='9' | '_' => {
@@ -647,6 +650,34 @@ fn number(&mut self, first_digit: char) -> LiteralKind {
         }
     }
 
+    /// Checks whether the hexadecimal digits that were just eaten are followed by
+    /// the rest of a hexadecimal float literal, e.g. `.8p3` in `0x1.8p3`. As `e`
+    /// is a hexadecimal digit, such literals need a binary exponent, which tells
+    /// them apart from field accesses and method calls like `0x1.abs()`.
+    fn at_hex_float_rest(&self) -> bool {
+        let mut rest = self.as_str();
+        if let Some(fraction) = rest.strip_prefix('.') {
+            if !fraction.starts_with(|c: char| c.is_ascii_hexdigit()) {
+                return false;
+            }
+            rest = fraction.trim_start_matches(|c: char| c.is_ascii_hexdigit() || c == '_');
+        }
+        let Some(exponent) = rest.strip_prefix(['p', 'P']) else { return false };
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        exponent.trim_start_matches('_').starts_with(|c: char| c.is_ascii_digit())
+    }
+
+    fn hex_float_rest(&mut self) -> LiteralKind {
+        if self.first() == '.' {
+            self.bump();
+            self.eat_hexadecimal_digits();
+        }
+        debug_assert!(self.first() == 'p' || self.first() == 'P');
+        self.bump();
+        let empty_exponent = !self.eat_float_exponent();
+        Float { base: Base::Hexadecimal, empty_exponent }
+    }
+
     fn lifetime_or_char(&mut self) -> TokenKind {
         debug_assert!(self.prev() == '\'');
 
@@ -865,7 +896,7 @@ fn eat_hexadecimal_digits(&mut self) -> bool {
     /// Eats the float exponent. Returns true if at least one digit was met,
     /// and returns false otherwise.
     fn eat_float_exponent(&mut self) -> bool {
-        debug_assert!(self.prev() == 'e' || self.prev() == 'E');
+        debug_assert!(matches!(self.prev(), 'e' | 'E' | 'p' | 'P'));
         if self.first() == '-' || self.first() == '+' {
             self.bump();
         }
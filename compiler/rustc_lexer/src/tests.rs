@@ -250,6 +250,28 @@ fn raw_string() {
     )
 }
 
+#[test]
+fn hex_float_literals() {
+    check_lexing(
+        r####"
+0x1.8p3
+0x1p-2f32
+0x1.abs
+"####,
+        expect![[r#"
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Float { base: Hexadecimal, empty_exponent: false }, suffix_start: 7 }, len: 7 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Float { base: Hexadecimal, empty_exponent: false }, suffix_start: 6 }, len: 9 }
+            Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: Int { base: Hexadecimal, empty_int: false }, suffix_start: 3 }, len: 3 }
+            Token { kind: Dot, len: 1 }
+            Token { kind: Ident, len: 3 }
+            Token { kind: Whitespace, len: 1 }
+        "#]],
+    )
+}
+
 #[test]
 fn literal_suffixes() {
     check_lexing(
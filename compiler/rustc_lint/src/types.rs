@@ -10,6 +10,8 @@
     },
 };
 use crate::{LateContext, LateLintPass, LintContext};
+use rustc_apfloat::ieee::{Double, Half, Quad, Single};
+use rustc_apfloat::Float;
 use rustc_ast as ast;
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashSet;
@@ -527,8 +529,12 @@ fn lint_literal<'tcx>(
         ty::Float(t) => {
             let is_infinite = match lit.node {
                 ast::LitKind::Float(v, _) => match t {
-                    ty::FloatTy::F32 => v.as_str().parse().map(f32::is_infinite),
-                    ty::FloatTy::F64 => v.as_str().parse().map(f64::is_infinite),
+                    // Unlike the host's `FromStr`, apfloat also parses hexadecimal
+                    // literals like `0x1p128`.
+                    ty::FloatTy::F32 => v.as_str().parse().map(Single::is_infinite),
+                    ty::FloatTy::F64 => v.as_str().parse().map(Double::is_infinite),
+                    ty::FloatTy::F16 => v.as_str().parse().map(Half::is_infinite),
+                    ty::FloatTy::F128 => v.as_str().parse().map(Quad::is_infinite),
                 },
                 _ => bug!(),
             };
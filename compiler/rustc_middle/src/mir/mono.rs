@@ -242,6 +242,10 @@ pub struct CodegenUnit<'tcx> {
     /// as well as the crate name and disambiguator.
     name: Symbol,
     items: FxHashMap<MonoItem<'tcx>, MonoItemData>,
+    /// Maps items that are emitted as aliases of another item in this CGU,
+    /// because their MIR bodies are identical, to that item. Backends that
+    /// can't emit aliases may codegen these items as usual instead.
+    aliases: FxHashMap<MonoItem<'tcx>, MonoItem<'tcx>>,
     size_estimate: usize,
     primary: bool,
     /// True if this is CGU is used to hold code coverage information for dead code,
@@ -294,6 +298,7 @@ pub fn new(name: Symbol) -> CodegenUnit<'tcx> {
         CodegenUnit {
             name,
             items: Default::default(),
+            aliases: Default::default(),
             size_estimate: 0,
             primary: false,
             is_code_coverage_dead_code_cgu: false,
@@ -326,6 +331,17 @@ pub fn items_mut(&mut self) -> &mut FxHashMap<MonoItem<'tcx>, MonoItemData> {
         &mut self.items
     }
 
+    /// Returns the item that `item` is emitted as an alias of, if any.
+    pub fn alias_target(&self, item: &MonoItem<'tcx>) -> Option<MonoItem<'tcx>> {
+        self.aliases.get(item).copied()
+    }
+
+    /// Makes `item` an alias of `target`, which must both be in this CGU.
+    pub fn add_alias(&mut self, item: MonoItem<'tcx>, target: MonoItem<'tcx>) {
+        debug_assert!(self.contains_item(&item) && self.contains_item(&target));
+        self.aliases.insert(item, target);
+    }
+
     pub fn is_code_coverage_dead_code_cgu(&self) -> bool {
         self.is_code_coverage_dead_code_cgu
     }
@@ -429,6 +445,7 @@ impl<'a, 'tcx> HashStable<StableHashingContext<'a>> for CodegenUnit<'tcx> {
     fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHasher) {
         let CodegenUnit {
             ref items,
+            ref aliases,
             name,
             // The size estimate is not relevant to the hash
             size_estimate: _,
@@ -451,6 +468,21 @@ fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHas
 
         items.sort_unstable_by_key(|i| i.0);
         items.hash_stable(hcx, hasher);
+
+        let mut aliases: Vec<(Fingerprint, Fingerprint)> = aliases
+            .iter()
+            .map(|(mono_item, target)| {
+                let mut hasher = StableHasher::new();
+                mono_item.hash_stable(hcx, &mut hasher);
+                let mono_item_fingerprint = hasher.finish();
+                let mut hasher = StableHasher::new();
+                target.hash_stable(hcx, &mut hasher);
+                (mono_item_fingerprint, hasher.finish())
+            })
+            .collect();
+
+        aliases.sort_unstable();
+        aliases.hash_stable(hcx, hasher);
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::build::expr::as_place::PlaceBuilder;
 use crate::build::scope::DropKind;
-use rustc_apfloat::ieee::{Double, Single};
+use rustc_apfloat::ieee::{Double, Half, Quad, Single};
 use rustc_apfloat::Float;
 use rustc_ast::attr;
 use rustc_data_structures::fx::FxHashMap;
@@ -999,23 +999,40 @@ pub(crate) fn parse_float_into_scalar(
     neg: bool,
 ) -> Option<Scalar> {
     let num = num.as_str();
+    // The host's `FromStr` impls don't accept hexadecimal floats, so those can't
+    // be checked against apfloat.
+    let is_hex = num.starts_with("0x");
     match float_ty {
+        // The host has no `f16`, so this one can't be checked either.
+        ty::FloatTy::F16 => {
+            let mut f = num
+                .parse::<Half>()
+                .unwrap_or_else(|e| panic!("apfloat::ieee::Half failed to parse `{num}`: {e:?}"));
+
+            if neg {
+                f = -f;
+            }
+
+            Some(Scalar::from_f16(f))
+        }
         ty::FloatTy::F32 => {
-            let Ok(rust_f) = num.parse::<f32>() else { return None };
+            let rust_f = if is_hex { None } else { Some(num.parse::<f32>().ok()?) };
             let mut f = num
                 .parse::<Single>()
                 .unwrap_or_else(|e| panic!("apfloat::ieee::Single failed to parse `{num}`: {e:?}"));
 
-            assert!(
-                u128::from(rust_f.to_bits()) == f.to_bits(),
-                "apfloat::ieee::Single gave different result for `{}`: \
-                 {}({:#x}) vs Rust's {}({:#x})",
-                rust_f,
-                f,
-                f.to_bits(),
-                Single::from_bits(rust_f.to_bits().into()),
-                rust_f.to_bits()
-            );
+            if let Some(rust_f) = rust_f {
+                assert!(
+                    u128::from(rust_f.to_bits()) == f.to_bits(),
+                    "apfloat::ieee::Single gave different result for `{}`: \
+                     {}({:#x}) vs Rust's {}({:#x})",
+                    rust_f,
+                    f,
+                    f.to_bits(),
+                    Single::from_bits(rust_f.to_bits().into()),
+                    rust_f.to_bits()
+                );
+            }
 
             if neg {
                 f = -f;
@@ -1024,21 +1041,23 @@ pub(crate) fn parse_float_into_scalar(
             Some(Scalar::from_f32(f))
         }
         ty::FloatTy::F64 => {
-            let Ok(rust_f) = num.parse::<f64>() else { return None };
+            let rust_f = if is_hex { None } else { Some(num.parse::<f64>().ok()?) };
             let mut f = num
                 .parse::<Double>()
                 .unwrap_or_else(|e| panic!("apfloat::ieee::Double failed to parse `{num}`: {e:?}"));
 
-            assert!(
-                u128::from(rust_f.to_bits()) == f.to_bits(),
-                "apfloat::ieee::Double gave different result for `{}`: \
-                 {}({:#x}) vs Rust's {}({:#x})",
-                rust_f,
-                f,
-                f.to_bits(),
-                Double::from_bits(rust_f.to_bits().into()),
-                rust_f.to_bits()
-            );
+            if let Some(rust_f) = rust_f {
+                assert!(
+                    u128::from(rust_f.to_bits()) == f.to_bits(),
+                    "apfloat::ieee::Double gave different result for `{}`: \
+                     {}({:#x}) vs Rust's {}({:#x})",
+                    rust_f,
+                    f,
+                    f.to_bits(),
+                    Double::from_bits(rust_f.to_bits().into()),
+                    rust_f.to_bits()
+                );
+            }
 
             if neg {
                 f = -f;
@@ -1046,6 +1065,18 @@ pub(crate) fn parse_float_into_scalar(
 
             Some(Scalar::from_f64(f))
         }
+        // Same for `f128`.
+        ty::FloatTy::F128 => {
+            let mut f = num
+                .parse::<Quad>()
+                .unwrap_or_else(|e| panic!("apfloat::ieee::Quad failed to parse `{num}`: {e:?}"));
+
+            if neg {
+                f = -f;
+            }
+
+            Some(Scalar::from_f128(f))
+        }
     }
 }
 
@@ -1529,6 +1529,8 @@ pub(crate) fn from_pat(cx: &MatchCheckCtxt<'p, 'tcx>, pat: &Pat<'tcx>) -> Self {
                                 let hi = hi.map(Double::from_bits).unwrap_or(Double::INFINITY);
                                 F64Range(lo, hi, *end)
                             }
+                            // Ranges of these aren't checked for exhaustiveness yet.
+                            ty::FloatTy::F16 | ty::FloatTy::F128 => Opaque(OpaqueId::new()),
                         }
                     }
                     _ => bug!("invalid type for range pattern: {}", ty),
@@ -209,13 +209,22 @@ pub struct UsageMap<'tcx> {
 
     // Maps every mono item to the mono items that use it.
     user_map: FxHashMap<MonoItem<'tcx>, Vec<MonoItem<'tcx>>>,
+
+    // Maps every mono item to the first use of it that was encountered, spanned
+    // with the location of that use. Only recorded for `-Z dump-mono-provenance`
+    // and `-Z print-mono-provenance`.
+    cause_map: Option<FxHashMap<MonoItem<'tcx>, Spanned<MonoItem<'tcx>>>>,
 }
 
 type MonoItems<'tcx> = Vec<Spanned<MonoItem<'tcx>>>;
 
 impl<'tcx> UsageMap<'tcx> {
-    fn new() -> UsageMap<'tcx> {
-        UsageMap { used_map: FxHashMap::default(), user_map: FxHashMap::default() }
+    fn new(record_causes: bool) -> UsageMap<'tcx> {
+        UsageMap {
+            used_map: FxHashMap::default(),
+            user_map: FxHashMap::default(),
+            cause_map: record_causes.then(FxHashMap::default),
+        }
     }
 
     fn record_used<'a>(
@@ -225,6 +234,12 @@ fn record_used<'a>(
     ) where
         'tcx: 'a,
     {
+        if let Some(cause_map) = &mut self.cause_map {
+            for used_item in used_items {
+                cause_map.entry(used_item.node).or_insert(respan(used_item.span, user_item));
+            }
+        }
+
         let used_items: Vec<_> = used_items.iter().map(|item| item.node).collect();
         for &used_item in used_items.iter() {
             self.user_map.entry(used_item).or_default().push(user_item);
@@ -237,6 +252,15 @@ pub fn get_user_items(&self, item: MonoItem<'tcx>) -> &[MonoItem<'tcx>] {
         self.user_map.get(&item).map(|items| items.as_slice()).unwrap_or(&[])
     }
 
+    /// Returns the use that caused `item` to be collected, spanned with the
+    /// location of the use, if causes were recorded and `item` isn't a root.
+    ///
+    /// Items used by several others are attributed to the first use that was
+    /// encountered, which can vary between runs with the parallel front-end.
+    pub fn get_cause(&self, item: MonoItem<'tcx>) -> Option<Spanned<MonoItem<'tcx>>> {
+        self.cause_map.as_ref()?.get(&item).copied()
+    }
+
     /// Internally iterate over all inlined items used by `item`.
     pub fn for_each_inlined_used_item<F>(&self, tcx: TyCtxt<'tcx>, item: MonoItem<'tcx>, mut f: F)
     where
@@ -265,7 +289,9 @@ pub fn collect_crate_mono_items(
     debug!("building mono item graph, beginning at roots");
 
     let mut visited = MTLock::new(FxHashSet::default());
-    let mut usage_map = MTLock::new(UsageMap::new());
+    let opts = &tcx.sess.opts.unstable_opts;
+    let record_causes = opts.dump_mono_provenance.enabled() || opts.print_mono_provenance.is_some();
+    let mut usage_map = MTLock::new(UsageMap::new(record_causes));
     let recursion_limit = tcx.recursion_limit();
 
     {
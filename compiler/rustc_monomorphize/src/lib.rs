@@ -18,6 +18,7 @@
 
 mod collector;
 mod errors;
+mod merge_functions;
 mod partitioning;
 mod polymorphize;
 mod util;
@@ -0,0 +1,339 @@
+//! Merging of mono items with identical MIR bodies, for `-Z merge-functions=mir`.
+//!
+//! Many instances of a generic function compile down to the same machine code,
+//! like the methods of `Vec<u32>` and `Vec<i32>`. LLVM's `mergefunc` pass only
+//! finds those after all of them have been codegened and optimized, and other
+//! backends don't find them at all. Here they are found before codegen instead,
+//! so that all but one of them can be emitted as aliases of the remaining one.
+//!
+//! Two instances of the same generic function share their MIR body, so they
+//! only differ in the types and constants that their generic parameters were
+//! instantiated with. Those are compared after mapping all signed integers to
+//! the unsigned integers of the same size, as both have the same layout and
+//! ABI. The places where signedness does matter, like arithmetic, comparisons
+//! and casts, record the actual signedness of their operands. Calls to other
+//! instances in this crate are compared by whether those are equivalent in
+//! turn, which is computed by refining a partition of all instances until it
+//! no longer changes.
+
+use std::hash::Hash;
+
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::mono::{InstantiationMode, MonoItem};
+use rustc_middle::mir::visit::{TyContext, Visitor as MirVisitor};
+use rustc_middle::mir::{self, Location};
+use rustc_middle::ty::adjustment::PointerCoercion;
+use rustc_middle::ty::fold::{TypeFoldable, TypeFolder, TypeSuperFoldable};
+use rustc_middle::ty::{self, GenericArgsRef, Instance, InstanceDef, Ty, TyCtxt};
+use rustc_query_system::ich::StableHashingContext;
+
+/// Finds the mono items that can be emitted as aliases of another one, and
+/// returns the item each of them should alias.
+pub(crate) fn find_aliases<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &FxHashSet<MonoItem<'tcx>>,
+) -> FxHashMap<MonoItem<'tcx>, MonoItem<'tcx>> {
+    let _prof_timer = tcx.prof.generic_activity("merge_functions_find_aliases");
+
+    // The instances that may be merged, or may be called by instances that
+    // are merged, in a deterministic order.
+    let mut candidates: Vec<_> = items
+        .iter()
+        .filter_map(|item| match *item {
+            MonoItem::Fn(instance) if is_candidate(tcx, item, instance) => {
+                Some((instance, item.symbol_name(tcx).name))
+            }
+            _ => None,
+        })
+        .collect();
+    candidates.sort_by_key(|&(_, symbol)| symbol);
+    let indices: FxHashMap<_, _> =
+        candidates.iter().enumerate().map(|(index, &(instance, _))| (instance, index)).collect();
+
+    let shapes: Vec<_> = tcx.with_stable_hashing_context(|hcx| {
+        candidates
+            .iter()
+            .map(|&(instance, _)| FunctionShape::compute(tcx, hcx.clone(), &indices, instance))
+            .collect()
+    });
+
+    // Start out with all calls to candidates being considered equal, and tell
+    // apart the instances calling candidates of different classes until the
+    // number of classes no longer changes.
+    let mut classes: Vec<Fingerprint> = shapes.iter().map(|shape| shape.base).collect();
+    let mut class_count = classes.iter().collect::<FxHashSet<_>>().len();
+    loop {
+        let refined: Vec<Fingerprint> = shapes
+            .iter()
+            .map(|shape| {
+                let mut hasher = StableHasher::new();
+                shape.base.hash(&mut hasher);
+                for &callee in &shape.callees {
+                    classes[callee].hash(&mut hasher);
+                }
+                hasher.finish()
+            })
+            .collect();
+        let refined_count = refined.iter().collect::<FxHashSet<_>>().len();
+        classes = refined;
+        if refined_count == class_count {
+            break;
+        }
+        class_count = refined_count;
+    }
+
+    // Every instance is aliased to the one with the smallest symbol name in
+    // its class, which comes first as the candidates are sorted by symbol.
+    let mut targets = FxHashMap::default();
+    let mut aliases = FxHashMap::default();
+    for (&(instance, _), class) in candidates.iter().zip(classes) {
+        if !can_be_aliased(tcx, instance) {
+            continue;
+        }
+        let item = MonoItem::Fn(instance);
+        let target = *targets.entry(class).or_insert(item);
+        if target != item {
+            aliases.insert(item, target);
+        }
+    }
+    debug!("found {} aliases among {} candidates", aliases.len(), candidates.len());
+    aliases
+}
+
+/// Whether an instance is one of the ones compared against each other. Those
+/// that aren't are only ever equivalent to themselves.
+fn is_candidate<'tcx>(tcx: TyCtxt<'tcx>, item: &MonoItem<'tcx>, instance: Instance<'tcx>) -> bool {
+    match instance.def {
+        InstanceDef::Item(_) => item.is_generic_fn(tcx),
+        InstanceDef::DropGlue(_, Some(_)) => true,
+        _ => false,
+    }
+}
+
+/// Whether an instance may be emitted as an alias of another one. Those with
+/// attributes affecting their symbol or how they are emitted are kept as is.
+fn can_be_aliased<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> bool {
+    let InstanceDef::Item(def_id) = instance.def else { return false };
+    if !matches!(
+        MonoItem::Fn(instance).instantiation_mode(tcx),
+        InstantiationMode::GloballyShared { .. }
+    ) {
+        return false;
+    }
+    let attrs = tcx.codegen_fn_attrs(def_id);
+    !attrs.contains_extern_indicator()
+        && attrs.linkage.is_none()
+        && !attrs.flags.contains(CodegenFnAttrFlags::NAKED)
+}
+
+/// What an instance compiles to, up to the candidates it calls.
+struct FunctionShape {
+    /// The hash of everything but the classes of the called candidates.
+    base: Fingerprint,
+    /// The indices of the called candidates, in the order they are used in.
+    callees: Vec<usize>,
+}
+
+impl FunctionShape {
+    fn compute<'tcx>(
+        tcx: TyCtxt<'tcx>,
+        mut hcx: StableHashingContext<'_>,
+        indices: &FxHashMap<Instance<'tcx>, usize>,
+        instance: Instance<'tcx>,
+    ) -> FunctionShape {
+        let mut hasher = StableHasher::new();
+        instance.def_id().hash_stable(&mut hcx, &mut hasher);
+
+        let body = tcx.instance_mir(instance.def);
+        let is_drop_glue = matches!(instance.def, InstanceDef::DropGlue(..));
+        is_drop_glue.hash_stable(&mut hcx, &mut hasher);
+        if is_drop_glue {
+            // Unlike the MIR of other items, the MIR of drop glue is specific
+            // to the dropped type.
+            let body = body.clone().fold_with(&mut EraseSignedness { tcx });
+            body.hash_stable(&mut hcx, &mut hasher);
+        }
+
+        let param_env = ty::ParamEnv::reveal_all();
+        match tcx.fn_abi_of_instance(param_env.and((instance, ty::List::empty()))) {
+            Ok(fn_abi) => {
+                fn_abi.ret.mode.hash(&mut hasher);
+                for arg in fn_abi.args.iter() {
+                    arg.mode.hash(&mut hasher);
+                }
+                fn_abi.conv.hash(&mut hasher);
+                fn_abi.c_variadic.hash(&mut hasher);
+                fn_abi.can_unwind.hash(&mut hasher);
+                fn_abi.fixed_count.hash(&mut hasher);
+            }
+            // Keep the instance to itself.
+            Err(_) => instance.hash_stable(&mut hcx, &mut hasher),
+        }
+
+        let mut visitor =
+            ShapeVisitor { tcx, hcx, hasher, indices, instance, body, callees: Vec::new() };
+        visitor.visit_body(body);
+        FunctionShape { base: visitor.hasher.finish(), callees: visitor.callees }
+    }
+}
+
+/// Hashes everything in a body that depends on the generic arguments of an
+/// instance, and collects the candidates it calls.
+struct ShapeVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    hcx: StableHashingContext<'a>,
+    hasher: StableHasher,
+    indices: &'a FxHashMap<Instance<'tcx>, usize>,
+    instance: Instance<'tcx>,
+    body: &'tcx mir::Body<'tcx>,
+    callees: Vec<usize>,
+}
+
+impl<'a, 'tcx> ShapeVisitor<'a, 'tcx> {
+    fn monomorphize<T>(&self, value: T) -> T
+    where
+        T: TypeFoldable<TyCtxt<'tcx>>,
+    {
+        self.instance.instantiate_mir_and_normalize_erasing_regions(
+            self.tcx,
+            ty::ParamEnv::reveal_all(),
+            ty::EarlyBinder::bind(value),
+        )
+    }
+
+    /// Hashes a value with signed integers replaced by unsigned ones.
+    fn hash_erased<T>(&mut self, value: T)
+    where
+        T: TypeFoldable<TyCtxt<'tcx>> + HashStable<StableHashingContext<'a>>,
+    {
+        let value = value.fold_with(&mut EraseSignedness { tcx: self.tcx });
+        value.hash_stable(&mut self.hcx, &mut self.hasher);
+    }
+
+    /// Hashes a value as it is, for the places where signedness matters.
+    fn hash_exact<T: HashStable<StableHashingContext<'a>>>(&mut self, value: T) {
+        value.hash_stable(&mut self.hcx, &mut self.hasher);
+    }
+
+    fn hash_signedness(&mut self, ty: Ty<'tcx>) {
+        self.hash_exact(ty.is_signed());
+    }
+
+    fn visit_instance_use(&mut self, instance: Instance<'tcx>) {
+        let instance = instance.polymorphize(self.tcx);
+        match self.indices.get(&instance) {
+            Some(&index) => {
+                self.hash_exact("candidate");
+                self.callees.push(index);
+            }
+            None => self.hash_exact(instance),
+        }
+    }
+}
+
+impl<'a, 'tcx> MirVisitor<'tcx> for ShapeVisitor<'a, 'tcx> {
+    fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
+        let body = self.body;
+        match *rvalue {
+            mir::Rvalue::BinaryOp(_, ref operands)
+            | mir::Rvalue::CheckedBinaryOp(_, ref operands) => {
+                let (lhs, rhs) = &**operands;
+                let lhs = self.monomorphize(lhs.ty(body, self.tcx));
+                let rhs = self.monomorphize(rhs.ty(body, self.tcx));
+                self.hash_signedness(lhs);
+                self.hash_signedness(rhs);
+            }
+            mir::Rvalue::UnaryOp(_, ref operand) => {
+                let ty = self.monomorphize(operand.ty(body, self.tcx));
+                self.hash_signedness(ty);
+            }
+            mir::Rvalue::Cast(kind, ref operand, target_ty) => {
+                let source_ty = self.monomorphize(operand.ty(body, self.tcx));
+                let target_ty = self.monomorphize(target_ty);
+                self.hash_signedness(source_ty);
+                self.hash_signedness(target_ty);
+                // These casts may create vtables or shims for the exact type.
+                if let mir::CastKind::PointerCoercion(
+                    PointerCoercion::Unsize
+                    | PointerCoercion::ReifyFnPointer
+                    | PointerCoercion::ClosureFnPointer(_),
+                )
+                | mir::CastKind::DynStar = kind
+                {
+                    self.hash_exact(source_ty);
+                    self.hash_exact(target_ty);
+                }
+            }
+            _ => {}
+        }
+        self.super_rvalue(rvalue, location);
+    }
+
+    fn visit_constant(&mut self, constant: &mir::ConstOperand<'tcx>, _: Location) {
+        let const_ = self.monomorphize(constant.const_);
+        let ty = const_.ty();
+        self.hash_erased(ty);
+        if let ty::FnDef(def_id, args) = *ty.kind() {
+            let param_env = ty::ParamEnv::reveal_all();
+            match Instance::resolve(self.tcx, param_env, def_id, args) {
+                Ok(Some(instance)) => self.visit_instance_use(instance),
+                _ => self.hash_exact(ty),
+            }
+            return;
+        }
+        match const_.eval(self.tcx, ty::ParamEnv::reveal_all(), None) {
+            Ok(value) => self.hash_exact(value),
+            Err(_) => self.hash_exact(const_),
+        }
+    }
+
+    fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Location) {
+        if let mir::TerminatorKind::Drop { ref place, .. } = terminator.kind {
+            let ty = self.monomorphize(place.ty(self.body, self.tcx).ty);
+            self.visit_instance_use(Instance::resolve_drop_in_place(self.tcx, ty));
+        }
+        self.super_terminator(terminator, location);
+    }
+
+    fn visit_ty(&mut self, ty: Ty<'tcx>, context: TyContext) {
+        // User type annotations don't affect codegen.
+        if let TyContext::UserTy(_) = context {
+            return;
+        }
+        let ty = self.monomorphize(ty);
+        self.hash_erased(ty);
+    }
+
+    fn visit_ty_const(&mut self, ct: ty::Const<'tcx>, _: Location) {
+        let ct = self.monomorphize(ct);
+        self.hash_erased(ct);
+    }
+
+    fn visit_args(&mut self, args: &GenericArgsRef<'tcx>, _: Location) {
+        let args = self.monomorphize(*args);
+        self.hash_erased(args);
+    }
+
+    fn visit_region(&mut self, _: ty::Region<'tcx>, _: Location) {}
+}
+
+/// Replaces all signed integer types by the unsigned ones of the same size.
+struct EraseSignedness<'tcx> {
+    tcx: TyCtxt<'tcx>,
+}
+
+impl<'tcx> TypeFolder<TyCtxt<'tcx>> for EraseSignedness<'tcx> {
+    fn interner(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn fold_ty(&mut self, ty: Ty<'tcx>) -> Ty<'tcx> {
+        match *ty.kind() {
+            ty::Int(int_ty) => Ty::new_uint(self.tcx, int_ty.to_unsigned()),
+            _ => ty.super_fold_with(self),
+        }
+    }
+}
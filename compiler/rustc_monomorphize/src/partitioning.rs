@@ -115,14 +115,21 @@
 use rustc_session::config::{DumpMonoStatsFormat, SwitchWithOptPath};
 use rustc_session::CodegenUnits;
 use rustc_span::symbol::Symbol;
+use rustc_target::spec::MergeFunctions;
 
 use crate::collector::UsageMap;
 use crate::collector::{self, MonoItemCollectionMode};
-use crate::errors::{CouldntDumpMonoStats, SymbolAlreadyDefined, UnknownCguCollectionMode};
+use crate::errors::{
+    CouldntDumpMonoProvenance, CouldntDumpMonoStats, SymbolAlreadyDefined, UnknownCguCollectionMode,
+};
+use crate::merge_functions;
 
 struct PartitioningCx<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     usage_map: &'a UsageMap<'tcx>,
+    /// The items that can be emitted as aliases of another item, for
+    /// `-Z merge-functions=mir`.
+    aliases: &'a FxHashMap<MonoItem<'tcx>, MonoItem<'tcx>>,
 }
 
 struct PlacedMonoItems<'tcx> {
@@ -137,13 +144,14 @@ fn partition<'tcx, I>(
     tcx: TyCtxt<'tcx>,
     mono_items: I,
     usage_map: &UsageMap<'tcx>,
+    aliases: &FxHashMap<MonoItem<'tcx>, MonoItem<'tcx>>,
 ) -> Vec<CodegenUnit<'tcx>>
 where
     I: Iterator<Item = MonoItem<'tcx>>,
 {
     let _prof_timer = tcx.prof.generic_activity("cgu_partitioning");
 
-    let cx = &PartitioningCx { tcx, usage_map };
+    let cx = &PartitioningCx { tcx, usage_map, aliases };
 
     // Place all mono items into a codegen unit. `place_mono_items` is
     // responsible for initializing the CGU size estimates.
@@ -174,6 +182,14 @@ fn partition<'tcx, I>(
         debug_dump(tcx, "INTERNALIZE", &codegen_units);
     }
 
+    // Turn the items with the same MIR as another item into aliases of it.
+    if !cx.aliases.is_empty() {
+        let _prof_timer = tcx.prof.generic_activity("cgu_partitioning_merge_functions");
+        record_aliases(cx, &mut codegen_units);
+
+        debug_dump(tcx, "MERGE FUNCTIONS", &codegen_units);
+    }
+
     // Mark one CGU for dead code, if necessary.
     let instrument_dead_code =
         tcx.sess.instrument_coverage() && !tcx.sess.instrument_coverage_except_unused_functions();
@@ -219,8 +235,11 @@ fn place_mono_items<'tcx, I>(cx: &PartitioningCx<'_, 'tcx>, mono_items: I) -> Pl
             InstantiationMode::LocalCopy => continue,
         }
 
-        let characteristic_def_id = characteristic_def_id_of_mono_item(cx.tcx, mono_item);
-        let is_volatile = is_incremental_build && mono_item.is_generic_fn(cx.tcx);
+        // Items that are going to be aliases have to end up in the same CGU
+        // as the item they alias, so they are placed like that item.
+        let placed_like = cx.aliases.get(&mono_item).copied().unwrap_or(mono_item);
+        let characteristic_def_id = characteristic_def_id_of_mono_item(cx.tcx, placed_like);
+        let is_volatile = is_incremental_build && placed_like.is_generic_fn(cx.tcx);
 
         let cgu_name = match characteristic_def_id {
             Some(def_id) => compute_codegen_unit_name(
@@ -502,6 +521,35 @@ fn compute_inlined_overlap<'tcx>(cgu1: &CodegenUnit<'tcx>, cgu2: &CodegenUnit<'t
     overlap
 }
 
+/// Records the items that have the same MIR as another item in their CGU as
+/// aliases of that item. Only items that ended up in the same CGU as their
+/// target and have a linkage an alias can have are turned into aliases.
+fn record_aliases<'tcx>(cx: &PartitioningCx<'_, 'tcx>, codegen_units: &mut [CodegenUnit<'tcx>]) {
+    let can_alias = |linkage| matches!(linkage, Linkage::External | Linkage::Internal);
+
+    for cgu in codegen_units {
+        let aliases: Vec<_> = cgu
+            .items()
+            .iter()
+            .filter_map(|(item, data)| {
+                let target = *cx.aliases.get(item)?;
+                let target_data = cgu.items().get(&target)?;
+                (can_alias(data.linkage) && can_alias(target_data.linkage))
+                    .then_some((*item, target))
+            })
+            .collect();
+        if aliases.is_empty() {
+            continue;
+        }
+        for (item, target) in aliases {
+            cgu.add_alias(item, target);
+            // An alias doesn't take any time to codegen.
+            cgu.items_mut().get_mut(&item).unwrap().size_estimate = 1;
+        }
+        cgu.compute_size_estimate();
+    }
+}
+
 fn internalize_symbols<'tcx>(
     cx: &PartitioningCx<'_, 'tcx>,
     codegen_units: &mut [CodegenUnit<'tcx>],
@@ -1106,10 +1154,18 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
 
     tcx.sess.abort_if_errors();
 
+    let merge_mode =
+        tcx.sess.opts.unstable_opts.merge_functions.unwrap_or(tcx.sess.target.merge_functions);
+    let aliases = if merge_mode == MergeFunctions::Mir {
+        merge_functions::find_aliases(tcx, &items)
+    } else {
+        FxHashMap::default()
+    };
+
     let (codegen_units, _) = tcx.sess.time("partition_and_assert_distinct_symbols", || {
         sync::join(
             || {
-                let mut codegen_units = partition(tcx, items.iter().copied(), &usage_map);
+                let mut codegen_units = partition(tcx, items.iter().copied(), &usage_map, &aliases);
                 codegen_units[0].make_primary();
                 &*tcx.arena.alloc_from_iter(codegen_units)
             },
@@ -1146,6 +1202,23 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
         }
     }
 
+    // Output the uses causing the instantiations of generic items
+    let dump_provenance = &tcx.sess.opts.unstable_opts.dump_mono_provenance;
+    let print_provenance = tcx.sess.opts.unstable_opts.print_mono_provenance;
+    if dump_provenance.enabled() || print_provenance.is_some() {
+        let provenance = mono_items_provenance(tcx, &items, &usage_map);
+        if let SwitchWithOptPath::Enabled(ref path) = *dump_provenance {
+            if let Err(err) =
+                dump_mono_items_provenance(&provenance, path, tcx.crate_name(LOCAL_CRATE))
+            {
+                tcx.sess.emit_fatal(CouldntDumpMonoProvenance { error: err.to_string() });
+            }
+        }
+        if let Some(count) = print_provenance {
+            print_mono_items_provenance(&provenance, count);
+        }
+    }
+
     if tcx.sess.opts.unstable_opts.print_mono_items.is_some() {
         let mut item_to_cgus: FxHashMap<_, Vec<_>> = Default::default();
 
@@ -1278,6 +1351,121 @@ struct MonoItem {
     Ok(())
 }
 
+#[derive(serde::Serialize)]
+struct GenericItemProvenance {
+    name: String,
+    instantiation_count: usize,
+    total_estimate: usize,
+    /// The items whose uses caused the instantiations, with how many of them
+    /// each one caused.
+    users: Vec<(String, usize)>,
+    instances: Vec<InstanceProvenance>,
+}
+
+#[derive(serde::Serialize)]
+struct InstanceProvenance {
+    name: String,
+    size_estimate: usize,
+    used_by: Option<String>,
+    used_at: Option<String>,
+}
+
+/// Gathers the instantiations of every generic item, along with the uses that
+/// caused them, sorted by total instantiated size, from heaviest to lightest.
+fn mono_items_provenance<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &FxHashSet<MonoItem<'tcx>>,
+    usage_map: &UsageMap<'tcx>,
+) -> Vec<GenericItemProvenance> {
+    let mut items_per_def_id: FxHashMap<_, Vec<_>> = Default::default();
+    for &mono_item in items {
+        if mono_item.is_generic_fn(tcx) {
+            items_per_def_id.entry(mono_item.def_id()).or_default().push(mono_item);
+        }
+    }
+
+    let mut provenance: Vec<_> = items_per_def_id
+        .into_iter()
+        .map(|(def_id, items)| {
+            let mut users: FxHashMap<String, usize> = Default::default();
+            let mut instances: Vec<_> = items
+                .into_iter()
+                .map(|mono_item| {
+                    let cause = usage_map.get_cause(mono_item);
+                    if let Some(cause) = cause {
+                        let user = with_no_trimmed_paths!(tcx.def_path_str(cause.node.def_id()));
+                        *users.entry(user).or_default() += 1;
+                    }
+                    InstanceProvenance {
+                        name: with_no_trimmed_paths!(mono_item.to_string()),
+                        size_estimate: mono_item.size_estimate(tcx),
+                        used_by: cause.map(|cause| with_no_trimmed_paths!(cause.node.to_string())),
+                        used_at: cause.filter(|cause| !cause.span.is_dummy()).map(|cause| {
+                            tcx.sess.source_map().span_to_embeddable_string(cause.span)
+                        }),
+                    }
+                })
+                .collect();
+            instances.sort_unstable_by(|a, b| {
+                b.size_estimate.cmp(&a.size_estimate).then_with(|| a.name.cmp(&b.name))
+            });
+
+            let mut users: Vec<_> = users.into_iter().collect();
+            users.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+            GenericItemProvenance {
+                name: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+                instantiation_count: instances.len(),
+                total_estimate: instances.iter().map(|instance| instance.size_estimate).sum(),
+                users,
+                instances,
+            }
+        })
+        .collect();
+    provenance.sort_unstable_by(|a, b| {
+        b.total_estimate.cmp(&a.total_estimate).then_with(|| a.name.cmp(&b.name))
+    });
+    provenance
+}
+
+/// Outputs the instantiations of every generic item, and the uses that caused
+/// them, to a JSON file in the given output directory.
+fn dump_mono_items_provenance(
+    provenance: &[GenericItemProvenance],
+    output_directory: &Option<PathBuf>,
+    crate_name: Symbol,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let output_path = output_directory.join(format!("{crate_name}.mono_provenance.json"));
+    let file = BufWriter::new(File::create(&output_path)?);
+    serde_json::to_writer(file, provenance)?;
+
+    Ok(())
+}
+
+/// Prints the `count` generic items with the largest total instantiated size,
+/// each followed by the items whose uses caused the most instantiations.
+fn print_mono_items_provenance(provenance: &[GenericItemProvenance], count: usize) {
+    const MAX_USERS: usize = 5;
+
+    println!("{:>14}  {:>14}  generic item", "total estimate", "instantiations");
+    for item in provenance.iter().take(count) {
+        println!("{:>14}  {:>14}  {}", item.total_estimate, item.instantiation_count, item.name);
+        for (user, instantiation_count) in item.users.iter().take(MAX_USERS) {
+            println!("{:>14}  {instantiation_count:>14}    used by {user}", "");
+        }
+        if item.users.len() > MAX_USERS {
+            println!("{:>14}  {:>14}    used by {} more", "", "", item.users.len() - MAX_USERS);
+        }
+    }
+}
+
 pub fn provide(providers: &mut Providers) {
     providers.collect_and_partition_mono_items = collect_and_partition_mono_items;
 
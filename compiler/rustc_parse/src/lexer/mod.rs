@@ -509,6 +509,11 @@ fn cook_lexer_literal(
                     self.sess.emit_err(errors::EmptyExponentFloat { span });
                 }
                 let base = match base {
+                    // Only hexadecimal floats with a binary exponent are supported.
+                    Base::Hexadecimal if self.str_from_to(start, end).contains(['p', 'P']) => {
+                        self.sess.gated_spans.gate(sym::hex_float_literals, self.mk_sp(start, end));
+                        None
+                    }
                     Base::Hexadecimal => Some("hexadecimal"),
                     Base::Octal => Some("octal"),
                     Base::Binary => Some("binary"),
@@ -2512,6 +2512,7 @@ fn check_invalid_crate_level_attr(tcx: TyCtxt<'_>, attrs: &[Attribute]) {
         sym::derive,
         sym::test,
         sym::test_case,
+        sym::test_fixture,
         sym::global_allocator,
         sym::bench,
     ];
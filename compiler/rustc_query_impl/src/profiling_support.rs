@@ -157,7 +157,7 @@ fn spec_to_self_profile_string(&self, builder: &mut QueryKeyStringBuilder<'_, '_
             StringComponent::Value(")"),
         ];
 
-        builder.profiler.alloc_string(components)
+        builder.profiler.alloc_string(&components[..])
     }
 }
 
@@ -100,6 +100,22 @@ pub(crate) struct DepGraphData<D: Deps> {
     /// a particular query result was decoded from disk
     /// (not just marked green)
     debug_loaded_from_disk: Lock<FxHashSet<DepNode>>,
+
+    /// When `-Z incremental-explain` is enabled, maps each node of the previous session which
+    /// could not be marked green to the dependency which prevented it.
+    red_dependencies: Option<Lock<FxHashMap<SerializedDepNodeIndex, SerializedDepNodeIndex>>>,
+}
+
+/// A node of the previous session whose query had to be executed again, as reported by
+/// [`DepGraph::recomputed_nodes`].
+#[derive(Debug)]
+pub struct RecomputedNode {
+    pub node: DepNode,
+    /// Whether the query result differs from the one of the previous session.
+    pub result_changed: bool,
+    /// The chain of dependencies which prevented the node from being marked green: each node
+    /// depends on the next one, and the last one is the changed input.
+    pub causes: Vec<DepNode>,
 }
 
 pub fn hash_result<R>(hcx: &mut StableHashingContext<'_>, result: &R) -> Fingerprint
@@ -119,6 +135,7 @@ pub fn new(
         encoder: FileEncoder,
         record_graph: bool,
         record_stats: bool,
+        record_red_dependencies: bool,
     ) -> DepGraph<D> {
         let prev_graph_node_count = prev_graph.node_count();
 
@@ -174,6 +191,7 @@ pub fn new(
                 previous: prev_graph,
                 colors,
                 debug_loaded_from_disk: Default::default(),
+                red_dependencies: record_red_dependencies.then(Default::default),
             })),
             virtual_dep_node_index: Lrc::new(AtomicU32::new(0)),
         }
@@ -861,7 +879,13 @@ fn try_mark_previous_green<Qcx: QueryContext<Deps = D>>(
         let prev_deps = self.previous.edge_targets_from(prev_dep_node_index);
 
         for dep_dep_node_index in prev_deps {
-            self.try_mark_parent_green(qcx, dep_dep_node_index, dep_node, Some(&frame))?;
+            if self.try_mark_parent_green(qcx, dep_dep_node_index, dep_node, Some(&frame)).is_none()
+            {
+                if let Some(red_dependencies) = &self.red_dependencies {
+                    red_dependencies.lock().insert(prev_dep_node_index, dep_dep_node_index);
+                }
+                return None;
+            }
         }
 
         // If we got here without hitting a `return` that means that all
@@ -973,6 +997,49 @@ pub fn exec_cache_promotions<Tcx: DepContext>(&self, tcx: Tcx) {
         }
     }
 
+    /// Returns the nodes of the previous session which could not be marked green, and whose
+    /// query was executed again. Returns `None` unless the graph was created with
+    /// `record_red_dependencies`.
+    pub fn recomputed_nodes(&self) -> Option<Vec<RecomputedNode>> {
+        let data = self.data.as_ref()?;
+        let red_dependencies = data.red_dependencies.as_ref()?.lock();
+
+        let mut recomputed: Vec<_> = red_dependencies
+            .keys()
+            .filter_map(|&prev_index| {
+                // Nodes without a color were not needed again after all.
+                let result_changed = match data.colors.get(prev_index)? {
+                    DepNodeColor::Red => true,
+                    DepNodeColor::Green(_) => false,
+                };
+
+                let mut causes = Vec::new();
+                let mut visited = FxHashSet::default();
+                let mut cause = red_dependencies.get(&prev_index);
+                while let Some(&cause_index) = cause {
+                    if !visited.insert(cause_index) {
+                        break;
+                    }
+                    causes.push(data.previous.index_to_node(cause_index));
+                    cause = red_dependencies.get(&cause_index);
+                }
+
+                Some((prev_index, result_changed, causes))
+            })
+            .collect();
+        recomputed.sort_unstable_by_key(|&(prev_index, ..)| prev_index);
+
+        let recomputed = recomputed
+            .into_iter()
+            .map(|(prev_index, result_changed, causes)| RecomputedNode {
+                node: data.previous.index_to_node(prev_index),
+                result_changed,
+                causes,
+            })
+            .collect();
+        Some(recomputed)
+    }
+
     pub fn print_incremental_info(&self) {
         if let Some(data) = &self.data {
             data.current.encoder.borrow().print_incremental_info(
@@ -544,6 +544,12 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A single SARIF 2.1.0 log, written once compilation is over. Consumed by
+    /// code-scanning services.
+    Sarif {
+        /// Render the log in a human readable way (with indents and newlines).
+        pretty: bool,
+    },
 }
 
 impl Default for ErrorOutputType {
@@ -1961,6 +1967,8 @@ pub fn parse_error_format(
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
+            Some("sarif") => ErrorOutputType::Sarif { pretty: false },
+            Some("pretty-sarif") => ErrorOutputType::Sarif { pretty: true },
 
             Some(arg) => {
                 handler.abort_if_error_and_set_error_format(ErrorOutputType::HumanReadable(
@@ -2041,6 +2049,14 @@ fn check_error_format_stability(
             });
             handler.early_error("`--error-format=human-annotate-rs` is unstable");
         }
+        if let ErrorOutputType::Sarif { pretty } = error_format {
+            handler.abort_if_error_and_set_error_format(ErrorOutputType::Json {
+                pretty: false,
+                json_rendered,
+            });
+            let format = if pretty { "pretty-sarif" } else { "sarif" };
+            handler.early_error(format!("`--error-format={format}` is unstable"));
+        }
     }
 }
 
@@ -2811,6 +2827,10 @@ pub fn build_session_options(
         handler.early_warn("using -Z remark-dir without enabling remarks using e.g. -C remark=all");
     }
 
+    if unstable_opts.print_self_profile_summary && !unstable_opts.self_profile.enabled() {
+        handler.early_warn("-Z print-self-profile-summary requires -Z self-profile to be enabled");
+    }
+
     let externs = parse_externs(handler, matches, &unstable_opts);
 
     let crate_name = matches.opt_str("crate-name");
@@ -3412,6 +3432,18 @@ pub fn extension(self) -> &'static str {
     }
 }
 
+/// The action requested by `-Z inspect-incremental`.
+#[derive(Clone, PartialEq, Hash, Debug)]
+pub enum InspectIncremental {
+    /// List the dependency graph nodes and work products of a session directory.
+    List,
+    /// Explain which codegen units were invalidated since the given, older, session directory.
+    Diff(PathBuf),
+    /// Garbage collect the session directories of every crate in an incremental directory, and
+    /// remove the crates whose most recent session is older than the given number of days.
+    Prune { max_age_days: Option<u64> },
+}
+
 /// `-Zpolonius` values, enabling the borrow checker polonius analysis, and which version: legacy,
 /// or future prototype.
 #[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
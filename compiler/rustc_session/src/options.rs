@@ -3,7 +3,7 @@
 use crate::search_paths::SearchPath;
 use crate::utils::NativeLib;
 use crate::{lint, EarlyErrorHandler};
-use rustc_data_structures::profiling::TimePassesFormat;
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_data_structures::stable_hasher::Hash64;
 use rustc_errors::ColorConfig;
 use rustc_errors::{LanguageIdentifier, TerminalUrl};
@@ -370,6 +370,7 @@ mod desc {
     pub const parse_opt_number: &str = parse_number;
     pub const parse_threads: &str = parse_number;
     pub const parse_time_passes_format: &str = "`text` (default) or `json`";
+    pub const parse_self_profile_format: &str = "`measureme` (default) or `chrome`";
     pub const parse_passes: &str = "a space-separated list of passes, or `all`";
     pub const parse_panic_strategy: &str = "either `unwind` or `abort`";
     pub const parse_opt_panic_strategy: &str = parse_panic_strategy;
@@ -387,6 +388,8 @@ mod desc {
     pub const parse_optimization_fuel: &str = "crate=integer";
     pub const parse_mir_spanview: &str = "`statement` (default), `terminator`, or `block`";
     pub const parse_dump_mono_stats: &str = "`markdown` (default) or `json`";
+    pub const parse_inspect_incremental: &str =
+        "`list`, `diff=<old-session-dir>`, `prune`, or `prune=<days>`";
     pub const parse_instrument_coverage: &str =
         "`all` (default), `branch`, `except-unused-generics`, `except-unused-functions`, or `off`";
     pub const parse_instrument_xray: &str = "either a boolean (`yes`, `no`, `on`, `off`, etc), or a comma separated list of settings: `always` or `never` (mutually exclusive), `ignore-loops`, `instruction-threshold=N`, `skip-entry`, `skip-exit`";
@@ -401,7 +404,8 @@ mod desc {
     pub const parse_location_detail: &str = "either `none`, or a comma separated list of location details to track: `file`, `line`, or `column`";
     pub const parse_switch_with_opt_path: &str =
         "an optional path to the profiling data output directory";
-    pub const parse_merge_functions: &str = "one of: `disabled`, `trampolines`, or `aliases`";
+    pub const parse_merge_functions: &str =
+        "one of: `disabled`, `trampolines`, `aliases`, or `mir`";
     pub const parse_symbol_mangling_version: &str = "either `legacy` or `v0` (RFC 2603)";
     pub const parse_src_file_hash: &str = "either `md5` or `sha1`";
     pub const parse_relocation_model: &str =
@@ -899,6 +903,40 @@ pub(crate) fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&s
         }
     }
 
+    pub(crate) fn parse_self_profile_format(slot: &mut SelfProfileFormat, v: Option<&str>) -> bool {
+        match v {
+            None => false,
+            Some("chrome") => {
+                *slot = SelfProfileFormat::Chrome;
+                true
+            }
+            Some("measureme") => {
+                *slot = SelfProfileFormat::Measureme;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    pub(crate) fn parse_inspect_incremental(
+        slot: &mut Option<InspectIncremental>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v.map(|v| v.split_once('=').unwrap_or((v, ""))) {
+            Some(("list", "")) => Some(InspectIncremental::List),
+            Some(("diff", old_session)) if !old_session.is_empty() => {
+                Some(InspectIncremental::Diff(PathBuf::from(old_session)))
+            }
+            Some(("prune", "")) => Some(InspectIncremental::Prune { max_age_days: None }),
+            Some(("prune", days)) => match days.parse() {
+                Ok(days) => Some(InspectIncremental::Prune { max_age_days: Some(days) }),
+                Err(_) => return false,
+            },
+            _ => return false,
+        };
+        true
+    }
+
     pub(crate) fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&str>) -> bool {
         match v {
             None => true,
@@ -1570,6 +1608,9 @@ pub(crate) fn parse_llvm_module_flag(
         all `statement`s (including terminators), only `terminator` spans, or \
         computed `block` spans (one span encompassing a block's terminator and \
         all statements)."),
+    dump_mono_provenance: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output, for every instantiation of a generic item, the use that caused it"),
     dump_mono_stats: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
         parse_switch_with_opt_path, [UNTRACKED],
         "output statistics about monomorphization collection"),
@@ -1584,6 +1625,9 @@ pub(crate) fn parse_llvm_module_flag(
         "enables LTO for dylib crate type"),
     ehcont_guard: bool = (false, parse_bool, [TRACKED],
         "generate Windows EHCont Guard tables"),
+    emit_size_report: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write a JSON report attributing the bytes of the emitted object files to crates, \
+        modules, functions and monomorphized instances to the given path"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
     emit_thin_lto: bool = (true, parse_bool, [TRACKED],
@@ -1620,6 +1664,9 @@ pub(crate) fn parse_llvm_module_flag(
         "display unnamed regions as `'<id>`, using a non-ident unique id (default: no)"),
     ignore_directory_in_diagnostics_source_blocks: Vec<String> = (Vec::new(), parse_string_push, [UNTRACKED],
         "do not display the source code block in diagnostics for files in the directory"),
+    incremental_explain: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write a JSON report to the given file, explaining why each query which could not be \
+        reused from the incremental compilation cache had to be executed again"),
     incremental_ignore_spans: bool = (false, parse_bool, [TRACKED],
         "ignore spans during ICH computation -- used for testing (default: no)"),
     incremental_info: bool = (false, parse_bool, [UNTRACKED],
@@ -1639,6 +1686,11 @@ pub(crate) fn parse_llvm_module_flag(
         "a default MIR inlining threshold (default: 50)"),
     input_stats: bool = (false, parse_bool, [UNTRACKED],
         "gather statistics about the input (default: no)"),
+    inspect_incremental: Option<InspectIncremental> = (None, parse_inspect_incremental, [UNTRACKED],
+        "inspect the incremental compilation directory given as input instead of compiling it: \
+        `list` the contents of a session directory, `diff=<old-session-dir>` to explain which \
+        codegen units were invalidated since an older session, or `prune` the stale sessions of \
+        a shared incremental directory, optionally dropping crates unused for the given days"),
     instrument_mcount: bool = (false, parse_bool, [TRACKED],
         "insert function instrument code for mcount-based tracing (default: no)"),
     instrument_xray: Option<InstrumentXRay> = (None, parse_instrument_xray, [TRACKED],
@@ -1679,7 +1731,8 @@ pub(crate) fn parse_llvm_module_flag(
         as source scopes (default: no)"),
     merge_functions: Option<MergeFunctions> = (None, parse_merge_functions, [TRACKED],
         "control the operation of the MergeFunctions LLVM pass, taking \
-        the same values as the target option of the same name"),
+        the same values as the target option of the same name, or merge \
+        functions with identical MIR bodies before codegen with `mir`"),
     meta_stats: bool = (false, parse_bool, [UNTRACKED],
         "gather metadata statistics (default: no)"),
     mir_emit_retag: bool = (false, parse_bool, [TRACKED],
@@ -1765,6 +1818,12 @@ pub(crate) fn parse_llvm_module_flag(
         "print the LLVM optimization passes being run (default: no)"),
     print_mono_items: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "print the result of the monomorphization collection pass"),
+    print_mono_provenance: Option<usize> = (None, parse_opt_number, [UNTRACKED],
+        "print the given number of generic items with the largest estimated size of \
+        their instantiations, and the uses that caused them"),
+    print_self_profile_summary: bool = (false, parse_bool, [UNTRACKED],
+        "print a summary table of the events recorded by the self profiler, which must be \
+        enabled with `-Z self-profile` (default: no)"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print layout information for each type encountered (default: no)"),
     print_vtable_sizes: bool = (false, parse_bool, [UNTRACKED],
@@ -1832,6 +1891,10 @@ pub(crate) fn parse_llvm_module_flag(
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, incr-result-hashing, query-keys, function-args, args, llvm, artifact-sizes"),
+    self_profile_format: SelfProfileFormat = (SelfProfileFormat::Measureme,
+        parse_self_profile_format, [UNTRACKED],
+        "the format of the self profiler output (default: `measureme`); `chrome` also writes \
+        a Chrome trace event file that can be viewed without the `measureme` tools"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     show_span: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -23,6 +23,7 @@
 use rustc_errors::emitter::{DynEmitter, EmitterWriter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{
     error_code, fallback_fluent_bundle, DiagnosticBuilder, DiagnosticId, DiagnosticMessage,
     ErrorGuaranteed, FluentBundle, Handler, IntoDiagnostic, LazyFallbackBundle, MultiSpan, Noted,
@@ -1353,6 +1354,10 @@ fn default_emitter(
                 sopts.unstable_opts.ignore_directory_in_diagnostics_source_blocks.clone(),
             ),
         ),
+        config::ErrorOutputType::Sarif { pretty } => Box::new(
+            SarifEmitter::stderr(Some(registry), source_map, bundle, fallback_bundle, pretty)
+                .ui_testing(sopts.unstable_opts.ui_testing),
+        ),
     }
 }
 
@@ -1433,6 +1438,8 @@ pub fn build_session(
             sopts.crate_name.as_deref(),
             sopts.unstable_opts.self_profile_events.as_deref(),
             &sopts.unstable_opts.self_profile_counter,
+            sopts.unstable_opts.self_profile_format,
+            sopts.unstable_opts.print_self_profile_summary,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1784,6 +1791,9 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
             false,
             TerminalUrl::No,
         )),
+        config::ErrorOutputType::Sarif { pretty } => {
+            Box::new(SarifEmitter::basic(pretty, None, fallback_bundle))
+        }
     };
     emitter
 }
@@ -5,14 +5,16 @@
 
 // Prefer importing stable_mir over internal rustc constructs to make this file more readable.
 use crate::rustc_smir::Tables;
-use rustc_middle::ty::{self as rustc_ty, Ty as InternalTy};
+use rustc_middle::traits::Reveal;
+use rustc_middle::ty::{self as rustc_ty, ToPredicate, Ty as InternalTy, TypeVisitableExt};
 use rustc_span::Symbol;
 use stable_mir::mir::alloc::AllocId;
 use stable_mir::mir::mono::{Instance, MonoItem, StaticDef};
 use stable_mir::ty::{
-    AdtDef, Binder, BoundRegionKind, BoundTyKind, BoundVariableKind, ClosureKind, Const,
-    ExistentialTraitRef, FloatTy, GenericArgKind, GenericArgs, IntTy, Region, RigidTy, Span,
-    TraitRef, Ty, UintTy,
+    AdtDef, AliasTy, Binder, BoundRegionKind, BoundTyKind, BoundVariableKind, ClauseKind,
+    ClosureKind, Const, ExistentialTraitRef, FloatTy, GenericArgKind, GenericArgs, ImplPolarity,
+    IntTy, OutlivesPredicate, ParamEnv, ProjectionPredicate, Region, RigidTy, Span, TermKind,
+    TraitPredicate, TraitRef, Ty, UintTy,
 };
 use stable_mir::{CrateItem, DefId};
 
@@ -135,8 +137,10 @@ impl<'tcx> RustcInternal<'tcx> for FloatTy {
 
     fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
         match self {
+            FloatTy::F16 => rustc_ty::FloatTy::F16,
             FloatTy::F32 => rustc_ty::FloatTy::F32,
             FloatTy::F64 => rustc_ty::FloatTy::F64,
+            FloatTy::F128 => rustc_ty::FloatTy::F128,
         }
     }
 }
@@ -253,6 +257,116 @@ fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
     }
 }
 
+impl<'tcx> RustcInternal<'tcx> for ImplPolarity {
+    type T = rustc_ty::ImplPolarity;
+
+    fn internal(&self, _tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            ImplPolarity::Positive => rustc_ty::ImplPolarity::Positive,
+            ImplPolarity::Negative => rustc_ty::ImplPolarity::Negative,
+            ImplPolarity::Reservation => rustc_ty::ImplPolarity::Reservation,
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for TraitPredicate {
+    type T = rustc_ty::TraitPredicate<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_ty::TraitPredicate {
+            trait_ref: self.trait_ref.internal(tables),
+            polarity: self.polarity.internal(tables),
+        }
+    }
+}
+
+impl<'tcx, A, B> RustcInternal<'tcx> for OutlivesPredicate<A, B>
+where
+    A: RustcInternal<'tcx>,
+    B: RustcInternal<'tcx>,
+{
+    type T = rustc_ty::OutlivesPredicate<A::T, B::T>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_ty::OutlivesPredicate(self.0.internal(tables), self.1.internal(tables))
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for AliasTy {
+    type T = rustc_ty::AliasTy<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_ty::AliasTy::new(
+            tables.tcx,
+            self.def_id.0.internal(tables),
+            self.args.internal(tables),
+        )
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for TermKind {
+    type T = rustc_ty::Term<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            TermKind::Type(ty) => ty.internal(tables).into(),
+            TermKind::Const(cnst) => ty_const(cnst, tables).into(),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for ProjectionPredicate {
+    type T = rustc_ty::ProjectionPredicate<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        rustc_ty::ProjectionPredicate {
+            projection_ty: self.projection_ty.internal(tables),
+            term: self.term.internal(tables),
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for ClauseKind {
+    type T = rustc_ty::ClauseKind<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        match self {
+            ClauseKind::Trait(pred) => rustc_ty::ClauseKind::Trait(pred.internal(tables)),
+            ClauseKind::RegionOutlives(pred) => {
+                rustc_ty::ClauseKind::RegionOutlives(pred.internal(tables))
+            }
+            ClauseKind::TypeOutlives(pred) => {
+                rustc_ty::ClauseKind::TypeOutlives(pred.internal(tables))
+            }
+            ClauseKind::Projection(pred) => rustc_ty::ClauseKind::Projection(pred.internal(tables)),
+            ClauseKind::ConstArgHasType(cnst, ty) => {
+                rustc_ty::ClauseKind::ConstArgHasType(ty_const(cnst, tables), ty.internal(tables))
+            }
+            ClauseKind::WellFormed(arg) => rustc_ty::ClauseKind::WellFormed(arg.internal(tables)),
+            ClauseKind::ConstEvaluatable(cnst) => {
+                rustc_ty::ClauseKind::ConstEvaluatable(ty_const(cnst, tables))
+            }
+        }
+    }
+}
+
+impl<'tcx> RustcInternal<'tcx> for ParamEnv {
+    type T = rustc_ty::ParamEnv<'tcx>;
+
+    fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
+        // Bound variables are not tracked by the stable clauses, so skip the ones that would
+        // have escaping bound variables once rebuilt.
+        let clauses: Vec<rustc_ty::Clause<'tcx>> = self
+            .caller_bounds
+            .iter()
+            .map(|clause| clause.internal(tables))
+            .filter(|clause| !clause.has_escaping_bound_vars())
+            .map(|clause| clause.to_predicate(tables.tcx))
+            .collect();
+        rustc_ty::ParamEnv::new(tables.tcx.mk_clauses(&clauses), Reveal::UserFacing)
+    }
+}
+
 impl<'tcx> RustcInternal<'tcx> for AllocId {
     type T = rustc_middle::mir::interpret::AllocId;
     fn internal(&self, tables: &mut Tables<'tcx>) -> Self::T {
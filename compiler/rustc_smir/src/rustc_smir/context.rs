@@ -3,16 +3,23 @@
 //! This trait is currently the main interface between the Rust compiler,
 //! and the `stable_mir` crate.
 
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_middle::traits::ObligationCause;
 use rustc_middle::ty::print::{with_forced_trimmed_paths, with_no_trimmed_paths};
-use rustc_middle::ty::{GenericPredicates, Instance, ParamEnv, ScalarInt, ValTree};
+use rustc_middle::ty::{
+    GenericPredicates, Instance, List, ParamEnv, ScalarInt, TypeVisitableExt, ValTree,
+};
 use rustc_span::def_id::LOCAL_CRATE;
+use rustc_trait_selection::infer::InferCtxtExt;
+use rustc_trait_selection::traits::ObligationCtxt;
+use stable_mir::abi::{FnAbi, Layout, LayoutShape};
 use stable_mir::compiler_interface::Context;
 use stable_mir::mir::alloc::GlobalAlloc;
 use stable_mir::mir::mono::{InstanceDef, StaticDef};
 use stable_mir::mir::Body;
 use stable_mir::ty::{
     AdtDef, AdtKind, Allocation, ClosureDef, ClosureKind, Const, FnDef, GenericArgs, LineInfo,
-    RigidTy, Span, TyKind,
+    RigidTy, Span, TraitRef, TyKind,
 };
 use stable_mir::{self, Crate, CrateItem, Error, Filename, ItemKind, Symbol};
 use std::cell::RefCell;
@@ -371,6 +378,82 @@ fn instance_name(&self, def: InstanceDef, trimmed: bool) -> Symbol {
             )
         }
     }
+
+    fn ty_layout(&self, ty: stable_mir::ty::Ty) -> Result<Layout, Error> {
+        let mut tables = self.0.borrow_mut();
+        let ty = ty.internal(&mut *tables);
+        let layout = tables
+            .tcx
+            .layout_of(ParamEnv::reveal_all().and(ty))
+            .map_err(|err| Error::new(format!("Failed to get layout for `{ty}`: {err}")))?;
+        Ok(layout.layout.stable(&mut *tables))
+    }
+
+    fn layout_shape(&self, id: Layout) -> LayoutShape {
+        let mut tables = self.0.borrow_mut();
+        let layout = tables.layouts[id];
+        layout.0.0.stable(&mut *tables)
+    }
+
+    fn instance_abi(&self, def: InstanceDef) -> Result<FnAbi, Error> {
+        let mut tables = self.0.borrow_mut();
+        let instance = tables.instances[def];
+        let fn_abi = tables
+            .tcx
+            .fn_abi_of_instance(ParamEnv::reveal_all().and((instance, List::empty())))
+            .map_err(|err| {
+                Error::new(format!("Failed to get ABI for instance `{instance}`: {err:?}"))
+            })?;
+        Ok(fn_abi.stable(&mut *tables))
+    }
+
+    fn implements_trait(&self, trait_ref: &TraitRef, param_env: &stable_mir::ty::ParamEnv) -> bool {
+        let mut tables = self.0.borrow_mut();
+        let trait_ref = trait_ref.internal(&mut *tables);
+        let param_env = param_env.internal(&mut *tables);
+        let infcx = tables.tcx.infer_ctxt().build();
+        infcx
+            .type_implements_trait(trait_ref.def_id, trait_ref.args, param_env)
+            .must_apply_modulo_regions()
+    }
+
+    fn normalize(
+        &self,
+        ty: stable_mir::ty::Ty,
+        param_env: &stable_mir::ty::ParamEnv,
+    ) -> Result<stable_mir::ty::Ty, Error> {
+        let mut tables = self.0.borrow_mut();
+        let ty = ty.internal(&mut *tables);
+        let param_env = param_env.internal(&mut *tables);
+        let infcx = tables.tcx.infer_ctxt().build();
+        let ocx = ObligationCtxt::new(&infcx);
+        let normalized = ocx.normalize(&ObligationCause::dummy(), param_env, ty);
+        let errors = ocx.select_all_or_error();
+        if !errors.is_empty() {
+            return Err(Error::new(format!("Failed to normalize `{ty}`: {errors:?}")));
+        }
+        let normalized = infcx.resolve_vars_if_possible(normalized);
+        if normalized.has_infer() {
+            return Err(Error::new(format!("Failed to normalize `{ty}`: result is ambiguous")));
+        }
+        Ok(tables.tcx.erase_regions(normalized).stable(&mut *tables))
+    }
+
+    fn is_subtype(
+        &self,
+        sub: stable_mir::ty::Ty,
+        sup: stable_mir::ty::Ty,
+        param_env: &stable_mir::ty::ParamEnv,
+    ) -> bool {
+        let mut tables = self.0.borrow_mut();
+        let sub = sub.internal(&mut *tables);
+        let sup = sup.internal(&mut *tables);
+        let param_env = param_env.internal(&mut *tables);
+        let infcx = tables.tcx.infer_ctxt().build();
+        let ocx = ObligationCtxt::new(&infcx);
+        ocx.sub(&ObligationCause::dummy(), param_env, sub, sup).is_ok()
+            && ocx.select_all_or_error().is_empty()
+    }
 }
 
 pub struct TablesWrapper<'tcx>(pub RefCell<Tables<'tcx>>);
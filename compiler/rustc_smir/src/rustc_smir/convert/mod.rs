@@ -5,6 +5,7 @@
 
 use crate::rustc_smir::{Stable, Tables};
 
+mod abi;
 mod mir;
 mod ty;
 
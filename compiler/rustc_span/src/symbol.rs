@@ -755,6 +755,8 @@
         extern_types,
         external_doc,
         f,
+        f128,
+        f16,
         f16c_target_feature,
         f32,
         f32_nan,
@@ -842,6 +844,7 @@
         generic_const_items,
         generic_param_attrs,
         get_context,
+        global,
         global_allocator,
         global_asm,
         globs,
@@ -849,6 +852,8 @@
         half_open_range_patterns,
         half_open_range_patterns_in_slices,
         hash,
+        hex_float_fmt,
+        hex_float_literals,
         hexagon_target_feature,
         hidden,
         homogeneous_aggregate,
@@ -1106,11 +1111,13 @@
         new_display,
         new_lower_exp,
         new_lower_hex,
+        new_lower_hex_float,
         new_octal,
         new_pointer,
         new_unchecked,
         new_upper_exp,
         new_upper_hex,
+        new_upper_hex_float,
         new_v1,
         new_v1_formatted,
         next,
@@ -1460,6 +1467,7 @@
         rustc_std_internal_symbol,
         rustc_strict_coherence,
         rustc_symbol_name,
+        rustc_test_fixture_marker,
         rustc_test_marker,
         rustc_then_this_would_need,
         rustc_trivial_field_reads,
@@ -1652,6 +1660,7 @@
         test_2018_feature,
         test_accepted_feature,
         test_case,
+        test_fixture,
         test_removed_feature,
         test_runner,
         test_unstable_lint,
@@ -356,6 +356,10 @@ fn print_type(&mut self, ty: Ty<'tcx>) -> Result<(), PrintError> {
             ty::Uint(UintTy::Usize) => "j",
             ty::Float(FloatTy::F32) => "f",
             ty::Float(FloatTy::F64) => "d",
+            // These don't have a basic type tag yet, so they're mangled as paths
+            // to the primitive, like `C3f16`.
+            ty::Float(FloatTy::F16) => "C3f16",
+            ty::Float(FloatTy::F128) => "C4f128",
             ty::Never => "z",
 
             // Placeholders (should be demangled as `_`).
@@ -780,6 +780,9 @@ pub enum MergeFunctions {
     Disabled,
     Trampolines,
     Aliases,
+    /// Like `Aliases`, but also merges functions with identical MIR bodies
+    /// before codegen.
+    Mir,
 }
 
 impl MergeFunctions {
@@ -788,6 +791,7 @@ pub fn desc(&self) -> &str {
             MergeFunctions::Disabled => "disabled",
             MergeFunctions::Trampolines => "trampolines",
             MergeFunctions::Aliases => "aliases",
+            MergeFunctions::Mir => "mir",
         }
     }
 }
@@ -800,6 +804,7 @@ fn from_str(s: &str) -> Result<MergeFunctions, ()> {
             "disabled" => Ok(MergeFunctions::Disabled),
             "trampolines" => Ok(MergeFunctions::Trampolines),
             "aliases" => Ok(MergeFunctions::Aliases),
+            "mir" => Ok(MergeFunctions::Mir),
             _ => Err(()),
         }
     }
@@ -811,6 +816,7 @@ fn to_json(&self) -> Json {
             MergeFunctions::Disabled => "disabled".to_json(),
             MergeFunctions::Trampolines => "trampolines".to_json(),
             MergeFunctions::Aliases => "aliases".to_json(),
+            MergeFunctions::Mir => "mir".to_json(),
         }
     }
 }
@@ -2121,7 +2127,7 @@ pub struct TargetOptions {
     pub override_export_symbols: Option<StaticCow<[StaticCow<str>]>>,
 
     /// Determines how or whether the MergeFunctions LLVM pass should run for
-    /// this target. Either "disabled", "trampolines", or "aliases".
+    /// this target. Either "disabled", "trampolines", "aliases", or "mir".
     /// The MergeFunctions pass is generally useful, but some targets may need
     /// to opt out. The default is "aliases".
     ///
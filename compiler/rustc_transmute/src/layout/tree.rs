@@ -274,10 +274,10 @@ pub fn from_ty(ty: Ty<'tcx>, tcx: TyCtxt<'tcx>) -> Result<Self, Err> {
                 ty::Bool => Ok(Self::bool()),
 
                 ty::Int(I8) | ty::Uint(U8) => Ok(Self::u8()),
-                ty::Int(I16) | ty::Uint(U16) => Ok(Self::number(2)),
+                ty::Int(I16) | ty::Uint(U16) | ty::Float(F16) => Ok(Self::number(2)),
                 ty::Int(I32) | ty::Uint(U32) | ty::Float(F32) => Ok(Self::number(4)),
                 ty::Int(I64) | ty::Uint(U64) | ty::Float(F64) => Ok(Self::number(8)),
-                ty::Int(I128) | ty::Uint(U128) => Ok(Self::number(16)),
+                ty::Int(I128) | ty::Uint(U128) | ty::Float(F128) => Ok(Self::number(16)),
                 ty::Int(Isize) | ty::Uint(Usize) => {
                     Ok(Self::number(target.pointer_size.bytes_usize()))
                 }
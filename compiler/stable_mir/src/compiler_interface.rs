@@ -5,13 +5,14 @@
 
 use std::cell::Cell;
 
+use crate::abi::{FnAbi, Layout, LayoutShape};
 use crate::mir::alloc::{AllocId, GlobalAlloc};
 use crate::mir::mono::{Instance, InstanceDef, StaticDef};
 use crate::mir::Body;
 use crate::ty::{
     AdtDef, AdtKind, Allocation, ClosureDef, ClosureKind, Const, FnDef, GenericArgs,
-    GenericPredicates, Generics, ImplDef, ImplTrait, LineInfo, RigidTy, Span, TraitDecl, TraitDef,
-    Ty, TyKind,
+    GenericPredicates, Generics, ImplDef, ImplTrait, LineInfo, ParamEnv, RigidTy, Span, TraitDecl,
+    TraitDef, TraitRef, Ty, TyKind,
 };
 use crate::{
     mir, Crate, CrateItem, CrateItems, DefId, Error, Filename, ImplTraitDecls, ItemKind, Symbol,
@@ -132,6 +133,24 @@ fn resolve_closure(
     fn vtable_allocation(&self, global_alloc: &GlobalAlloc) -> Option<AllocId>;
     fn krate(&self, def_id: DefId) -> Crate;
     fn instance_name(&self, def: InstanceDef, trimmed: bool) -> Symbol;
+
+    /// Get the layout of a type.
+    fn ty_layout(&self, ty: Ty) -> Result<Layout, Error>;
+
+    /// Get the layout shape.
+    fn layout_shape(&self, id: Layout) -> LayoutShape;
+
+    /// Get an instance ABI.
+    fn instance_abi(&self, def: InstanceDef) -> Result<FnAbi, Error>;
+
+    /// Check whether the trait reference holds under the given environment.
+    fn implements_trait(&self, trait_ref: &TraitRef, param_env: &ParamEnv) -> bool;
+
+    /// Normalize a type under the given environment.
+    fn normalize(&self, ty: Ty, param_env: &ParamEnv) -> Result<Ty, Error>;
+
+    /// Check whether `sub` is a subtype of `sup` under the given environment.
+    fn is_subtype(&self, sub: Ty, sup: Ty, param_env: &ParamEnv) -> bool;
 }
 
 // A thread local variable that stores a pointer to the tables mapping between TyCtxt
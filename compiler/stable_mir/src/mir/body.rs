@@ -1,4 +1,4 @@
-use crate::mir::pretty::{function_body, pretty_statement};
+use crate::mir::pretty::{function_body, pretty_statement, pretty_terminator};
 use crate::ty::{
     AdtDef, ClosureDef, Const, CoroutineDef, GenericArgs, Movability, Region, RigidTy, Ty, TyKind,
 };
@@ -68,6 +68,44 @@ pub fn locals(&self) -> &[LocalDecl] {
         &self.locals
     }
 
+    /// Appends a new local to this function's inner locals and returns it.
+    pub fn add_local(&mut self, ty: Ty, span: Span, mutability: Mutability) -> Local {
+        self.locals.push(LocalDecl { ty, span, mutability });
+        self.locals.len() - 1
+    }
+
+    /// Inserts `statement` into the basic block `bb`, before its statement at
+    /// index `at`, or after its last statement if `at` is their number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bb` is not a basic block of this body, or if `at` is greater
+    /// than the number of statements of `bb`.
+    pub fn insert_statement(&mut self, bb: usize, at: usize, statement: Statement) {
+        self.blocks[bb].statements.insert(at, statement);
+    }
+
+    /// Splits the basic block `bb` right before its statement at index `at`.
+    ///
+    /// The statements starting at `at` and the terminator of `bb` are moved to a
+    /// new basic block, which is appended to the body, and `bb` is terminated
+    /// with a `Goto` to it. Returns the index of the new basic block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bb` is not a basic block of this body, or if `at` is greater
+    /// than the number of statements of `bb`.
+    pub fn split_block(&mut self, bb: usize, at: usize) -> usize {
+        let new_bb = self.blocks.len();
+        let block = &mut self.blocks[bb];
+        let statements = block.statements.split_off(at);
+        let span = block.terminator.span;
+        let goto = Terminator { kind: TerminatorKind::Goto { target: new_bb }, span };
+        let terminator = std::mem::replace(&mut block.terminator, goto);
+        self.blocks.push(BasicBlock { statements, terminator });
+        new_bb
+    }
+
     pub fn dump<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
         writeln!(w, "{}", function_body(self))?;
         self.blocks
@@ -83,6 +121,7 @@ pub fn dump<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
                         Ok(())
                     })
                     .collect::<Vec<_>>();
+                writeln!(w, "{}", pretty_terminator(&block.terminator.kind))?;
                 writeln!(w, "    }}").unwrap();
                 Ok(())
             })
@@ -1,7 +1,10 @@
 use crate::crate_def::CrateDef;
-use crate::mir::{Operand, Rvalue, StatementKind};
+use crate::mir::{
+    NonDivergingIntrinsic, Operand, Rvalue, StatementKind, TerminatorKind, UnwindAction,
+};
 use crate::ty::{DynKind, FloatTy, IntTy, RigidTy, TyKind, UintTy};
 use crate::{with, Body, CrateItem, Mutability};
+use std::fmt::Write;
 
 pub fn function_name(item: CrateItem) -> String {
     let mut pretty_name = String::new();
@@ -32,7 +35,7 @@ pub fn function_body(body: &Body) -> String {
     body.inner_locals().iter().enumerate().for_each(|(index, local)| {
         pretty_body.push_str("    ");
         pretty_body.push_str(format!("let {}", ret_mutability(&local.mutability)).as_str());
-        pretty_body.push_str(format!("_{}: ", index).as_str());
+        write!(pretty_body, "_{}: ", index + body.arg_locals().len() + 1).unwrap();
         pretty_body.push_str(format!("{}", pretty_ty(local.ty.kind())).as_str());
         pretty_body.push_str(";\n");
     });
@@ -54,22 +57,120 @@ pub fn pretty_statement(statement: &StatementKind) -> String {
             pretty.push_str(format!("        _{} = ", place.local).as_str());
             pretty.push_str(format!("{}", &pretty_rvalue(rval)).as_str());
         }
-        StatementKind::FakeRead(_, _) => todo!(),
-        StatementKind::SetDiscriminant { .. } => todo!(),
-        StatementKind::Deinit(_) => todo!(),
-        StatementKind::StorageLive(_) => todo!(),
-        StatementKind::StorageDead(_) => todo!(),
-        StatementKind::Retag(_, _) => todo!(),
-        StatementKind::PlaceMention(_) => todo!(),
-        StatementKind::AscribeUserType { .. } => todo!(),
-        StatementKind::Coverage(_) => todo!(),
-        StatementKind::Intrinsic(_) => todo!(),
+        StatementKind::FakeRead(cause, place) => {
+            write!(pretty, "        FakeRead({:?}, _{})", cause, place.local).unwrap();
+        }
+        StatementKind::SetDiscriminant { place, variant_index } => {
+            write!(pretty, "        discriminant(_{}) = {}", place.local, variant_index).unwrap();
+        }
+        StatementKind::Deinit(place) => {
+            write!(pretty, "        Deinit(_{})", place.local).unwrap();
+        }
+        StatementKind::StorageLive(local) => {
+            write!(pretty, "        StorageLive(_{})", local).unwrap();
+        }
+        StatementKind::StorageDead(local) => {
+            write!(pretty, "        StorageDead(_{})", local).unwrap();
+        }
+        StatementKind::Retag(kind, place) => {
+            write!(pretty, "        Retag({:?}, _{})", kind, place.local).unwrap();
+        }
+        StatementKind::PlaceMention(place) => {
+            write!(pretty, "        PlaceMention(_{})", place.local).unwrap();
+        }
+        StatementKind::AscribeUserType { place, projections: _, variance } => {
+            write!(pretty, "        AscribeUserType(_{}, {:?})", place.local, variance).unwrap();
+        }
+        StatementKind::Coverage(_) => pretty.push_str("        Coverage"),
+        StatementKind::Intrinsic(NonDivergingIntrinsic::Assume(op)) => {
+            write!(pretty, "        assume({})", pretty_operand(op)).unwrap();
+        }
+        StatementKind::Intrinsic(NonDivergingIntrinsic::CopyNonOverlapping(copy)) => {
+            write!(
+                pretty,
+                "        copy_nonoverlapping({}, {}, {})",
+                pretty_operand(&copy.src),
+                pretty_operand(&copy.dst),
+                pretty_operand(&copy.count)
+            )
+            .unwrap();
+        }
         StatementKind::ConstEvalCounter => (),
         StatementKind::Nop => (),
     }
     pretty
 }
 
+pub fn pretty_terminator(terminator: &TerminatorKind) -> String {
+    let mut pretty = String::new();
+    match terminator {
+        TerminatorKind::Goto { target } => {
+            write!(pretty, "        goto -> bb{}", target).unwrap();
+        }
+        TerminatorKind::SwitchInt { discr, targets, otherwise } => {
+            write!(pretty, "        switchInt({}) -> [", pretty_operand(discr)).unwrap();
+            targets.iter().for_each(|target| {
+                write!(pretty, "{}: bb{}, ", target.value, target.target).unwrap();
+            });
+            write!(pretty, "otherwise: bb{}]", otherwise).unwrap();
+        }
+        TerminatorKind::Resume => pretty.push_str("        resume"),
+        TerminatorKind::Abort => pretty.push_str("        abort"),
+        TerminatorKind::Return => pretty.push_str("        return"),
+        TerminatorKind::Unreachable => pretty.push_str("        unreachable"),
+        TerminatorKind::Drop { place, target, unwind } => {
+            write!(pretty, "        drop(_{}) -> bb{}", place.local, target).unwrap();
+            pretty.push_str(&pretty_unwind(unwind));
+        }
+        TerminatorKind::Call { func, args, destination, target, unwind } => {
+            write!(pretty, "        _{} = ", destination.local).unwrap();
+            pretty.push_str(&pretty_operand(func));
+            pretty.push_str("(");
+            args.iter().enumerate().for_each(|(i, arg)| {
+                pretty.push_str(&pretty_operand(arg));
+                if i != args.len() - 1 {
+                    pretty.push_str(", ");
+                }
+            });
+            pretty.push_str(")");
+            if let Some(target) = target {
+                write!(pretty, " -> bb{}", target).unwrap();
+            }
+            pretty.push_str(&pretty_unwind(unwind));
+        }
+        TerminatorKind::Assert { cond, expected, msg, target, unwind } => {
+            write!(
+                pretty,
+                "        assert({}, {}, {:?}) -> bb{}",
+                pretty_operand(cond),
+                expected,
+                msg,
+                target
+            )
+            .unwrap();
+            pretty.push_str(&pretty_unwind(unwind));
+        }
+        TerminatorKind::CoroutineDrop => pretty.push_str("        coroutine_drop"),
+        TerminatorKind::InlineAsm { template, destination, unwind, .. } => {
+            write!(pretty, "        asm!({:?})", template).unwrap();
+            if let Some(destination) = destination {
+                write!(pretty, " -> bb{}", destination).unwrap();
+            }
+            pretty.push_str(&pretty_unwind(unwind));
+        }
+    }
+    pretty
+}
+
+fn pretty_unwind(unwind: &UnwindAction) -> String {
+    match unwind {
+        UnwindAction::Continue => String::new(),
+        UnwindAction::Unreachable => " unwind unreachable".to_string(),
+        UnwindAction::Terminate => " unwind terminate".to_string(),
+        UnwindAction::Cleanup(bb) => format!(" unwind bb{}", bb),
+    }
+}
+
 pub fn pretty_operand(operand: &Operand) -> String {
     let mut pretty = String::new();
     match operand {
@@ -149,7 +250,13 @@ pub fn pretty_rvalue(rval: &Rvalue) -> String {
             pretty.push_str(" ");
             pretty.push_str(&pretty_ty(cnst.ty().kind()));
         }
-        Rvalue::ShallowInitBox(_, _) => todo!(),
+        Rvalue::ShallowInitBox(op, ty) => {
+            pretty.push_str("ShallowInitBox(");
+            pretty.push_str(&pretty_operand(op));
+            pretty.push_str(", ");
+            pretty.push_str(&pretty_ty(ty.kind()));
+            pretty.push_str(")");
+        }
         Rvalue::ThreadLocalRef(item) => {
             pretty.push_str("thread_local_ref");
             pretty.push_str(format!("{:#?}", item).as_str());
@@ -192,8 +299,10 @@ pub fn pretty_ty(ty: TyKind) -> String {
                 UintTy::U128 => "u128".to_string(),
             },
             RigidTy::Float(f) => match f {
+                FloatTy::F16 => "f16".to_string(),
                 FloatTy::F32 => "f32".to_string(),
                 FloatTy::F64 => "f64".to_string(),
+                FloatTy::F128 => "f128".to_string(),
             },
             RigidTy::Adt(def, _) => {
                 format!("{:#?}", with(|cx| cx.def_ty(def.0)))
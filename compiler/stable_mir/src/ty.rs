@@ -3,6 +3,7 @@
     mir::{Body, Mutability},
     with, DefId, Error, Symbol,
 };
+use crate::abi::Layout;
 use crate::crate_def::CrateDef;
 use crate::mir::alloc::AllocId;
 use crate::{Filename, Opaque};
@@ -36,6 +37,36 @@ impl Ty {
     pub fn kind(&self) -> TyKind {
         with(|context| context.ty_kind(*self))
     }
+
+    /// Get the layout of this type, assuming it is fully monomorphic.
+    pub fn layout(self) -> Result<Layout, Error> {
+        with(|context| context.ty_layout(self))
+    }
+
+    /// Check whether this type implements the given trait under `param_env`.
+    ///
+    /// The `args` are the generic arguments of the trait, not including `Self`.
+    pub fn implements_trait(
+        self,
+        trait_def: TraitDef,
+        args: &GenericArgs,
+        param_env: &ParamEnv,
+    ) -> bool {
+        let trait_ref = TraitRef::new(trait_def, self, args);
+        with(|context| context.implements_trait(&trait_ref, param_env))
+    }
+
+    /// Normalize all projections in this type under `param_env`.
+    ///
+    /// Note that regions are erased in the resulting type.
+    pub fn normalize(self, param_env: &ParamEnv) -> Result<Ty, Error> {
+        with(|context| context.normalize(self, param_env))
+    }
+
+    /// Check whether this type is a subtype of `other` under `param_env`.
+    pub fn is_subtype(self, other: Ty, param_env: &ParamEnv) -> bool {
+        with(|context| context.is_subtype(self, other, param_env))
+    }
 }
 
 /// Represents a constant in MIR or from the Type system.
@@ -286,8 +317,10 @@ pub enum UintTy {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FloatTy {
+    F16,
     F32,
     F64,
+    F128,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -780,6 +813,49 @@ pub struct GenericPredicates {
     pub predicates: Vec<(PredicateKind, Span)>,
 }
 
+/// The where clauses that are assumed to hold when solving trait and type relating queries.
+///
+/// Clauses are stored without their bound variables, thus higher-ranked clauses, such as
+/// `for<'a> F: Fn(&'a u8)`, cannot be represented and are ignored by the compiler.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParamEnv {
+    pub caller_bounds: Vec<ClauseKind>,
+}
+
+impl ParamEnv {
+    /// An environment without any where clause. This is only meaningful for monomorphic code.
+    pub fn empty() -> ParamEnv {
+        ParamEnv::default()
+    }
+
+    /// Build an environment from the clauses of the given predicates.
+    pub fn from_predicates(predicates: &GenericPredicates) -> ParamEnv {
+        let mut param_env = ParamEnv::empty();
+        param_env.extend_with(predicates);
+        param_env
+    }
+
+    /// Build the environment of an item, including the predicates of all its parents.
+    pub fn for_def(def_id: DefId) -> ParamEnv {
+        let mut param_env = ParamEnv::empty();
+        let mut next = Some(def_id);
+        while let Some(def_id) = next {
+            let predicates = with(|cx| cx.predicates_of(def_id));
+            param_env.extend_with(&predicates);
+            next = predicates.parent.map(|parent| parent.0);
+        }
+        param_env
+    }
+
+    fn extend_with(&mut self, predicates: &GenericPredicates) {
+        let clauses = predicates.predicates.iter().filter_map(|(kind, _)| match kind {
+            PredicateKind::Clause(clause) => Some(clause.clone()),
+            _ => None,
+        });
+        self.caller_bounds.extend(clauses);
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PredicateKind {
     Clause(ClauseKind),
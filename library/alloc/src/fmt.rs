@@ -348,6 +348,8 @@
 //! * `b` ⇒ [`Binary`]
 //! * `e` ⇒ [`LowerExp`]
 //! * `E` ⇒ [`UpperExp`]
+//! * `a` ⇒ [`LowerHexFloat`]
+//! * `A` ⇒ [`UpperHexFloat`]
 //!
 //! What this means is that any type of argument which implements the
 //! [`fmt::Binary`][`Binary`] trait can then be formatted with `{:b}`. Implementations
@@ -571,6 +573,8 @@
 pub use core::fmt::{LowerExp, UpperExp};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerHex, Pointer, UpperHex};
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+pub use core::fmt::{LowerHexFloat, UpperHexFloat};
 
 #[cfg(not(no_global_oom_handling))]
 use crate::string;
@@ -233,6 +233,11 @@ fn clone(&self) -> Self {
         bool char
     }
 
+    #[cfg(not(bootstrap))]
+    impl_clone! {
+        f16 f128
+    }
+
     #[unstable(feature = "never_type", issue = "35121")]
     impl Clone for ! {
         #[inline]
@@ -1439,6 +1439,9 @@ fn ne(&self, _other: &()) -> bool {
         bool char usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64
     }
 
+    #[cfg(not(bootstrap))]
+    partial_eq_impl! { f16 f128 }
+
     macro_rules! eq_impl {
         ($($t:ty)*) => ($(
             #[stable(feature = "rust1", since = "1.0.0")]
@@ -1491,6 +1494,9 @@ fn partial_cmp(&self, other: &bool) -> Option<Ordering> {
 
     partial_ord_impl! { f32 f64 }
 
+    #[cfg(not(bootstrap))]
+    partial_ord_impl! { f16 f128 }
+
     macro_rules! ord_impl {
         ($($t:ty)*) => ($(
             #[stable(feature = "rust1", since = "1.0.0")]
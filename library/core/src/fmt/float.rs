@@ -1,6 +1,7 @@
 use crate::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use crate::fmt::{LowerHexFloat, UpperHexFloat};
 use crate::mem::MaybeUninit;
-use crate::num::flt2dec;
+use crate::num::flt2dec::{self, FullDecoded};
 use crate::num::fmt as numfmt;
 
 #[doc(hidden)]
@@ -169,6 +170,93 @@ fn float_to_exponential_common<T>(fmt: &mut Formatter<'_>, num: &T, upper: bool)
     }
 }
 
+// Common code of floating point LowerHexFloat and UpperHexFloat.
+fn float_to_hexadecimal_common<T>(fmt: &mut Formatter<'_>, num: &T, upper: bool) -> Result
+where
+    T: flt2dec::DecodableFloat,
+{
+    let (negative, full_decoded) = flt2dec::decode(*num);
+    let sign = match full_decoded {
+        FullDecoded::Nan => "",
+        _ if negative => "-",
+        _ if fmt.sign_plus() => "+",
+        _ => "",
+    };
+
+    // The value is `integral.frac * 2^exp`, with `frac` holding 16 fractional hexadecimal digits.
+    let (mut integral, mut frac, exp) = match full_decoded {
+        FullDecoded::Nan | FullDecoded::Infinite => {
+            let special: &[u8] = if full_decoded == FullDecoded::Nan { b"NaN" } else { b"inf" };
+            let formatted = numfmt::Formatted { sign, parts: &[numfmt::Part::Copy(special)] };
+            // SAFETY: `special` is ASCII.
+            return unsafe { fmt.pad_formatted_parts(&formatted) };
+        }
+        FullDecoded::Zero => (0, 0, 0),
+        FullDecoded::Finite(ref decoded) => {
+            // Shift the leading one of the mantissa out, leaving the fractional bits behind.
+            let shift = decoded.mant.leading_zeros();
+            (1, decoded.mant << shift << 1, i32::from(decoded.exp) + 63 - shift as i32)
+        }
+    };
+
+    let mut digits = 16 - frac.trailing_zeros() as usize / 4;
+    let mut extra_zeros = 0;
+    if let Some(precision) = fmt.precision {
+        if precision < 16 {
+            // Round the fractional digits to `precision` digits, ties to even.
+            let kept_bits = 4 * precision as u32;
+            let mut kept = if kept_bits == 0 { 0 } else { frac >> (64 - kept_bits) };
+            let rest = frac << kept_bits;
+            let odd = if kept_bits == 0 { integral & 1 == 1 } else { kept & 1 == 1 };
+            if rest > 1 << 63 || (rest == 1 << 63 && odd) {
+                kept += 1;
+                if kept_bits == 0 || kept == 1 << kept_bits {
+                    kept = 0;
+                    integral += 1;
+                }
+            }
+            frac = if kept_bits == 0 { 0 } else { kept << (64 - kept_bits) };
+            digits = precision;
+        } else {
+            digits = 16;
+            extra_zeros = precision - 16;
+        }
+    }
+
+    let hex_digits = if upper { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+    let mut buf = [0; 18];
+    buf[0] = hex_digits[integral];
+    let mut len = 1;
+    if digits > 0 || extra_zeros > 0 {
+        buf[1] = b'.';
+        len = 2;
+        for i in 0..digits {
+            buf[len] = hex_digits[(frac >> (60 - 4 * i)) as usize & 0xf];
+            len += 1;
+        }
+    }
+
+    let mut parts = [
+        numfmt::Part::Copy(b"0x"),
+        numfmt::Part::Zero(0),
+        numfmt::Part::Copy(&buf[..len]),
+        numfmt::Part::Zero(extra_zeros),
+        numfmt::Part::Copy(if upper { b"P" } else { b"p" }),
+        numfmt::Part::Copy(if exp < 0 { b"-" } else { b"" }),
+        numfmt::Part::Num(exp.unsigned_abs() as u16),
+    ];
+    if let Some(width) = fmt.width() {
+        if fmt.sign_aware_zero_pad() {
+            // Like the digits of `{:#010x}`, the zero padding goes after the `0x`.
+            let len = numfmt::Formatted { sign, parts: &parts }.len();
+            parts[1] = numfmt::Part::Zero(width.saturating_sub(len));
+        }
+    }
+    let formatted = numfmt::Formatted { sign, parts: &parts };
+    // SAFETY: All the parts are ASCII.
+    unsafe { fmt.pad_formatted_parts(&formatted) }
+}
+
 fn float_to_general_debug<T>(fmt: &mut Formatter<'_>, num: &T) -> Result
 where
     T: flt2dec::DecodableFloat + GeneralFormat,
@@ -223,6 +311,20 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
                 float_to_exponential_common(fmt, self, true)
             }
         }
+
+        #[unstable(feature = "hex_float_fmt", issue = "none")]
+        impl LowerHexFloat for $ty {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                float_to_hexadecimal_common(fmt, self, false)
+            }
+        }
+
+        #[unstable(feature = "hex_float_fmt", issue = "none")]
+        impl UpperHexFloat for $ty {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                float_to_hexadecimal_common(fmt, self, true)
+            }
+        }
     };
 }
 
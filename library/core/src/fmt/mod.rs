@@ -1071,6 +1071,69 @@ pub trait UpperExp {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result;
 }
 
+/// `a` formatting.
+///
+/// The `LowerHexFloat` trait should format its output as a hexadecimal floating point number,
+/// with lower-case hexadecimal digits and a lower-case `p` before the binary exponent. This is
+/// the same format as C's `%a`, and the output of the floating point types is exact and can be
+/// used as a hexadecimal float literal.
+///
+/// A precision gives the number of hexadecimal digits after the point, rounding the value if
+/// needed. With the `0` flag, zeros are inserted after the `0x` prefix.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(hex_float_fmt)]
+///
+/// let x = 12.0; // 12.0 is 1.5 * 2^3
+///
+/// assert_eq!(format!("{x:a}"), "0x1.8p3");
+/// assert_eq!(format!("{:a}", 0.1f32), "0x1.99999ap-4");
+/// assert_eq!(format!("{:.2a}", 0.1f32), "0x1.9ap-4");
+/// assert_eq!(format!("{:012a}", -1.0), "-0x0000001p0");
+/// ```
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+pub trait LowerHexFloat {
+    /// Formats the value using the given formatter.
+    #[unstable(feature = "hex_float_fmt", issue = "none")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+/// `A` formatting.
+///
+/// The `UpperHexFloat` trait should format its output as a hexadecimal floating point number,
+/// with upper-case hexadecimal digits and an upper-case `P` before the binary exponent. Like
+/// the `#X` format, the `0x` prefix stays lower-case.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(hex_float_fmt)]
+///
+/// let x = 0.1;
+///
+/// assert_eq!(format!("{x:A}"), "0x1.999999999999AP-4");
+/// ```
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+pub trait UpperHexFloat {
+    /// Formats the value using the given formatter.
+    #[unstable(feature = "hex_float_fmt", issue = "none")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
 /// The `write` function takes an output stream, and an `Arguments` struct
 /// that can be precompiled with the `format_args!` macro.
 ///
@@ -2303,6 +2366,31 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result { $tr::fmt(&**self, f) }
 
 fmt_refs! { Debug, Display, Octal, Binary, LowerHex, UpperHex, LowerExp, UpperExp }
 
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+impl<T: ?Sized + LowerHexFloat> LowerHexFloat for &T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerHexFloat::fmt(&**self, f)
+    }
+}
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+impl<T: ?Sized + LowerHexFloat> LowerHexFloat for &mut T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerHexFloat::fmt(&**self, f)
+    }
+}
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+impl<T: ?Sized + UpperHexFloat> UpperHexFloat for &T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperHexFloat::fmt(&**self, f)
+    }
+}
+#[unstable(feature = "hex_float_fmt", issue = "none")]
+impl<T: ?Sized + UpperHexFloat> UpperHexFloat for &mut T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperHexFloat::fmt(&**self, f)
+    }
+}
+
 #[unstable(feature = "never_type", issue = "35121")]
 impl Debug for ! {
     #[inline]
@@ -129,6 +129,14 @@ pub fn new_upper_exp<'b, T: UpperExp>(x: &'b T) -> Argument<'_> {
         Self::new(x, UpperExp::fmt)
     }
     #[inline(always)]
+    pub fn new_lower_hex_float<'b, T: LowerHexFloat>(x: &'b T) -> Argument<'_> {
+        Self::new(x, LowerHexFloat::fmt)
+    }
+    #[inline(always)]
+    pub fn new_upper_hex_float<'b, T: UpperHexFloat>(x: &'b T) -> Argument<'_> {
+        Self::new(x, UpperHexFloat::fmt)
+    }
+    #[inline(always)]
     pub fn from_usize(x: &usize) -> Argument<'_> {
         Self::new(x, USIZE_MARKER)
     }
@@ -273,6 +273,10 @@
 #![feature(tbm_target_feature)]
 #![feature(wasm_target_feature)]
 // tidy-alphabetical-end
+//
+// Features the bootstrap compiler doesn't know yet:
+#![cfg_attr(not(bootstrap), feature(f128))]
+#![cfg_attr(not(bootstrap), feature(f16))]
 
 // allow using `core::` in intra-doc links
 #[allow(unused_extern_crates)]
@@ -332,6 +336,17 @@ pub mod assert_matches {
 #[path = "num/shells/usize.rs"]
 pub mod usize;
 
+#[cfg(not(bootstrap))]
+#[path = "num/float_macros.rs"]
+#[macro_use]
+mod float_macros;
+
+#[cfg(not(bootstrap))]
+#[path = "num/f128.rs"]
+pub mod f128;
+#[cfg(not(bootstrap))]
+#[path = "num/f16.rs"]
+pub mod f16;
 #[path = "num/f32.rs"]
 pub mod f32;
 #[path = "num/f64.rs"]
@@ -1615,6 +1615,23 @@ macro_rules! trace_macros {
         /* compiler built-in */
     }
 
+    /// Attribute macro applied to a function to turn it into a test fixture.
+    ///
+    /// The function is called before each test of the module it's defined in (including
+    /// submodules), and the value it returns is dropped after the test, even if the test
+    /// panicked. `#[test_fixture(global)]` instead calls it once before the first test of the
+    /// binary, and drops the value after the last one.
+    #[unstable(
+        feature = "test_fixture",
+        issue = "none",
+        reason = "`test_fixture` is an experimental extension of the test harness"
+    )]
+    #[allow_internal_unstable(test, rustc_attrs)]
+    #[rustc_builtin_macro]
+    pub macro test_fixture($item:item) {
+        /* compiler built-in */
+    }
+
     /// An implementation detail of the `#[test]` and `#[bench]` macros.
     #[unstable(
         feature = "custom_test_frameworks",
@@ -0,0 +1,159 @@
+//! Parsing of hexadecimal floats, like `0x1.8p3`.
+//!
+//! Unlike decimal floats, these can be converted exactly: every hexadecimal digit
+//! is four bits of the significand, so the only rounding happens once, when the
+//! significand is cut down to the precision of the target type.
+
+use crate::num::dec2flt::float::RawFloat;
+use crate::num::dec2flt::parse::parse_inf_nan;
+use crate::num::dec2flt::{pfe_empty, pfe_invalid, ParseFloatError};
+
+/// Exponents beyond this are clamped, as they overflow or underflow either way.
+const EXPONENT_LIMIT: i64 = 1 << 20;
+
+/// Converts a hexadecimal string into a floating point number.
+pub fn hex2flt<F: RawFloat>(s: &str) -> Result<F, ParseFloatError> {
+    let mut s = s.as_bytes();
+    let c = if let Some(&c) = s.first() {
+        c
+    } else {
+        return Err(pfe_empty());
+    };
+    let negative = c == b'-';
+    if c == b'-' || c == b'+' {
+        s = &s[1..];
+    }
+    if s.is_empty() {
+        return Err(pfe_invalid());
+    }
+
+    let prefixed = s.starts_with(b"0x") || s.starts_with(b"0X");
+    let Some((mantissa, sticky, exponent)) = parse_hex_number(if prefixed { &s[2..] } else { s })
+    else {
+        return match parse_inf_nan(s, negative) {
+            Some(value) if !prefixed => Ok(value),
+            _ => Err(pfe_invalid()),
+        };
+    };
+
+    let float = F::from_u64_bits(round_to_bits::<F>(mantissa, sticky, exponent));
+    Ok(if negative { -float } else { float })
+}
+
+/// Parses the digits and the binary exponent of a hexadecimal float.
+///
+/// Returns the first 16 significant digits, whether any of the dropped digits
+/// was nonzero, and the exponent, such that the value is `mantissa * 2^exponent`
+/// (plus a bit more if the dropped digits weren't all zero).
+fn parse_hex_number(mut s: &[u8]) -> Option<(u64, bool, i64)> {
+    let mut mantissa = 0u64;
+    let mut sticky = false;
+    let mut exponent = 0i64;
+    let mut any_digits = false;
+
+    let mut push_digit = |digit: u64, fractional: bool| {
+        if mantissa >> 60 == 0 {
+            mantissa = mantissa << 4 | digit;
+            if fractional {
+                exponent -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !fractional {
+                exponent += 4;
+            }
+        }
+    };
+
+    while let Some((digit, rest)) = split_hex_digit(s) {
+        push_digit(digit, false);
+        any_digits = true;
+        s = rest;
+    }
+    if let [b'.', rest @ ..] = s {
+        s = rest;
+        while let Some((digit, rest)) = split_hex_digit(s) {
+            push_digit(digit, true);
+            any_digits = true;
+            s = rest;
+        }
+    }
+    if !any_digits {
+        return None;
+    }
+
+    if let [b'p' | b'P', rest @ ..] = s {
+        let (negative, mut rest) = match rest {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            _ => (false, rest),
+        };
+        if rest.is_empty() {
+            return None;
+        }
+        let mut value = 0i64;
+        while let [c @ b'0'..=b'9', tail @ ..] = rest {
+            value = (value * 10 + i64::from(c - b'0')).min(EXPONENT_LIMIT);
+            rest = tail;
+        }
+        exponent += if negative { -value } else { value };
+        s = rest;
+    }
+
+    if s.is_empty() { Some((mantissa, sticky, exponent)) } else { None }
+}
+
+fn split_hex_digit(s: &[u8]) -> Option<(u64, &[u8])> {
+    let (&c, rest) = s.split_first()?;
+    let digit = (c as char).to_digit(16)?;
+    Some((u64::from(digit), rest))
+}
+
+/// Rounds `mantissa * 2^exponent` to the nearest float, ties to even, and returns
+/// its bits without the sign.
+fn round_to_bits<F: RawFloat>(mantissa: u64, sticky: bool, exponent: i64) -> u64 {
+    if mantissa == 0 {
+        return 0;
+    }
+    let explicit_bits = F::MANTISSA_EXPLICIT_BITS as i64;
+    let bias = -i64::from(F::MINIMUM_EXPONENT);
+    let infinity = (2 * bias + 1) << explicit_bits;
+
+    // The value is `1.xxx * 2^exponent` with the leading one at bit 63 of `mantissa`.
+    let shift = mantissa.leading_zeros();
+    let mantissa = mantissa << shift;
+    let exponent = exponent + 63 - i64::from(shift);
+    if exponent > bias {
+        return infinity as u64;
+    }
+
+    // Subnormals have fewer significant bits, and values below half of the
+    // smallest subnormal round to zero.
+    let kept_bits = explicit_bits + 1 - (1 - bias - exponent).max(0);
+    if kept_bits < 0 {
+        return 0;
+    }
+    let dropped_bits = 64 - kept_bits as u32;
+    let mut kept = (u128::from(mantissa) >> dropped_bits) as u64;
+    let rest = u128::from(mantissa) & ((1 << dropped_bits) - 1);
+    let half = 1 << (dropped_bits - 1);
+    if rest > half || (rest == half && (sticky || kept & 1 == 1)) {
+        kept += 1;
+    }
+
+    if exponent < 1 - bias {
+        // A subnormal, which becomes the smallest normal value if rounding carried
+        // into the exponent field.
+        kept
+    } else {
+        let (kept, exponent) = if kept >> (explicit_bits + 1) != 0 {
+            (kept >> 1, exponent + 1)
+        } else {
+            (kept, exponent)
+        };
+        if exponent > bias {
+            return infinity as u64;
+        }
+        ((exponent + bias) << explicit_bits) as u64 | (kept & ((1 << explicit_bits) - 1))
+    }
+}
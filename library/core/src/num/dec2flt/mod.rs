@@ -85,9 +85,12 @@
 use self::parse::{parse_inf_nan, parse_number};
 use self::slow::parse_long_mantissa;
 
+pub(super) use self::hex::hex2flt;
+
 mod common;
 mod decimal;
 mod fpu;
+mod hex;
 mod slow;
 mod table;
 // float is used in flt2dec, and all are used in unit tests.
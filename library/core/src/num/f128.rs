@@ -0,0 +1,84 @@
+//! Constants for the `f128` quadruple-precision floating point type.
+//!
+//! *[See also the `f128` primitive type][f128].*
+//!
+//! Unlike the `f32` and `f64` modules, this one only holds the mathematical
+//! constants; everything else is an associated item on `f128` itself.
+
+#![unstable(feature = "f128", issue = "none")]
+
+/// Basic mathematical constants.
+#[unstable(feature = "f128", issue = "none")]
+pub mod consts {
+    // FIXME: replace with mathematical constants from cmath.
+
+    /// Archimedes' constant (π)
+    #[unstable(feature = "f128", issue = "none")]
+    pub const PI: f128 = 3.14159265358979323846264338327950288_f128;
+
+    /// The full circle constant (τ)
+    ///
+    /// Equal to 2π.
+    #[unstable(feature = "f128", issue = "none")]
+    pub const TAU: f128 = 6.28318530717958647692528676655900577_f128;
+
+    /// π/2
+    #[unstable(feature = "f128", issue = "none")]
+    pub const FRAC_PI_2: f128 = 1.57079632679489661923132169163975144_f128;
+
+    /// π/4
+    #[unstable(feature = "f128", issue = "none")]
+    pub const FRAC_PI_4: f128 = 0.785398163397448309615660845819875721_f128;
+
+    /// 1/π
+    #[unstable(feature = "f128", issue = "none")]
+    pub const FRAC_1_PI: f128 = 0.318309886183790671537767526745028724_f128;
+
+    /// sqrt(2)
+    #[unstable(feature = "f128", issue = "none")]
+    pub const SQRT_2: f128 = 1.41421356237309504880168872420969808_f128;
+
+    /// Euler's number (e)
+    #[unstable(feature = "f128", issue = "none")]
+    pub const E: f128 = 2.71828182845904523536028747135266250_f128;
+
+    /// ln(2)
+    #[unstable(feature = "f128", issue = "none")]
+    pub const LN_2: f128 = 0.693147180559945309417232121458176568_f128;
+
+    /// ln(10)
+    #[unstable(feature = "f128", issue = "none")]
+    pub const LN_10: f128 = 2.30258509299404568401799145468436421_f128;
+}
+
+#[cfg(not(test))]
+impl f128 {
+    float_impl! {
+        Self = f128,
+        Bits = u128,
+        SignedBits = i128,
+        feature = "f128",
+        BYTES = 16,
+        BITS_MINUS_ONE = 127,
+
+        MANTISSA_DIGITS = 113,
+        DIGITS = 33,
+        EPSILON = 1.92592994438723585305597794258492732e-34_f128,
+        MIN = -1.18973149535723176508575932662800701e+4932_f128,
+        MIN_POSITIVE = 3.36210314311209350626267781732175260e-4932_f128,
+        MAX = 1.18973149535723176508575932662800701e+4932_f128,
+        MIN_EXP = -16381,
+        MAX_EXP = 16384,
+        MIN_10_EXP = -4931,
+        MAX_10_EXP = 4932,
+
+        SIGN_MASK = 0x8000_0000_0000_0000_0000_0000_0000_0000,
+        EXP_MASK = 0x7fff_0000_0000_0000_0000_0000_0000_0000,
+        MAN_MASK = 0x0000_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+
+        subnormal = "1.0e-4940",
+        bits = "0x4002_9000_0000_0000_0000_0000_0000_0000",
+        be_bytes = "[0x40, 0x02, 0x90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]",
+        le_bytes = "[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x90, 0x02, 0x40]",
+    }
+}
@@ -0,0 +1,84 @@
+//! Constants for the `f16` half-precision floating point type.
+//!
+//! *[See also the `f16` primitive type][f16].*
+//!
+//! Unlike the `f32` and `f64` modules, this one only holds the mathematical
+//! constants; everything else is an associated item on `f16` itself.
+
+#![unstable(feature = "f16", issue = "none")]
+
+/// Basic mathematical constants.
+#[unstable(feature = "f16", issue = "none")]
+pub mod consts {
+    // FIXME: replace with mathematical constants from cmath.
+
+    /// Archimedes' constant (π)
+    #[unstable(feature = "f16", issue = "none")]
+    pub const PI: f16 = 3.14159265358979323846264338327950288_f16;
+
+    /// The full circle constant (τ)
+    ///
+    /// Equal to 2π.
+    #[unstable(feature = "f16", issue = "none")]
+    pub const TAU: f16 = 6.28318530717958647692528676655900577_f16;
+
+    /// π/2
+    #[unstable(feature = "f16", issue = "none")]
+    pub const FRAC_PI_2: f16 = 1.57079632679489661923132169163975144_f16;
+
+    /// π/4
+    #[unstable(feature = "f16", issue = "none")]
+    pub const FRAC_PI_4: f16 = 0.785398163397448309615660845819875721_f16;
+
+    /// 1/π
+    #[unstable(feature = "f16", issue = "none")]
+    pub const FRAC_1_PI: f16 = 0.318309886183790671537767526745028724_f16;
+
+    /// sqrt(2)
+    #[unstable(feature = "f16", issue = "none")]
+    pub const SQRT_2: f16 = 1.41421356237309504880168872420969808_f16;
+
+    /// Euler's number (e)
+    #[unstable(feature = "f16", issue = "none")]
+    pub const E: f16 = 2.71828182845904523536028747135266250_f16;
+
+    /// ln(2)
+    #[unstable(feature = "f16", issue = "none")]
+    pub const LN_2: f16 = 0.693147180559945309417232121458176568_f16;
+
+    /// ln(10)
+    #[unstable(feature = "f16", issue = "none")]
+    pub const LN_10: f16 = 2.30258509299404568401799145468436421_f16;
+}
+
+#[cfg(not(test))]
+impl f16 {
+    float_impl! {
+        Self = f16,
+        Bits = u16,
+        SignedBits = i16,
+        feature = "f16",
+        BYTES = 2,
+        BITS_MINUS_ONE = 15,
+
+        MANTISSA_DIGITS = 11,
+        DIGITS = 3,
+        EPSILON = 9.7656e-4_f16,
+        MIN = -6.5504e+4_f16,
+        MIN_POSITIVE = 6.1035e-5_f16,
+        MAX = 6.5504e+4_f16,
+        MIN_EXP = -13,
+        MAX_EXP = 16,
+        MIN_10_EXP = -4,
+        MAX_10_EXP = 4,
+
+        SIGN_MASK = 0x8000,
+        EXP_MASK = 0x7c00,
+        MAN_MASK = 0x03ff,
+
+        subnormal = "1.0e-5",
+        bits = "0x4a40",
+        be_bytes = "[0x4a, 0x40]",
+        le_bytes = "[0x40, 0x4a]",
+    }
+}
@@ -15,6 +15,7 @@
 #[cfg(not(test))]
 use crate::intrinsics;
 use crate::mem;
+use crate::num::dec2flt::{self, ParseFloatError};
 use crate::num::FpCategory;
 
 /// The radix or base of the internal representation of `f32`.
@@ -1233,6 +1234,34 @@ fn rt_u32_to_f32(x: u32) -> f32 {
         unsafe { intrinsics::const_eval_select((v,), ct_u32_to_f32, rt_u32_to_f32) }
     }
 
+    /// Parses a hexadecimal float, like `0x1.8p3`.
+    ///
+    /// The string is an optional sign, an optional `0x` or `0X` prefix, hexadecimal
+    /// digits with an optional point, and an optional binary exponent: a `p` or `P`
+    /// followed by a decimal exponent of two. Without a prefix, `inf`, `infinity`
+    /// and `nan` are accepted as well, like in the [`FromStr`](crate::str::FromStr)
+    /// implementation. This is the format produced by the
+    /// [`LowerHexFloat`](crate::fmt::LowerHexFloat) and
+    /// [`UpperHexFloat`](crate::fmt::UpperHexFloat) implementations.
+    ///
+    /// The result is rounded to the nearest representable value, ties to even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(float_from_str_hex)]
+    ///
+    /// assert_eq!(f32::from_str_hex("0x1.8p3"), Ok(12.0));
+    /// assert_eq!(f32::from_str_hex("-1.99999ap-4"), Ok(-0.1));
+    /// assert!(f32::from_str_hex("0x1.8e3").is_ok());
+    /// assert!(f32::from_str_hex("1.8e3p").is_err());
+    /// ```
+    #[unstable(feature = "float_from_str_hex", issue = "none")]
+    #[inline(never)]
+    pub fn from_str_hex(src: &str) -> Result<f32, ParseFloatError> {
+        dec2flt::hex2flt(src)
+    }
+
     /// Return the memory representation of this floating point number as a byte array in
     /// big-endian (network) byte order.
     ///
@@ -0,0 +1,536 @@
+//! The associated items shared by the `f16` and `f128` primitive types.
+//!
+//! Not every target can do arithmetic on these types natively, so the methods
+//! here work on the bit representation wherever they can.
+
+macro_rules! float_impl {
+    (
+        Self = $SelfT:ident,
+        Bits = $BitsT:ident,
+        SignedBits = $SignedBitsT:ident,
+        feature = $feature:tt,
+        BYTES = $BYTES:literal,
+        BITS_MINUS_ONE = $BITS_MINUS_ONE:literal,
+
+        MANTISSA_DIGITS = $MANTISSA_DIGITS:literal,
+        DIGITS = $DIGITS:literal,
+        EPSILON = $EPSILON:literal,
+        MIN = $MIN:literal,
+        MIN_POSITIVE = $MIN_POSITIVE:literal,
+        MAX = $MAX:literal,
+        MIN_EXP = $MIN_EXP:literal,
+        MAX_EXP = $MAX_EXP:literal,
+        MIN_10_EXP = $MIN_10_EXP:literal,
+        MAX_10_EXP = $MAX_10_EXP:literal,
+
+        SIGN_MASK = $SIGN_MASK:literal,
+        EXP_MASK = $EXP_MASK:literal,
+        MAN_MASK = $MAN_MASK:literal,
+
+        // These are all for use *only* in doc comments.
+        // As such, they're all passed as literals -- passing them as a string
+        // literal is fine if they need to be multiple code tokens.
+        subnormal = $subnormal:literal,
+        bits = $bits:literal,
+        be_bytes = $be_bytes:literal,
+        le_bytes = $le_bytes:literal,
+    ) => {
+        #[doc = concat!("The radix or base of the internal representation of `", stringify!($SelfT), "`.")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const RADIX: u32 = 2;
+
+        /// Number of significant digits in base 2.
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MANTISSA_DIGITS: u32 = $MANTISSA_DIGITS;
+
+        /// Approximate number of significant digits in base 10.
+        ///
+        /// This is the maximum <i>x</i> such that any decimal number with <i>x</i>
+        #[doc = concat!("significant digits can be converted to `", stringify!($SelfT), "` and back without loss.")]
+        ///
+        /// Equal to floor(log<sub>10</sub>&nbsp;2<sup>[`MANTISSA_DIGITS`]&nbsp;&minus;&nbsp;1</sup>).
+        ///
+        #[doc = concat!("[`MANTISSA_DIGITS`]: ", stringify!($SelfT), "::MANTISSA_DIGITS")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const DIGITS: u32 = $DIGITS;
+
+        #[doc = concat!("[Machine epsilon] value for `", stringify!($SelfT), "`.")]
+        ///
+        /// This is the difference between `1.0` and the next larger representable number.
+        ///
+        /// Equal to 2<sup>1&nbsp;&minus;&nbsp;[`MANTISSA_DIGITS`]</sup>.
+        ///
+        /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
+        #[doc = concat!("[`MANTISSA_DIGITS`]: ", stringify!($SelfT), "::MANTISSA_DIGITS")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const EPSILON: $SelfT = $EPSILON;
+
+        #[doc = concat!("Smallest finite `", stringify!($SelfT), "` value.")]
+        ///
+        /// Equal to &minus;[`MAX`].
+        ///
+        #[doc = concat!("[`MAX`]: ", stringify!($SelfT), "::MAX")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MIN: $SelfT = $MIN;
+        #[doc = concat!("Smallest positive normal `", stringify!($SelfT), "` value.")]
+        ///
+        /// Equal to 2<sup>[`MIN_EXP`]&nbsp;&minus;&nbsp;1</sup>.
+        ///
+        #[doc = concat!("[`MIN_EXP`]: ", stringify!($SelfT), "::MIN_EXP")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MIN_POSITIVE: $SelfT = $MIN_POSITIVE;
+        #[doc = concat!("Largest finite `", stringify!($SelfT), "` value.")]
+        ///
+        /// Equal to
+        /// (1&nbsp;&minus;&nbsp;2<sup>&minus;[`MANTISSA_DIGITS`]</sup>)&nbsp;2<sup>[`MAX_EXP`]</sup>.
+        ///
+        #[doc = concat!("[`MANTISSA_DIGITS`]: ", stringify!($SelfT), "::MANTISSA_DIGITS")]
+        #[doc = concat!("[`MAX_EXP`]: ", stringify!($SelfT), "::MAX_EXP")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MAX: $SelfT = $MAX;
+
+        /// One greater than the minimum possible normal power of 2 exponent.
+        ///
+        /// If <i>x</i>&nbsp;=&nbsp;`MIN_EXP`, then normal numbers
+        /// ≥&nbsp;0.5&nbsp;×&nbsp;2<sup><i>x</i></sup>.
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MIN_EXP: i32 = $MIN_EXP;
+        /// Maximum possible power of 2 exponent.
+        ///
+        /// If <i>x</i>&nbsp;=&nbsp;`MAX_EXP`, then normal numbers
+        /// &lt;&nbsp;1&nbsp;×&nbsp;2<sup><i>x</i></sup>.
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MAX_EXP: i32 = $MAX_EXP;
+
+        /// Minimum <i>x</i> for which 10<sup><i>x</i></sup> is normal.
+        ///
+        /// Equal to ceil(log<sub>10</sub>&nbsp;[`MIN_POSITIVE`]).
+        ///
+        #[doc = concat!("[`MIN_POSITIVE`]: ", stringify!($SelfT), "::MIN_POSITIVE")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MIN_10_EXP: i32 = $MIN_10_EXP;
+        /// Maximum <i>x</i> for which 10<sup><i>x</i></sup> is normal.
+        ///
+        /// Equal to floor(log<sub>10</sub>&nbsp;[`MAX`]).
+        ///
+        #[doc = concat!("[`MAX`]: ", stringify!($SelfT), "::MAX")]
+        #[unstable(feature = $feature, issue = "none")]
+        pub const MAX_10_EXP: i32 = $MAX_10_EXP;
+
+        /// Not a Number (NaN).
+        ///
+        /// Note that IEEE 754 doesn't define just a single NaN value;
+        /// a plethora of bit patterns are considered to be NaN.
+        /// This constant isn't guaranteed to equal to any specific NaN bitpattern,
+        /// and the stability of its representation over Rust versions
+        /// and target platforms isn't guaranteed.
+        #[unstable(feature = $feature, issue = "none")]
+        pub const NAN: $SelfT = 0.0 / 0.0;
+        /// Infinity (∞).
+        #[unstable(feature = $feature, issue = "none")]
+        pub const INFINITY: $SelfT = 1.0 / 0.0;
+        /// Negative infinity (−∞).
+        #[unstable(feature = $feature, issue = "none")]
+        pub const NEG_INFINITY: $SelfT = -1.0 / 0.0;
+
+        /// Sign bit
+        const SIGN_MASK: $BitsT = $SIGN_MASK;
+
+        /// Exponent mask
+        const EXP_MASK: $BitsT = $EXP_MASK;
+
+        /// Mantissa mask
+        const MAN_MASK: $BitsT = $MAN_MASK;
+
+        /// Returns `true` if this value is NaN.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let nan = ", stringify!($SelfT), "::NAN;")]
+        #[doc = concat!("let f = 7.0_", stringify!($SelfT), ";")]
+        ///
+        /// assert!(nan.is_nan());
+        /// assert!(!f.is_nan());
+        /// ```
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_nan(self) -> bool {
+            self.to_bits() & !Self::SIGN_MASK > Self::EXP_MASK
+        }
+
+        /// Returns `true` if this value is positive infinity or negative infinity, and
+        /// `false` otherwise.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let f = 7.0_", stringify!($SelfT), ";")]
+        #[doc = concat!("let inf = ", stringify!($SelfT), "::INFINITY;")]
+        #[doc = concat!("let neg_inf = ", stringify!($SelfT), "::NEG_INFINITY;")]
+        #[doc = concat!("let nan = ", stringify!($SelfT), "::NAN;")]
+        ///
+        /// assert!(!f.is_infinite());
+        /// assert!(!nan.is_infinite());
+        ///
+        /// assert!(inf.is_infinite());
+        /// assert!(neg_inf.is_infinite());
+        /// ```
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_infinite(self) -> bool {
+            self.to_bits() & !Self::SIGN_MASK == Self::EXP_MASK
+        }
+
+        /// Returns `true` if this number is neither infinite nor NaN.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let f = 7.0_", stringify!($SelfT), ";")]
+        #[doc = concat!("let inf = ", stringify!($SelfT), "::INFINITY;")]
+        #[doc = concat!("let neg_inf = ", stringify!($SelfT), "::NEG_INFINITY;")]
+        #[doc = concat!("let nan = ", stringify!($SelfT), "::NAN;")]
+        ///
+        /// assert!(f.is_finite());
+        ///
+        /// assert!(!nan.is_finite());
+        /// assert!(!inf.is_finite());
+        /// assert!(!neg_inf.is_finite());
+        /// ```
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_finite(self) -> bool {
+            self.to_bits() & Self::EXP_MASK != Self::EXP_MASK
+        }
+
+        /// Returns `true` if the number is [subnormal].
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let min = ", stringify!($SelfT), "::MIN_POSITIVE;")]
+        #[doc = concat!("let lower_than_min = ", $subnormal, "_", stringify!($SelfT), ";")]
+        ///
+        /// assert!(!min.is_subnormal());
+        #[doc = concat!("assert!(!0.0_", stringify!($SelfT), ".is_subnormal());")]
+        #[doc = concat!("assert!(!", stringify!($SelfT), "::NAN.is_subnormal());")]
+        /// // Values between `0` and `min` are Subnormal.
+        /// assert!(lower_than_min.is_subnormal());
+        /// ```
+        /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_subnormal(self) -> bool {
+            matches!(self.classify(), crate::num::FpCategory::Subnormal)
+        }
+
+        /// Returns `true` if the number is neither zero, infinite,
+        /// [subnormal], or NaN.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let min = ", stringify!($SelfT), "::MIN_POSITIVE;")]
+        #[doc = concat!("let lower_than_min = ", $subnormal, "_", stringify!($SelfT), ";")]
+        ///
+        /// assert!(min.is_normal());
+        #[doc = concat!("assert!(", stringify!($SelfT), "::MAX.is_normal());")]
+        #[doc = concat!("assert!(!0.0_", stringify!($SelfT), ".is_normal());")]
+        #[doc = concat!("assert!(!", stringify!($SelfT), "::INFINITY.is_normal());")]
+        /// assert!(!lower_than_min.is_normal());
+        /// ```
+        /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_normal(self) -> bool {
+            matches!(self.classify(), crate::num::FpCategory::Normal)
+        }
+
+        /// Returns the floating point category of the number. If only one property
+        /// is going to be tested, it is generally faster to use the specific
+        /// predicate instead.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        /// use std::num::FpCategory;
+        ///
+        #[doc = concat!("let num = 12.4_", stringify!($SelfT), ";")]
+        #[doc = concat!("let inf = ", stringify!($SelfT), "::INFINITY;")]
+        ///
+        /// assert_eq!(num.classify(), FpCategory::Normal);
+        /// assert_eq!(inf.classify(), FpCategory::Infinite);
+        /// ```
+        #[unstable(feature = $feature, issue = "none")]
+        pub const fn classify(self) -> crate::num::FpCategory {
+            let b = self.to_bits();
+            match (b & Self::MAN_MASK, b & Self::EXP_MASK) {
+                (0, Self::EXP_MASK) => crate::num::FpCategory::Infinite,
+                (_, Self::EXP_MASK) => crate::num::FpCategory::Nan,
+                (0, 0) => crate::num::FpCategory::Zero,
+                (_, 0) => crate::num::FpCategory::Subnormal,
+                _ => crate::num::FpCategory::Normal,
+            }
+        }
+
+        /// Returns `true` if `self` has a positive sign, including `+0.0`, NaNs with
+        /// positive sign bit and positive infinity.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let f = 7.0_", stringify!($SelfT), ";")]
+        #[doc = concat!("let g = -7.0_", stringify!($SelfT), ";")]
+        ///
+        /// assert!(f.is_sign_positive());
+        /// assert!(!g.is_sign_positive());
+        /// ```
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_sign_positive(self) -> bool {
+            !self.is_sign_negative()
+        }
+
+        /// Returns `true` if `self` has a negative sign, including `-0.0`, NaNs with
+        /// negative sign bit and negative infinity.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let f = 7.0_", stringify!($SelfT), ";")]
+        #[doc = concat!("let g = -7.0_", stringify!($SelfT), ";")]
+        ///
+        /// assert!(!f.is_sign_negative());
+        /// assert!(g.is_sign_negative());
+        /// ```
+        #[must_use]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn is_sign_negative(self) -> bool {
+            self.to_bits() & Self::SIGN_MASK != 0
+        }
+
+        /// Computes the absolute value of `self`.
+        ///
+        /// This only clears the sign bit, so it is exact and never changes a NaN's payload.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("assert_eq!((-3.5_", stringify!($SelfT), ").abs().to_bits(), 3.5_", stringify!($SelfT), ".to_bits());")]
+        #[doc = concat!("assert!(", stringify!($SelfT), "::NAN.abs().is_nan());")]
+        /// ```
+        #[must_use = "method returns a new number and does not mutate the original value"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn abs(self) -> $SelfT {
+            <$SelfT>::from_bits(self.to_bits() & !Self::SIGN_MASK)
+        }
+
+        /// Returns a number composed of the magnitude of `self` and the sign of
+        /// `sign`.
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let f = 3.5_", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("assert_eq!(f.copysign(0.42).to_bits(), 3.5_", stringify!($SelfT), ".to_bits());")]
+        #[doc = concat!("assert_eq!(f.copysign(-0.42).to_bits(), (-3.5_", stringify!($SelfT), ").to_bits());")]
+        /// ```
+        #[must_use = "method returns a new number and does not mutate the original value"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn copysign(self, sign: $SelfT) -> $SelfT {
+            let sign = sign.to_bits() & Self::SIGN_MASK;
+            <$SelfT>::from_bits(self.to_bits() & !Self::SIGN_MASK | sign)
+        }
+
+        #[doc = concat!("Raw transmutation to `", stringify!($BitsT), "`.")]
+        ///
+        #[doc = concat!("This is currently identical to `transmute::<", stringify!($SelfT), ", ", stringify!($BitsT), ">(self)` on all platforms.")]
+        ///
+        /// Note that this function is distinct from `as` casting, which attempts to
+        /// preserve the *numeric* value, and not the bitwise value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("assert_eq!(12.5_", stringify!($SelfT), ".to_bits(), ", $bits, ");")]
+        /// ```
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn to_bits(self) -> $BitsT {
+            // SAFETY: the integer is a plain old datatype so we can always transmute to it.
+            unsafe { crate::mem::transmute::<$SelfT, $BitsT>(self) }
+        }
+
+        #[doc = concat!("Raw transmutation from `", stringify!($BitsT), "`.")]
+        ///
+        #[doc = concat!("This is currently identical to `transmute::<", stringify!($BitsT), ", ", stringify!($SelfT), ">(v)` on all platforms.")]
+        /// See [`f32::from_bits`] for why this is portable.
+        ///
+        /// Note that this function is distinct from `as` casting, which attempts to
+        /// preserve the *numeric* value, and not the bitwise value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let v = ", stringify!($SelfT), "::from_bits(", $bits, ");")]
+        #[doc = concat!("assert_eq!(v.to_bits(), 12.5_", stringify!($SelfT), ".to_bits());")]
+        /// ```
+        #[unstable(feature = $feature, issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_bits(v: $BitsT) -> Self {
+            // SAFETY: the integer is a plain old datatype so we can always transmute from it.
+            unsafe { crate::mem::transmute::<$BitsT, $SelfT>(v) }
+        }
+
+        /// Return the memory representation of this floating point number as a byte array in
+        /// big-endian (network) byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let bytes = 12.5_", stringify!($SelfT), ".to_be_bytes();")]
+        #[doc = concat!("assert_eq!(bytes, ", $be_bytes, ");")]
+        /// ```
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn to_be_bytes(self) -> [u8; $BYTES] {
+            self.to_bits().to_be_bytes()
+        }
+
+        /// Return the memory representation of this floating point number as a byte array in
+        /// little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let bytes = 12.5_", stringify!($SelfT), ".to_le_bytes();")]
+        #[doc = concat!("assert_eq!(bytes, ", $le_bytes, ");")]
+        /// ```
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn to_le_bytes(self) -> [u8; $BYTES] {
+            self.to_bits().to_le_bytes()
+        }
+
+        /// Return the memory representation of this floating point number as a byte array in
+        /// native byte order.
+        ///
+        /// As the target platform's native endianness is used, portable code
+        /// should use [`to_be_bytes`] or [`to_le_bytes`], as appropriate, instead.
+        ///
+        #[doc = concat!("[`to_be_bytes`]: ", stringify!($SelfT), "::to_be_bytes")]
+        #[doc = concat!("[`to_le_bytes`]: ", stringify!($SelfT), "::to_le_bytes")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[unstable(feature = $feature, issue = "none")]
+        #[inline]
+        pub const fn to_ne_bytes(self) -> [u8; $BYTES] {
+            self.to_bits().to_ne_bytes()
+        }
+
+        /// Create a floating point value from its representation as a byte array in big endian.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let value = ", stringify!($SelfT), "::from_be_bytes(", $be_bytes, ");")]
+        #[doc = concat!("assert_eq!(value.to_bits(), 12.5_", stringify!($SelfT), ".to_bits());")]
+        /// ```
+        #[unstable(feature = $feature, issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_be_bytes(bytes: [u8; $BYTES]) -> Self {
+            Self::from_bits($BitsT::from_be_bytes(bytes))
+        }
+
+        /// Create a floating point value from its representation as a byte array in little endian.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        #[doc = concat!("let value = ", stringify!($SelfT), "::from_le_bytes(", $le_bytes, ");")]
+        #[doc = concat!("assert_eq!(value.to_bits(), 12.5_", stringify!($SelfT), ".to_bits());")]
+        /// ```
+        #[unstable(feature = $feature, issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_le_bytes(bytes: [u8; $BYTES]) -> Self {
+            Self::from_bits($BitsT::from_le_bytes(bytes))
+        }
+
+        /// Create a floating point value from its representation as a byte array in native endian.
+        ///
+        /// As the target platform's native endianness is used, portable code
+        /// likely wants to use [`from_be_bytes`] or [`from_le_bytes`], as
+        /// appropriate instead.
+        ///
+        #[doc = concat!("[`from_be_bytes`]: ", stringify!($SelfT), "::from_be_bytes")]
+        #[doc = concat!("[`from_le_bytes`]: ", stringify!($SelfT), "::from_le_bytes")]
+        #[unstable(feature = $feature, issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_ne_bytes(bytes: [u8; $BYTES]) -> Self {
+            Self::from_bits($BitsT::from_ne_bytes(bytes))
+        }
+
+        /// Return the ordering between `self` and `other`.
+        ///
+        /// This follows the `totalOrder` predicate of IEEE 754, like [`f32::total_cmp`].
+        ///
+        /// # Example
+        ///
+        /// ```
+        #[doc = concat!("#![feature(", $feature, ")]")]
+        ///
+        /// use std::cmp::Ordering;
+        ///
+        #[doc = concat!("assert_eq!((-0.0_", stringify!($SelfT), ").total_cmp(&0.0), Ordering::Less);")]
+        #[doc = concat!("assert_eq!(1.0_", stringify!($SelfT), ".total_cmp(&", stringify!($SelfT), "::INFINITY), Ordering::Less);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::NAN.abs().total_cmp(&", stringify!($SelfT), "::INFINITY), Ordering::Greater);")]
+        /// ```
+        #[unstable(feature = $feature, issue = "none")]
+        #[must_use]
+        #[inline]
+        pub fn total_cmp(&self, other: &Self) -> crate::cmp::Ordering {
+            let mut left = self.to_bits() as $SignedBitsT;
+            let mut right = other.to_bits() as $SignedBitsT;
+
+            // Flip everything but the sign of negative numbers, to order them
+            // like two's complement integers. See `f32::total_cmp` for details.
+            left ^= (((left >> $BITS_MINUS_ONE) as $BitsT) >> 1) as $SignedBitsT;
+            right ^= (((right >> $BITS_MINUS_ONE) as $BitsT) >> 1) as $SignedBitsT;
+
+            left.cmp(&right)
+        }
+    };
+}
@@ -107,6 +107,8 @@ fn add(self, other: $t) -> $t { self + other }
 }
 
 add_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+add_impl! { f16 f128 }
 
 /// The subtraction operator `-`.
 ///
@@ -215,6 +217,8 @@ fn sub(self, other: $t) -> $t { self - other }
 }
 
 sub_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+sub_impl! { f16 f128 }
 
 /// The multiplication operator `*`.
 ///
@@ -344,6 +348,8 @@ fn mul(self, other: $t) -> $t { self * other }
 }
 
 mul_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+mul_impl! { f16 f128 }
 
 /// The division operator `/`.
 ///
@@ -501,6 +507,8 @@ fn div(self, other: $t) -> $t { self / other }
 }
 
 div_impl_float! { f32 f64 }
+#[cfg(not(bootstrap))]
+div_impl_float! { f16 f128 }
 
 /// The remainder operator `%`.
 ///
@@ -617,6 +625,8 @@ fn rem(self, other: $t) -> $t { self % other }
 }
 
 rem_impl_float! { f32 f64 }
+#[cfg(not(bootstrap))]
+rem_impl_float! { f16 f128 }
 
 /// The unary negation operator `-`.
 ///
@@ -692,6 +702,8 @@ fn neg(self) -> $t { -self }
 }
 
 neg_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+neg_impl! { f16 f128 }
 
 /// The addition assignment operator `+=`.
 ///
@@ -758,6 +770,8 @@ fn add_assign(&mut self, other: $t) { *self += other }
 }
 
 add_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+add_assign_impl! { f16 f128 }
 
 /// The subtraction assignment operator `-=`.
 ///
@@ -824,6 +838,8 @@ fn sub_assign(&mut self, other: $t) { *self -= other }
 }
 
 sub_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+sub_assign_impl! { f16 f128 }
 
 /// The multiplication assignment operator `*=`.
 ///
@@ -881,6 +897,8 @@ fn mul_assign(&mut self, other: $t) { *self *= other }
 }
 
 mul_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+mul_assign_impl! { f16 f128 }
 
 /// The division assignment operator `/=`.
 ///
@@ -937,6 +955,8 @@ fn div_assign(&mut self, other: $t) { *self /= other }
 }
 
 div_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+div_assign_impl! { f16 f128 }
 
 /// The remainder assignment operator `%=`.
 ///
@@ -997,3 +1017,5 @@ fn rem_assign(&mut self, other: $t) { *self %= other }
 }
 
 rem_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+#[cfg(not(bootstrap))]
+rem_assign_impl! { f16 f128 }
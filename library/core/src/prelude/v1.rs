@@ -83,6 +83,13 @@
 #[unstable(feature = "derive_const", issue = "none")]
 pub use crate::macros::builtin::derive_const;
 
+#[unstable(
+    feature = "test_fixture",
+    issue = "none",
+    reason = "`test_fixture` is an experimental extension of the test harness"
+)]
+pub use crate::macros::builtin::test_fixture;
+
 #[unstable(
     feature = "cfg_accessible",
     issue = "64797",
@@ -1090,6 +1090,24 @@ mod prim_tuple {}
 #[doc(hidden)]
 impl<T> (T,) {}
 
+#[cfg(not(bootstrap))]
+#[rustc_doc_primitive = "f16"]
+/// A 16-bit floating point type (specifically, the "binary16" type defined in IEEE 754-2008).
+///
+/// This type is very similar to [`f32`], but has decreased precision by using half as many
+/// bits. Please see [the documentation for `f32`][`f32`] or [Wikipedia on half-precision
+/// values][wikipedia] for more information.
+///
+/// Not every target supports arithmetic on `f16` in hardware, in which case it is done in
+/// software, often by converting to `f32` and back.
+///
+/// *[See also the `std::f16::consts` module](crate::f16::consts).*
+///
+/// [`f32`]: prim@f32
+/// [wikipedia]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
+#[unstable(feature = "f16", issue = "none")]
+mod prim_f16 {}
+
 #[rustc_doc_primitive = "f32"]
 /// A 32-bit floating point type (specifically, the "binary32" type defined in IEEE 754-2008).
 ///
@@ -1173,6 +1191,24 @@ mod prim_f32 {}
 #[stable(feature = "rust1", since = "1.0.0")]
 mod prim_f64 {}
 
+#[cfg(not(bootstrap))]
+#[rustc_doc_primitive = "f128"]
+/// A 128-bit floating point type (specifically, the "binary128" type defined in IEEE 754-2008).
+///
+/// This type is very similar to [`f32`] and [`f64`], but has increased precision by using
+/// four times as many bits as `f32`. Please see [the documentation for `f32`][`f32`] or
+/// [Wikipedia on quadruple-precision values][wikipedia] for more information.
+///
+/// Hardware support for `f128` is rare, so arithmetic on it is usually done in software.
+///
+/// *[See also the `std::f128::consts` module](crate::f128::consts).*
+///
+/// [`f32`]: prim@f32
+/// [`f64`]: prim@f64
+/// [wikipedia]: https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format
+#[unstable(feature = "f128", issue = "none")]
+mod prim_f128 {}
+
 #[rustc_doc_primitive = "i8"]
 //
 /// The 8-bit signed integer type.
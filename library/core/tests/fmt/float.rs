@@ -170,6 +170,42 @@ fn test_format_f32_rounds_ties_to_even() {
     assert_eq!("-1.28E2", format!("{:.2E}", -128.5f32));
 }
 
+#[test]
+fn test_format_hex_float() {
+    assert_eq!("0x1p0", format!("{:a}", 1.0f64));
+    assert_eq!("0x1.8p3", format!("{:a}", 12.0f64));
+    assert_eq!("-0x0p0", format!("{:a}", -0.0f64));
+    assert_eq!("0x1.999999999999ap-4", format!("{:a}", 0.1f64));
+    assert_eq!("0x1.999999999999AP-4", format!("{:A}", 0.1f64));
+    assert_eq!("0x1.fffffffffffffp1023", format!("{:a}", f64::MAX));
+    assert_eq!("0x1p-1022", format!("{:a}", f64::MIN_POSITIVE));
+    assert_eq!("0x1p-1074", format!("{:a}", 5e-324f64));
+    assert_eq!("0x1.99999ap-4", format!("{:a}", 0.1f32));
+    assert_eq!("0x1.FFFFFEP127", format!("{:A}", f32::MAX));
+    assert_eq!("0x1p-149", format!("{:a}", 1e-45f32));
+    assert_eq!("inf", format!("{:a}", f64::INFINITY));
+    assert_eq!("-inf", format!("{:a}", f64::NEG_INFINITY));
+    assert_eq!("NaN", format!("{:a}", f64::NAN));
+    assert_eq!("+0x1p0", format!("{:+a}", 1.0f64));
+
+    assert_eq!("     0x1p0", format!("{:10a}", 1.0f64));
+    assert_eq!("0x1p0     ", format!("{:<10a}", 1.0f64));
+    assert_eq!("-0x00001p0", format!("{:010a}", -1.0f64));
+}
+
+#[test]
+fn test_format_hex_float_precision() {
+    assert_eq!("0x1.000p0", format!("{:.3a}", 1.0f64));
+    assert_eq!("0x1.000000000000000000p0", format!("{:.18a}", 1.0f64));
+    assert_eq!("0x1p0", format!("{:.0a}", 1.25f64));
+    assert_eq!("0x2p0", format!("{:.0a}", 1.5f64));
+    assert_eq!("0x1.0p0", format!("{:.1a}", 1.03125f64));
+    assert_eq!("0x1.2p0", format!("{:.1a}", 1.09375f64));
+    assert_eq!("0x2.0p0", format!("{:.1a}", 1.96875f64));
+    assert_eq!("0x1.9ap-4", format!("{:.2a}", 0.1f32));
+    assert_eq!("0x0.00p0", format!("{:.2a}", 0.0f64));
+}
+
 fn is_exponential(s: &str) -> bool {
     s.contains("e") || s.contains("E")
 }
@@ -127,3 +127,51 @@ fn massive_exponent() {
     assert_eq!(format!("1e-{max}000").parse(), Ok(0.0));
     assert_eq!(format!("1e{max}000").parse(), Ok(f64::INFINITY));
 }
+
+#[test]
+fn hex() {
+    assert_eq!(f64::from_str_hex("0x1.8p3"), Ok(12.0));
+    assert_eq!(f64::from_str_hex("1.8p3"), Ok(12.0));
+    assert_eq!(f64::from_str_hex("-0x1p-2"), Ok(-0.25));
+    assert_eq!(f64::from_str_hex("+0X1P+2"), Ok(4.0));
+    assert_eq!(f64::from_str_hex("0x.8"), Ok(0.5));
+    assert_eq!(f64::from_str_hex("0x1."), Ok(1.0));
+    assert_eq!(f64::from_str_hex("0x0p0"), Ok(0.0));
+    assert_eq!(f64::from_str_hex("inf"), Ok(f64::INFINITY));
+    assert!(f64::from_str_hex("NaN").unwrap().is_nan());
+    assert_eq!(f32::from_str_hex("0x1.99999ap-4"), Ok(0.1));
+    assert_eq!(f32::from_str_hex("0x1.fffffep127"), Ok(f32::MAX));
+    assert_eq!(f32::from_str_hex("0x1p-149"), Ok(1e-45));
+
+    for s in ["", "-", "0x", "0x.", "0xp1", "0x1p", "0x1p+", "0x1g", "0xinf", "0x1 "] {
+        assert!(f64::from_str_hex(s).is_err(), "{s:?}");
+    }
+}
+
+#[test]
+fn hex_rounding() {
+    assert_eq!(f64::from_str_hex("0x1.00000000000008p0"), Ok(1.0));
+    assert_eq!(f64::from_str_hex("0x1.00000000000018p0"), Ok(1.0 + 2.0 * f64::EPSILON));
+    assert_eq!(f64::from_str_hex("0x1.00000000000008000001p0"), Ok(1.0 + f64::EPSILON));
+    assert_eq!(f64::from_str_hex("0x1.fffffffffffff8p1023"), Ok(f64::INFINITY));
+    assert_eq!(f64::from_str_hex("0x1p1024"), Ok(f64::INFINITY));
+    assert_eq!(f64::from_str_hex("0x1p99999999999999999999"), Ok(f64::INFINITY));
+}
+
+#[test]
+fn hex_subnormal() {
+    assert_eq!(f64::from_str_hex("0x1p-1022"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(f64::from_str_hex("0x0.fffffffffffff8p-1022"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(f64::from_str_hex("0x1p-1074"), Ok(5e-324));
+    assert_eq!(f64::from_str_hex("0x1.8p-1075"), Ok(5e-324));
+    assert_eq!(f64::from_str_hex("0x1p-1075"), Ok(0.0));
+    assert_eq!(f64::from_str_hex("0x1p-99999999999999999999"), Ok(0.0));
+}
+
+#[test]
+fn hex_roundtrip() {
+    for x in [1.0, 0.1, 1e300, 1e-300, 5e-324, f64::MAX, f64::MIN_POSITIVE, -123.456] {
+        assert_eq!(f64::from_str_hex(&format!("{x:a}")), Ok(x));
+        assert_eq!(f64::from_str_hex(&format!("{x:A}")), Ok(x));
+    }
+}
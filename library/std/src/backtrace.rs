@@ -93,9 +93,11 @@
 use crate::ffi::c_void;
 use crate::fmt;
 use crate::panic::UnwindSafe;
+use crate::path::PathBuf;
+use crate::string::String;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::LazyLock;
-use crate::sys_common::backtrace::{lock, output_filename, set_image_base};
+use crate::sys_common::backtrace::{bytes_or_wide_to_path, lock, output_filename, set_image_base};
 use crate::vec::Vec;
 
 /// A captured OS thread stack backtrace.
@@ -147,6 +149,10 @@ fn _assert<T: Send + Sync>() {}
 }
 
 /// A single frame of a backtrace.
+///
+/// A frame corresponds to a return address on the stack, which can be
+/// resolved to several [`BacktraceSymbol`]s when functions were inlined into
+/// the function of the frame.
 #[unstable(feature = "backtrace_frames", issue = "79676")]
 pub struct BacktraceFrame {
     frame: RawFrame,
@@ -160,7 +166,13 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// The symbol information of a frame of a backtrace, resolved from the debug
+/// information of the program.
+///
+/// Any of the information may be missing, for example because the program
+/// was compiled without debug information.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -208,6 +220,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -371,7 +384,32 @@ pub fn status(&self) -> BacktraceStatus {
 }
 
 impl<'a> Backtrace {
-    /// Returns an iterator over the backtrace frames.
+    /// Returns the frames of the backtrace, resolving their symbols if it was
+    /// not done yet.
+    ///
+    /// The frames start with the innermost frame, and include the frames of
+    /// the backtrace capture itself which are not displayed. The slice is
+    /// empty if no backtrace was captured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace_frames)]
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         println!(
+    ///             "{:?} {} at {:?}:{}",
+    ///             frame.ip(),
+    ///             symbol.name().as_deref().unwrap_or("<unknown>"),
+    ///             symbol.filename(),
+    ///             symbol.lineno().unwrap_or(0),
+    ///         );
+    ///     }
+    /// }
+    /// ```
     #[must_use]
     #[unstable(feature = "backtrace_frames", issue = "79676")]
     pub fn frames(&'a self) -> &'a [BacktraceFrame] {
@@ -379,6 +417,86 @@ pub fn frames(&'a self) -> &'a [BacktraceFrame] {
     }
 }
 
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the address of the next instruction to execute in the
+    /// frame, i.e. the return address of the call.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function of this frame, if it is
+    /// known.
+    ///
+    /// On some platforms this is only an approximation, and it may be the
+    /// same as [`ip`](BacktraceFrame::ip).
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the symbols this frame was resolved to.
+    ///
+    /// If functions were inlined, there is one symbol for each of them,
+    /// starting with the innermost inlined function and ending with the
+    /// function which actually owns the frame. The slice is empty if the
+    /// frame could not be resolved.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of the function, without the hash of
+    /// Rust symbols.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the raw name of the function, as it is found in the debug
+    /// information. This is usually a mangled name.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn raw_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path of the source file of the function.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().map(|b| {
+            bytes_or_wide_to_path(match b {
+                BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+                BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
+            })
+            .into_owned()
+        })
+    }
+
+    /// Returns the line number in the source file.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number in the source file.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
 #[stable(feature = "backtrace", since = "1.65.0")]
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -471,4 +589,12 @@ fn ip(&self) -> *mut c_void {
             RawFrame::Fake => crate::ptr::invalid_mut(1),
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => crate::ptr::invalid_mut(1),
+        }
+    }
 }
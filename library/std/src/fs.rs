@@ -223,6 +223,57 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A handle to an open directory, relative to which files and directories can be
+/// opened, created, renamed and removed.
+///
+/// Path-based functions like [`fs::remove_file`] resolve their whole path each time
+/// they are called, so if another process replaces one of the directories along the
+/// way, for instance by a symbolic link, they operate somewhere else than intended.
+/// The methods of `Dir` instead resolve their path relative to the directory the
+/// handle was opened on, even if it was moved or renamed in the meantime.
+///
+/// The paths passed to these methods must be relative: absolute paths are rejected
+/// with an error of kind [`io::ErrorKind::InvalidInput`]. None of the methods follow
+/// a symbolic link as the *last* component of their path: [`Dir::open_dir`] and
+/// [`Dir::open_file`] fail on one, and the others operate on the link itself. `..`
+/// components and symbolic links in any *other* component are followed though, and
+/// can lead out of the directory.
+///
+/// To confine operations to a directory tree, open each subdirectory with
+/// [`Dir::open_dir`] and only ever pass single file names, checking that they are
+/// not `..`. This way no path is ever resolved through a symbolic link.
+///
+/// # Platform-specific behavior
+///
+/// This type uses `openat` and the related functions on Unix, and is supported on
+/// WASI. It is not supported on Windows yet, nor on any other platform: there,
+/// [`Dir::open`] returns an error of kind [`io::ErrorKind::Unsupported`].
+///
+/// [`fs::remove_file`]: remove_file
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dir_handle)]
+/// use std::fs::{Dir, OpenOptions};
+/// use std::io::Write;
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = Dir::open("/srv/uploads")?;
+///     dir.create_dir("incoming")?;
+///     let incoming = dir.open_dir("incoming")?;
+///     let mut file =
+///         incoming.open_file("upload.txt", OpenOptions::new().write(true).create_new(true))?;
+///     file.write_all(b"hello")?;
+///     incoming.rename_to("upload.txt", &dir, "upload.txt")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "dir_handle", issue = "none")]
+pub struct Dir {
+    inner: fs_imp::DirHandle,
+}
+
 /// Read the entire contents of a file into a bytes vector.
 ///
 /// This is a convenience function for using [`File::open`] and [`read_to_end`]
@@ -518,6 +569,179 @@ pub fn sync_data(&self) -> io::Result<()> {
         self.inner.datasync()
     }
 
+    /// Acquires an exclusive lock on the file, blocking until it can be acquired.
+    ///
+    /// At most one exclusive lock, or any number of shared locks, can be held on
+    /// a file at the same time. The lock is released by [`unlock`], or when all the
+    /// handles to the file are closed, including those created with
+    /// [`try_clone`]. To be portable, release the lock before acquiring another
+    /// one through the same handle, as what happens otherwise differs between
+    /// platforms.
+    ///
+    /// This lock is advisory: it only coordinates processes which lock the file,
+    /// and does not prevent reading or writing it.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with
+    /// the `LOCK_EX` flag, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_EXCLUSIVE_LOCK` flag. On Windows, the lock is mandatory: other
+    /// handles cannot read or write a file locked exclusively. Other platforms
+    /// return an error of kind [`io::ErrorKind::Unsupported`].
+    /// Note that this [may change in the future][changes].
+    ///
+    /// On Unix, acquiring a lock through a handle which already holds one
+    /// converts the lock, which may not be atomic. On Windows, locks are not
+    /// converted but stacked, and each of them must be released with its own
+    /// call to [`unlock`]. An exclusive lock cannot be acquired while the file
+    /// is locked at all, even through the same handle, so upgrading a shared
+    /// lock with this function blocks forever, and [`try_lock`] returns
+    /// `Ok(false)`.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`unlock`]: File::unlock
+    /// [`try_clone`]: File::try_clone
+    /// [`try_lock`]: File::try_lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("cache.lock")?;
+    ///     f.lock()?;
+    ///     // Update the cache, while other processes wait on the lock.
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared lock on the file, blocking until it can be acquired.
+    ///
+    /// Any number of shared locks can be held on a file, as long as no exclusive
+    /// lock is held. See [`lock`] for how locks are released.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with
+    /// the `LOCK_SH` flag, and the `LockFileEx` function on Windows. On Windows,
+    /// the lock is mandatory: other handles cannot write a file with a shared lock.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("cache.lock")?;
+    ///     f.lock_shared()?;
+    ///     // Read the cache, while other readers may hold the lock too.
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Attempts to acquire an exclusive lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds a lock on the file, and
+    /// `Ok(true)` once the lock is acquired. See [`lock`] for the details.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with
+    /// the `LOCK_EX` and `LOCK_NB` flags, and the `LockFileEx` function on Windows
+    /// with the `LOCKFILE_EXCLUSIVE_LOCK` and `LOCKFILE_FAIL_IMMEDIATELY` flags.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("cache.lock")?;
+    ///     if !f.try_lock()? {
+    ///         eprintln!("waiting for another process to release the cache");
+    ///         f.lock()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Attempts to acquire a shared lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds an exclusive lock on the file,
+    /// and `Ok(true)` once the lock is acquired. See [`lock_shared`] for the
+    /// details.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with
+    /// the `LOCK_SH` and `LOCK_NB` flags, and the `LockFileEx` function on Windows
+    /// with the `LOCKFILE_FAIL_IMMEDIATELY` flag.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock_shared`]: File::lock_shared
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with
+    /// the `LOCK_UN` flag, and the `UnlockFile` function on Windows. On Windows,
+    /// this returns an error if the file is not locked, and only releases one of
+    /// the locks held through the handle, see [`lock`].
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("cache.lock")?;
+    ///     f.lock()?;
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
     /// Truncates or extends the underlying file, updating the size of
     /// this file to become `size`.
     ///
@@ -2582,6 +2806,195 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
     }
 }
 
+impl Dir {
+    /// Opens the directory at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist or is not a
+    /// directory, or if the user lacks permission to open it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(dir_handle)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("/srv/www")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Dir> {
+        fs_imp::DirHandle::open(path.as_ref()).map(|inner| Dir { inner })
+    }
+
+    /// Opens the subdirectory at `path`, relative to this directory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, if its last
+    /// component is a symbolic link, or in the same cases as [`Dir::open`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        self.inner.open_dir(relative_to_dir(path.as_ref())?).map(|inner| Dir { inner })
+    }
+
+    /// Opens the file at `path`, relative to this directory, with the options
+    /// specified by `opts`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, if its last
+    /// component is a symbolic link, or in the same cases as [`OpenOptions::open`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(dir_handle)]
+    /// use std::fs::{Dir, OpenOptions};
+    /// use std::io::Read;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("/srv/www")?;
+    ///     let mut file = dir.open_file("index.html", OpenOptions::new().read(true))?;
+    ///     let mut contents = String::new();
+    ///     file.read_to_string(&mut contents)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open_file<P: AsRef<Path>>(&self, path: P, opts: &OpenOptions) -> io::Result<File> {
+        self.inner.open_file(relative_to_dir(path.as_ref())?, &opts.0).map(|inner| File { inner })
+    }
+
+    /// Creates a new, empty directory at `path`, relative to this directory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, or in the same
+    /// cases as [`fs::create_dir`].
+    ///
+    /// [`fs::create_dir`]: create_dir
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn create_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.create_dir(relative_to_dir(path.as_ref())?)
+    }
+
+    /// Removes the file at `path`, relative to this directory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, or in the same
+    /// cases as [`fs::remove_file`].
+    ///
+    /// [`fs::remove_file`]: remove_file
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn remove_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_file(relative_to_dir(path.as_ref())?)
+    }
+
+    /// Removes the empty directory at `path`, relative to this directory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, or in the same
+    /// cases as [`fs::remove_dir`].
+    ///
+    /// [`fs::remove_dir`]: remove_dir
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn remove_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_dir(relative_to_dir(path.as_ref())?)
+    }
+
+    /// Renames the file or directory at `from`, relative to this directory, to
+    /// `to`, relative to `to_dir`, replacing the original file if `to` already
+    /// exists.
+    ///
+    /// `to_dir` can be this directory itself.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `from` or `to` is absolute, or in
+    /// the same cases as [`fs::rename`].
+    ///
+    /// [`fs::rename`]: rename
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn rename_to<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        from: P,
+        to_dir: &Dir,
+        to: Q,
+    ) -> io::Result<()> {
+        let from = relative_to_dir(from.as_ref())?;
+        let to = relative_to_dir(to.as_ref())?;
+        self.inner.rename_to(from, &to_dir.inner, to)
+    }
+
+    /// Queries the metadata of the file at `path`, relative to this directory,
+    /// without following a symbolic link at the end of the path.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` is absolute, or in the same
+    /// cases as [`fs::symlink_metadata`].
+    ///
+    /// [`fs::symlink_metadata`]: symlink_metadata
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn symlink_metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<Metadata> {
+        self.inner.symlink_metadata(relative_to_dir(path.as_ref())?).map(Metadata)
+    }
+
+    /// Returns an iterator over the entries of this directory.
+    ///
+    /// The [`DirEntry::path`] of the entries is built from the path this
+    /// directory was opened with, and may no longer point at the entry if the
+    /// directory was moved. Use [`DirEntry::file_name`] with the methods of this
+    /// handle instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(dir_handle)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let dir = Dir::open("/srv/uploads")?;
+    ///     for entry in dir.read_dir()? {
+    ///         let name = entry?.file_name();
+    ///         if dir.symlink_metadata(&name)?.is_file() {
+    ///             dir.remove_file(&name)?;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        self.inner.read_dir().map(ReadDir)
+    }
+}
+
+#[unstable(feature = "dir_handle", issue = "none")]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Rejects absolute paths, which the platform would resolve without the directory.
+fn relative_to_dir(path: &Path) -> io::Result<&Path> {
+    if path.has_root() {
+        Err(io::const_io_error!(
+            io::ErrorKind::InvalidInput,
+            "path must be relative to the directory handle",
+        ))
+    } else {
+        Ok(path)
+    }
+}
+
 /// Returns `Ok(true)` if the path points at an existing entity.
 ///
 /// This function will traverse symbolic links to query information about the
@@ -1636,6 +1636,81 @@ fn rename_directory() {
     assert!(new_path.join("newdir/temp.txt").exists());
 }
 
+#[test]
+#[cfg(unix)]
+fn dir_handle_relative_operations() {
+    let tmpdir = tmpdir();
+    let dir = check!(fs::Dir::open(tmpdir.path()));
+
+    check!(dir.create_dir("sub"));
+    let sub = check!(dir.open_dir("sub"));
+    let mut file = check!(sub.open_file("a.txt", OpenOptions::new().write(true).create_new(true)));
+    check!(file.write_all(b"hello"));
+    drop(file);
+    assert!(check!(sub.symlink_metadata("a.txt")).is_file());
+
+    // Moving the directory does not affect operations relative to its handle.
+    check!(fs::rename(tmpdir.join("sub"), tmpdir.join("moved")));
+    check!(sub.rename_to("a.txt", &dir, "b.txt"));
+    assert_eq!(check!(fs::read(tmpdir.join("b.txt"))), b"hello");
+
+    let names: Vec<_> = check!(dir.read_dir()).map(|e| check!(e).file_name()).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.iter().any(|name| name == "b.txt"));
+
+    check!(dir.remove_file("b.txt"));
+    check!(dir.remove_dir("moved"));
+    assert!(check!(dir.read_dir()).next().is_none());
+
+    let err = dir.open_file(tmpdir.join("b.txt"), OpenOptions::new().read(true)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(unix)]
+fn dir_handle_does_not_follow_symlinks() {
+    let tmpdir = tmpdir();
+    check!(fs::create_dir(tmpdir.join("outside")));
+    check!(fs::write(tmpdir.join("outside/secret.txt"), b"secret"));
+    check!(fs::create_dir(tmpdir.join("root")));
+    check!(symlink_dir(tmpdir.join("outside"), tmpdir.join("root/dir_link")));
+    check!(symlink_file(tmpdir.join("outside/secret.txt"), tmpdir.join("root/file_link")));
+
+    let root = check!(fs::Dir::open(tmpdir.join("root")));
+    assert!(root.open_dir("dir_link").is_err());
+    assert!(root.open_file("file_link", OpenOptions::new().read(true)).is_err());
+    assert!(root.open_file("file_link", OpenOptions::new().write(true).truncate(true)).is_err());
+    assert!(check!(root.symlink_metadata("file_link")).file_type().is_symlink());
+
+    // Removing the link leaves its target alone.
+    check!(root.remove_file("file_link"));
+    assert_eq!(check!(fs::read(tmpdir.join("outside/secret.txt"))), b"secret");
+}
+
+#[test]
+#[cfg(any(windows, target_os = "linux", target_os = "macos"))]
+fn file_lock_multiple_handles() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("lock");
+    let f1 = check!(File::create(&path));
+    let f2 = check!(File::open(&path));
+
+    check!(f1.lock());
+    assert!(!check!(f2.try_lock()));
+    assert!(!check!(f2.try_lock_shared()));
+    check!(f1.unlock());
+
+    check!(f1.lock_shared());
+    assert!(check!(f2.try_lock_shared()));
+    check!(f2.unlock());
+    assert!(!check!(f2.try_lock()));
+    check!(f1.unlock());
+
+    assert!(check!(f2.try_lock()));
+    check!(f2.unlock());
+    assert!(check!(f1.try_lock()));
+}
+
 #[test]
 fn test_file_times() {
     #[cfg(target_os = "ios")]
@@ -0,0 +1,210 @@
+#[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::pipe as imp;
+use crate::sys_common::{AsInner, IntoInner};
+
+/// Creates an anonymous pipe.
+///
+/// Returns the reading end and the writing end of the pipe. Bytes written to
+/// the [`PipeWriter`] can be read from the [`PipeReader`], in the same order.
+/// Reading blocks until some data is available, and returns `Ok(0)` once all
+/// the writers were dropped. Writing blocks while the pipe buffer is full,
+/// and fails with [`ErrorKind::BrokenPipe`] once all the readers were dropped.
+///
+/// Both ends can be converted into a [`Stdio`] to be given to a child
+/// process, which is how the output of a child can be sent to several
+/// processes, or the outputs of several children to a single reader. The
+/// handles of the pipe are not inherited by child processes otherwise.
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function (or `pipe`
+/// followed by setting the close-on-exec flag where `pipe2` is not
+/// available) on Unix and the `CreatePipe` function on Windows.
+/// Note that this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+/// [`ErrorKind::BrokenPipe`]: io::ErrorKind::BrokenPipe
+/// [`Stdio`]: crate::process::Stdio
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// let (mut reader, writer) = io::pipe()?;
+///
+/// // Both children write to the same pipe.
+/// let mut hello = Command::new("echo").arg("hello").stdout(writer.try_clone()?).spawn()?;
+/// let mut world = Command::new("echo").arg("world").stdout(writer).spawn()?;
+///
+/// // The last writer was moved into the `Command`, so the read ends once both
+/// // children exited.
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output)?;
+/// hello.wait()?;
+/// world.wait()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    let (reader, writer) = imp::pipe()?;
+    Ok((PipeReader(reader), PipeWriter(writer)))
+}
+
+/// The reading end of a pipe, created by [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(imp::PipeEnd);
+
+/// The writing end of a pipe, created by [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(imp::PipeEnd);
+
+impl PipeReader {
+    /// Creates a new `PipeReader` reading from the same pipe.
+    ///
+    /// The pipe only reports the end of the data once every writer, but
+    /// not every reader, was dropped.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` writing to the same pipe.
+    ///
+    /// The reading end of the pipe only sees the end of the data once every
+    /// clone was dropped.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        (&*self).read_buf(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        (&*self).read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        (&&*self).is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        (&*self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        (&&*self).is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish_non_exhaustive()
+    }
+}
+
+impl AsInner<imp::PipeEnd> for PipeReader {
+    #[inline]
+    fn as_inner(&self) -> &imp::PipeEnd {
+        &self.0
+    }
+}
+
+impl IntoInner<imp::PipeEnd> for PipeReader {
+    fn into_inner(self) -> imp::PipeEnd {
+        self.0
+    }
+}
+
+impl AsInner<imp::PipeEnd> for PipeWriter {
+    #[inline]
+    fn as_inner(&self) -> &imp::PipeEnd {
+        &self.0
+    }
+}
+
+impl IntoInner<imp::PipeEnd> for PipeWriter {
+    fn into_inner(self) -> imp::PipeEnd {
+        self.0
+    }
+}
@@ -0,0 +1,33 @@
+use crate::io::{self, Read, Write};
+use crate::thread;
+
+#[test]
+fn pipe_read_write() {
+    let (mut reader, mut writer) = io::pipe().unwrap();
+    let writer2 = writer.try_clone().unwrap();
+
+    let t = thread::spawn(move || {
+        (&writer2).write_all(b"hello ").unwrap();
+    });
+    t.join().unwrap();
+    writer.write_all(b"world").unwrap();
+    drop(writer);
+
+    let mut s = String::new();
+    reader.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "hello world");
+}
+
+#[test]
+fn pipe_reader_clone() {
+    let (reader, mut writer) = io::pipe().unwrap();
+    let mut reader2 = reader.try_clone().unwrap();
+    drop(reader);
+
+    writer.write_all(b"a").unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    reader2.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"a");
+}
@@ -384,6 +384,10 @@
 #![feature(trace_macros)]
 // tidy-alphabetical-end
 //
+// Only for re-exporting, and not known to the bootstrap compiler yet:
+#![cfg_attr(not(bootstrap), feature(f128))]
+#![cfg_attr(not(bootstrap), feature(f16))]
+//
 // Only used in tests/benchmarks:
 //
 // Only for const-ness:
@@ -551,6 +555,12 @@
 #[allow(deprecated, deprecated_in_future)]
 pub use core::usize;
 
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f128", issue = "none")]
+pub use core::f128;
+#[cfg(not(bootstrap))]
+#[unstable(feature = "f16", issue = "none")]
+pub use core::f16;
 pub mod f32;
 pub mod f64;
 
@@ -29,10 +29,14 @@
 pub use self::socket_addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
 #[unstable(feature = "tcplistener_into_incoming", issue = "88339")]
 pub use self::tcp::IntoIncoming;
+#[unstable(feature = "socket_builder", issue = "none")]
+pub use self::tcp::TcpSocketBuilder;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
+#[unstable(feature = "socket_builder", issue = "none")]
+pub use self::udp::UdpSocketBuilder;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::net::AddrParseError;
 
@@ -68,6 +72,22 @@ pub enum Shutdown {
     Both,
 }
 
+/// The options set by [`TcpSocketBuilder`] and [`UdpSocketBuilder`] before
+/// binding or connecting a socket.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SocketOptions {
+    /// `SO_REUSEADDR`, left to the platform default if `None`.
+    pub(crate) reuse_address: Option<bool>,
+    /// `SO_REUSEPORT`.
+    pub(crate) reuse_port: bool,
+    /// `IPV6_V6ONLY` for IPv6 sockets, left to the platform default if `None`.
+    pub(crate) only_v6: Option<bool>,
+    /// The address a TCP socket is bound to before connecting it.
+    pub(crate) local_addr: Option<SocketAddr>,
+    /// The size of the queue of pending connections of a TCP listener.
+    pub(crate) backlog: Option<u32>,
+}
+
 fn each_addr<A: ToSocketAddrs, F, T>(addr: A, mut f: F) -> io::Result<T>
 where
     F: FnMut(io::Result<&SocketAddr>) -> io::Result<T>,
@@ -8,7 +8,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::iter::FusedIterator;
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Shutdown, SocketAddr, SocketOptions, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -1069,3 +1069,179 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A builder for TCP sockets, to set options which have to be set before the
+/// socket is bound or connected.
+///
+/// A `TcpSocketBuilder` creates a [`TcpListener`] with [`listen`], or a
+/// [`TcpStream`] with [`connect`] or [`connect_timeout`]. Each of these
+/// creates a new socket with the options of the builder, so a builder can be
+/// used to create several sockets.
+///
+/// [`listen`]: TcpSocketBuilder::listen
+/// [`connect`]: TcpSocketBuilder::connect
+/// [`connect_timeout`]: TcpSocketBuilder::connect_timeout
+///
+/// # Examples
+///
+/// Several listeners can share a port with `SO_REUSEPORT`:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+/// use std::net::TcpSocketBuilder;
+///
+/// let mut builder = TcpSocketBuilder::new();
+/// builder.reuse_port(true).backlog(1024);
+/// let listener1 = builder.listen("0.0.0.0:8080")?;
+/// let listener2 = builder.listen("0.0.0.0:8080")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+///
+/// Connecting from a given local address:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+/// use std::net::{SocketAddr, TcpSocketBuilder};
+///
+/// let stream = TcpSocketBuilder::new()
+///     .local_addr(SocketAddr::from(([192, 168, 0, 2], 0)))
+///     .connect("192.168.0.1:8080")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[unstable(feature = "socket_builder", issue = "none")]
+#[derive(Clone, Debug, Default)]
+pub struct TcpSocketBuilder {
+    options: SocketOptions,
+}
+
+impl TcpSocketBuilder {
+    /// Creates a builder with no options set, creating sockets like
+    /// [`TcpListener::bind`] and [`TcpStream::connect`] do.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub fn new() -> TcpSocketBuilder {
+        TcpSocketBuilder::default()
+    }
+
+    /// Sets the `SO_REUSEADDR` option of the sockets.
+    ///
+    /// On Unix, this allows a listener to bind to an address which is still
+    /// used by the connections of a previous listener. [`TcpListener::bind`]
+    /// sets this option on platforms other than Windows.
+    ///
+    /// On Windows, this allows a socket to bind to an address which is
+    /// already bound, even by another process, so it should only be used with
+    /// care.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_address(&mut self, reuse: bool) -> &mut TcpSocketBuilder {
+        self.options.reuse_address = Some(reuse);
+        self
+    }
+
+    /// Sets the `SO_REUSEPORT` option of the sockets, which allows several
+    /// sockets to bind to the same address and port.
+    ///
+    /// On Linux, the incoming connections are distributed among the listeners
+    /// which are bound to the same address.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is supported on Linux, Android, the BSDs and Apple
+    /// platforms. On other platforms, creating a socket fails with
+    /// [`io::ErrorKind::Unsupported`] if it is set.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_port(&mut self, reuse: bool) -> &mut TcpSocketBuilder {
+        self.options.reuse_port = reuse;
+        self
+    }
+
+    /// Sets the `IPV6_V6ONLY` option of IPv6 sockets.
+    ///
+    /// If this is set to `true`, a listener bound to an IPv6 address only
+    /// accepts IPv6 connections, and if it is set to `false`, it also accepts
+    /// IPv4 connections from IPv4-mapped addresses. The default depends on the
+    /// platform. This option is ignored for IPv4 sockets.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut TcpSocketBuilder {
+        self.options.only_v6 = Some(only_v6);
+        self
+    }
+
+    /// Sets the local address the sockets are bound to before they are
+    /// connected.
+    ///
+    /// By default the operating system chooses the local address and port.
+    /// A port of 0 still lets the operating system choose the port. An
+    /// address which is not of the same family as the remote address fails
+    /// to bind, so that address is skipped by [`connect`].
+    ///
+    /// This has no effect on [`listen`].
+    ///
+    /// [`connect`]: TcpSocketBuilder::connect
+    /// [`listen`]: TcpSocketBuilder::listen
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn local_addr(&mut self, addr: SocketAddr) -> &mut TcpSocketBuilder {
+        self.options.local_addr = Some(addr);
+        self
+    }
+
+    /// Sets the maximum number of pending connections of the listeners, which
+    /// were not accepted yet.
+    ///
+    /// The operating system may silently cap the value. The default is 128
+    /// on most platforms.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn backlog(&mut self, backlog: u32) -> &mut TcpSocketBuilder {
+        self.options.backlog = Some(backlog);
+        self
+    }
+
+    /// Creates a new socket, bound to the given address, and starts listening
+    /// for connections.
+    ///
+    /// If `addr` yields multiple addresses, `listen` is attempted with each
+    /// of the addresses until one succeeds, like [`TcpListener::bind`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn listen<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpListener> {
+        super::each_addr(addr, |addr| net_imp::TcpListener::bind_with(addr, &self.options))
+            .map(TcpListener)
+    }
+
+    /// Creates a new socket and opens a TCP connection to a remote host.
+    ///
+    /// If `addr` yields multiple addresses, `connect` is attempted with each
+    /// of the addresses until a connection is successful, like
+    /// [`TcpStream::connect`], with a new socket for each attempt.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn connect<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpStream> {
+        super::each_addr(addr, |addr| net_imp::TcpStream::connect_with(addr, &self.options, None))
+            .map(TcpStream)
+    }
+
+    /// Creates a new socket and opens a TCP connection to a remote host,
+    /// with a timeout.
+    ///
+    /// Unlike [`TcpStream::connect_timeout`], this accepts anything which
+    /// implements [`ToSocketAddrs`], including host names. If `addr` yields
+    /// multiple addresses, they are tried in turn like in [`connect`], and
+    /// the timeout applies to each attempt. Name resolution is not covered by
+    /// the timeout.
+    ///
+    /// It is an error to pass a zero `Duration` to this function.
+    ///
+    /// [`connect`]: TcpSocketBuilder::connect
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn connect_timeout<A: ToSocketAddrs>(
+        &self,
+        addr: A,
+        timeout: Duration,
+    ) -> io::Result<TcpStream> {
+        super::each_addr(addr, |addr| {
+            net_imp::TcpStream::connect_with(addr, &self.options, Some(timeout))
+        })
+        .map(TcpStream)
+    }
+}
@@ -914,3 +914,27 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_builder() {
+    each_ip(&mut |addr| {
+        let listener = t!(TcpSocketBuilder::new().reuse_address(true).backlog(4).listen(&addr));
+        let local = SocketAddr::new(addr.ip(), 0);
+
+        let mut builder = TcpSocketBuilder::new();
+        builder.local_addr(local);
+        let stream = t!(builder.connect(&addr));
+        assert_eq!(t!(stream.local_addr()).ip(), addr.ip());
+        let (_, peer) = t!(listener.accept());
+        assert_eq!(peer, t!(stream.local_addr()));
+
+        // The first address refuses the connection, so the second one is used.
+        let unused = t!(TcpListener::bind(SocketAddr::new(addr.ip(), 0)));
+        let refused = t!(unused.local_addr());
+        drop(unused);
+        let addrs = [refused, addr];
+        let stream = t!(builder.connect_timeout(&addrs[..], Duration::from_secs(2)));
+        assert_eq!(t!(stream.peer_addr()), addr);
+    })
+}
@@ -3,7 +3,7 @@
 
 use crate::fmt;
 use crate::io::{self, ErrorKind};
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketOptions, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -840,3 +840,88 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A builder for UDP sockets, to set options which have to be set before the
+/// socket is bound.
+///
+/// Each call to [`bind`] creates a new socket with the options of the
+/// builder.
+///
+/// [`bind`]: UdpSocketBuilder::bind
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+/// use std::net::UdpSocketBuilder;
+///
+/// // Receive both IPv6 and IPv4 datagrams on the same socket.
+/// let socket = UdpSocketBuilder::new().only_v6(false).bind("[::]:3400")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[unstable(feature = "socket_builder", issue = "none")]
+#[derive(Clone, Debug, Default)]
+pub struct UdpSocketBuilder {
+    options: SocketOptions,
+}
+
+impl UdpSocketBuilder {
+    /// Creates a builder with no options set, creating sockets like
+    /// [`UdpSocket::bind`] does.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub fn new() -> UdpSocketBuilder {
+        UdpSocketBuilder::default()
+    }
+
+    /// Sets the `SO_REUSEADDR` option of the sockets.
+    ///
+    /// This allows several sockets to bind to the same multicast address and
+    /// port. On Windows, this allows a socket to bind to any address which is
+    /// already bound, even by another process, so it should only be used with
+    /// care.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_address(&mut self, reuse: bool) -> &mut UdpSocketBuilder {
+        self.options.reuse_address = Some(reuse);
+        self
+    }
+
+    /// Sets the `SO_REUSEPORT` option of the sockets, which allows several
+    /// sockets to bind to the same address and port.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is supported on Linux, Android, the BSDs and Apple
+    /// platforms. On other platforms, creating a socket fails with
+    /// [`io::ErrorKind::Unsupported`] if it is set.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_port(&mut self, reuse: bool) -> &mut UdpSocketBuilder {
+        self.options.reuse_port = reuse;
+        self
+    }
+
+    /// Sets the `IPV6_V6ONLY` option of IPv6 sockets.
+    ///
+    /// If this is set to `false`, a socket bound to an IPv6 address also
+    /// sends and receives IPv4 datagrams, using IPv4-mapped addresses. The
+    /// default depends on the platform. This option is ignored for IPv4
+    /// sockets.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut UdpSocketBuilder {
+        self.options.only_v6 = Some(only_v6);
+        self
+    }
+
+    /// Creates a new socket bound to the given address.
+    ///
+    /// If `addr` yields multiple addresses, `bind` is attempted with each of
+    /// the addresses until one succeeds, like [`UdpSocket::bind`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn bind<A: ToSocketAddrs>(&self, addr: A) -> io::Result<UdpSocket> {
+        super::each_addr(addr, |addr| net_imp::UdpSocket::bind_with(addr, &self.options))
+            .map(UdpSocket)
+    }
+}
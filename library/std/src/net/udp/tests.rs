@@ -366,3 +366,18 @@ fn set_nonblocking() {
         }
     })
 }
+
+#[test]
+fn socket_builder() {
+    each_ip(&mut |addr1, addr2| {
+        let server = t!(UdpSocketBuilder::new().reuse_address(true).bind(&addr1));
+        let client = t!(UdpSocketBuilder::new().bind(&addr2));
+
+        t!(client.send_to(&[99], &addr1));
+        let mut buf = [0];
+        let (nread, src) = t!(server.recv_from(&mut buf));
+        assert_eq!(nread, 1);
+        assert_eq!(buf[0], 99);
+        assert_eq!(src, addr2);
+    })
+}
@@ -302,6 +302,42 @@ fn from(owned_fd: OwnedFd) -> Self {
     }
 }
 
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsFd for io::PipeReader {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().as_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for OwnedFd {
+    #[inline]
+    fn from(pipe: io::PipeReader) -> OwnedFd {
+        pipe.into_inner().into_inner().into_inner()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsFd for io::PipeWriter {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().as_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for OwnedFd {
+    #[inline]
+    fn from(pipe: io::PipeWriter) -> OwnedFd {
+        pipe.into_inner().into_inner().into_inner()
+    }
+}
+
 #[stable(feature = "io_safety", since = "1.63.0")]
 impl AsFd for crate::net::TcpStream {
     #[inline]
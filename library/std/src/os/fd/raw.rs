@@ -186,6 +186,42 @@ fn into_raw_fd(self) -> RawFd {
     }
 }
 
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeReader {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().as_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeReader {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeWriter {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().as_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeWriter {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_raw_fd()
+    }
+}
+
 #[stable(feature = "asraw_stdio", since = "1.21.0")]
 impl AsRawFd for io::Stdin {
     #[inline]
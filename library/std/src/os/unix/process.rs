@@ -187,6 +187,72 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     /// ```
     #[stable(feature = "process_set_process_group", since = "1.64.0")]
     fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Starts the child process in a new session, equivalent to a `setsid`
+    /// call in the child process.
+    ///
+    /// The child becomes the leader of a new session and of a new process
+    /// group, and is detached from the controlling terminal of the parent.
+    /// This takes precedence over [`process_group`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_setsid)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// Command::new("sleep")
+    ///     .arg("10")
+    ///     .setsid(true)
+    ///     .spawn()?
+    ///     .wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`process_group`]: CommandExt::process_group
+    #[unstable(feature = "process_setsid", issue = "none")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
+
+    /// Makes [`Child::kill`] send `SIGKILL` to the whole process group of the
+    /// child instead of only to the child, so that the processes it spawned
+    /// are killed as well.
+    ///
+    /// Unless [`process_group`] or [`setsid`] is also used, the child is put
+    /// in a new process group of its own, so that the group does not include
+    /// the parent. Only a group which the child leads is killed: if
+    /// [`process_group`] makes the child join an existing group, only the
+    /// child is killed.
+    ///
+    /// Processes which moved to another process group are not killed. The
+    /// group is not signaled if the child was already reaped, as its process
+    /// group ID may have been reused by then.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_kill_group)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// let mut child = Command::new("sh")
+    ///     .args(["-c", "sleep 100 & sleep 100"])
+    ///     .kill_process_group(true)
+    ///     .spawn()?;
+    ///
+    /// // Kills both `sleep` processes.
+    /// child.kill()?;
+    /// child.wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Child::kill`]: process::Child::kill
+    /// [`process_group`]: CommandExt::process_group
+    /// [`setsid`]: CommandExt::setsid
+    #[unstable(feature = "process_kill_group", issue = "none")]
+    fn kill_process_group(&mut self, kill: bool) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -232,6 +298,16 @@ fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
         self.as_inner_mut().pgroup(pgroup);
         self
     }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+
+    fn kill_process_group(&mut self, kill: bool) -> &mut process::Command {
+        self.as_inner_mut().kill_process_group(kill);
+        self
+    }
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
@@ -516,6 +516,38 @@ fn from(owned: OwnedHandle) -> Self {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsHandle for io::PipeReader {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.as_inner().as_handle()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for OwnedHandle {
+    #[inline]
+    fn from(pipe: io::PipeReader) -> OwnedHandle {
+        pipe.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsHandle for io::PipeWriter {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.as_inner().as_handle()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for OwnedHandle {
+    #[inline]
+    fn from(pipe: io::PipeWriter) -> OwnedHandle {
+        pipe.into_inner().into_inner()
+    }
+}
+
 #[stable(feature = "io_safety", since = "1.63.0")]
 impl AsHandle for crate::io::Stdin {
     #[inline]
@@ -2003,6 +2003,17 @@ pub struct Path {
 #[stable(since = "1.7.0", feature = "strip_prefix")]
 pub struct StripPrefixError(());
 
+/// An error returned from [`Path::relative_to`] if no relative path leads
+/// from the base path to the path.
+///
+/// This `struct` is created by the [`relative_to`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`relative_to`]: Path::relative_to
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "path_relative_to", issue = "none")]
+pub struct RelativePathError(());
+
 impl Path {
     // The following (private!) function allows construction of a path from a u8
     // slice, which is only safe when it is known to follow the OsStr encoding.
@@ -2440,6 +2451,145 @@ fn _ends_with(&self, child: &Path) -> bool {
         iter_after(self.components().rev(), child.components().rev()).is_some()
     }
 
+    /// Normalizes the path without accessing the filesystem.
+    ///
+    /// Occurrences of `.` are removed, and each `..` is removed along with the
+    /// preceding normal component. A `..` directly after the root of the path
+    /// is removed, as the parent of the root is the root itself. A `..` at the
+    /// start of a relative path is kept, as the directory it refers to is not
+    /// known without the current directory. Prefixes on Windows are kept as
+    /// they are. If nothing is left of a relative path, the result is `.`.
+    ///
+    /// Unlike [`canonicalize`], this does not resolve symbolic links and does
+    /// not require the path to exist. Note that this means the result can
+    /// refer to another file than the original path: if `b` is a symbolic link
+    /// to a directory in another place, then `a/b/../c` is not `a/c`.
+    ///
+    /// [`canonicalize`]: Path::canonicalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(normalize_lexically)]
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(Path::new("a/./b/../c").normalize_lexically(), Path::new("a/c"));
+    /// assert_eq!(Path::new("/a/../../b/").normalize_lexically(), Path::new("/b"));
+    /// assert_eq!(Path::new("../a/../b").normalize_lexically(), Path::new("../b"));
+    /// assert_eq!(Path::new("a/..").normalize_lexically(), Path::new("."));
+    /// ```
+    #[unstable(feature = "normalize_lexically", issue = "none")]
+    #[must_use]
+    pub fn normalize_lexically(&self) -> PathBuf {
+        let mut lexical = PathBuf::new();
+        // The number of normal components at the end of `lexical`, which can
+        // be removed by `..`.
+        let mut normal = 0;
+        for component in self.components() {
+            match component {
+                Component::Prefix(_) | Component::RootDir => lexical.push(component),
+                Component::CurDir => {}
+                Component::ParentDir if normal > 0 => {
+                    lexical.pop();
+                    normal -= 1;
+                }
+                Component::ParentDir if self.has_root() => {}
+                Component::ParentDir => lexical.push(component),
+                Component::Normal(_) => {
+                    lexical.push(component);
+                    normal += 1;
+                }
+            }
+        }
+        if lexical.as_os_str().is_empty() {
+            lexical.push(Component::CurDir);
+        }
+        lexical
+    }
+
+    /// Returns a relative path that, when joined onto `base`, refers to the
+    /// same location as `self`, without accessing the filesystem.
+    ///
+    /// Both paths are first normalized with [`normalize_lexically`], then the
+    /// result goes up from `base` with `..` components to the last directory
+    /// the paths have in common, and down to `self` from there. Components are
+    /// compared exactly, so on platforms with case-insensitive file names
+    /// paths differing only in case are considered different.
+    ///
+    /// The same caveat as for [`normalize_lexically`] applies: if `base` goes
+    /// through symbolic links, the relative path might not lead to `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if no such path can be computed lexically:
+    ///
+    /// * if one of the paths is absolute and the other is relative,
+    /// * on Windows, if the paths have different prefixes, e.g. are on
+    ///   different drives, or one of them is a verbatim path and the other is
+    ///   not,
+    /// * if `base` goes above the directory `self` is relative to, e.g. `self`
+    ///   is `a` and `base` is `../b`, as the name of that directory is unknown.
+    ///
+    /// [`normalize_lexically`]: Path::normalize_lexically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_relative_to)]
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/usr/include/stdio.h");
+    ///
+    /// assert_eq!(path.relative_to("/usr/include"), Ok("stdio.h".into()));
+    /// assert_eq!(path.relative_to("/usr/lib/"), Ok("../include/stdio.h".into()));
+    /// assert_eq!(path.relative_to("/usr/local/../lib"), Ok("../include/stdio.h".into()));
+    /// assert_eq!(path.relative_to("/usr/include/stdio.h"), Ok(".".into()));
+    ///
+    /// assert_eq!(Path::new("src/gen/a.rs").relative_to("src/lib"), Ok("../gen/a.rs".into()));
+    ///
+    /// assert!(path.relative_to("usr").is_err());
+    /// assert!(Path::new("a.rs").relative_to("../src").is_err());
+    /// ```
+    #[unstable(feature = "path_relative_to", issue = "none")]
+    pub fn relative_to<P: AsRef<Path>>(&self, base: P) -> Result<PathBuf, RelativePathError> {
+        self._relative_to(base.as_ref())
+    }
+
+    fn _relative_to(&self, base: &Path) -> Result<PathBuf, RelativePathError> {
+        let path = self.normalize_lexically();
+        let base = base.normalize_lexically();
+        if path.components().prefix != base.components().prefix
+            || path.has_root() != base.has_root()
+        {
+            return Err(RelativePathError(()));
+        }
+
+        let mut path_components = path.components().filter(|c| *c != Component::CurDir).peekable();
+        let mut base_components = base.components().filter(|c| *c != Component::CurDir).peekable();
+        while let (Some(a), Some(b)) = (path_components.peek(), base_components.peek()) {
+            if a != b {
+                break;
+            }
+            path_components.next();
+            base_components.next();
+        }
+
+        let mut relative = PathBuf::new();
+        for component in base_components {
+            match component {
+                Component::Normal(_) => relative.push(Component::ParentDir),
+                // After normalization, `..` can only be left at the start of a relative
+                // `base`, going up to a directory whose name is unknown.
+                _ => return Err(RelativePathError(())),
+            }
+        }
+        relative.extend(path_components);
+        if relative.as_os_str().is_empty() {
+            relative.push(Component::CurDir);
+        }
+        Ok(relative)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: Path::file_name
@@ -3312,6 +3462,16 @@ fn description(&self) -> &str {
     }
 }
 
+#[unstable(feature = "path_relative_to", issue = "none")]
+impl fmt::Display for RelativePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no relative path leads from the base path to the path")
+    }
+}
+
+#[unstable(feature = "path_relative_to", issue = "none")]
+impl Error for RelativePathError {}
+
 /// Makes the path absolute without accessing the filesystem.
 ///
 /// If the path is relative, the current directory is used as the base directory.
@@ -1805,6 +1805,79 @@ macro_rules! unchanged(
     assert_eq!(absolute(r"COM1").unwrap().as_os_str(), Path::new(r"\\.\COM1").as_os_str());
 }
 
+#[test]
+fn test_normalize_lexically() {
+    macro_rules! check {
+        ($path:expr, $expected:expr) => {
+            assert_eq!(
+                Path::new($path).normalize_lexically().as_os_str(),
+                Path::new($expected).as_os_str()
+            );
+        };
+    }
+
+    check!("a/b/c", "a/b/c");
+    check!("a/./b/../c", "a/c");
+    check!("a//b/.//c/", "a/b/c");
+    check!("./a", "a");
+    check!("a/..", ".");
+    check!("", ".");
+    check!("../a/../../b", "../../b");
+    check!("/a/../../b", "/b");
+    check!("/..", "/");
+
+    #[cfg(windows)]
+    {
+        check!(r"C:\..\..", r"C:\");
+        check!(r"C:a\..\..", r"C:..");
+        check!(r"C:\a\.\b\..\c", r"C:\a\c");
+        check!(r"\\server\share\..", r"\\server\share\");
+        check!(r"\\server\share\a\..\..\b", r"\\server\share\b");
+        check!(r"\\?\C:\..", r"\\?\C:\");
+    }
+}
+
+#[test]
+fn test_relative_to() {
+    macro_rules! check {
+        ($path:expr, $base:expr, $expected:expr) => {
+            assert_eq!(
+                Path::new($path).relative_to($base).as_deref().map(Path::as_os_str),
+                Ok(Path::new($expected).as_os_str())
+            );
+        };
+        ($path:expr, $base:expr) => {
+            assert_eq!(Path::new($path).relative_to($base), Err(RelativePathError(())));
+        };
+    }
+
+    check!("/a/b/c", "/a", "b/c");
+    check!("/a/b/c", "/a/d/e", "../../b/c");
+    check!("/a", "/a/b/c", "../..");
+    check!("/a/b", "/a/b/", ".");
+    check!("/a/./b/../c", "/a/d/..", "c");
+    check!("a/b", "c", "../a/b");
+    check!("a", "", "a");
+    check!("../a", "b", "../../a");
+    check!("../a", "../b", "../a");
+    check!("a", "../b");
+    check!("/a", "a");
+    check!("a", "/a");
+
+    #[cfg(windows)]
+    {
+        check!(r"C:\a\b", r"c:\c", r"..\a\b");
+        check!(r"C:a", r"c:b", r"..\a");
+        check!(r"C:", r"c:\c");
+        check!(r"C:", r"D:");
+        check!(r"C:", r"");
+        check!(r"C:", r"\\?\C:");
+        check!(r"\\server\share", r"\\server\share", r".");
+        check!(r"\\server\share\a", r"\\server\share\b\c", r"..\..\a");
+        check!(r"\\server\share\a", r"\\server\other\a");
+    }
+}
+
 #[bench]
 #[cfg_attr(miri, ignore)] // Miri isn't fast...
 fn bench_path_cmp_fast_path_buf_sort(b: &mut test::Bencher) {
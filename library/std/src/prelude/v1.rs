@@ -67,6 +67,13 @@
 #[unstable(feature = "derive_const", issue = "none")]
 pub use core::prelude::v1::derive_const;
 
+#[unstable(
+    feature = "test_fixture",
+    issue = "none",
+    reason = "`test_fixture` is an experimental extension of the test harness"
+)]
+pub use core::prelude::v1::test_fixture;
+
 // Do not `doc(no_inline)` either.
 #[unstable(
     feature = "cfg_accessible",
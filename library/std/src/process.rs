@@ -58,6 +58,10 @@
 //! assert_eq!(b"Oh no, a typo!\n", output.stdout.as_slice());
 //! ```
 //!
+//! A [`Pipeline`] connects the stdout of each of several commands to the stdin
+//! of the next one in the same way, and [`io::pipe`] creates pipes which are
+//! not tied to a single child process.
+//!
 //! Note that [`ChildStderr`] and [`ChildStdout`] implement [`Read`] and
 //! [`ChildStdin`] implements [`Write`]:
 //!
@@ -119,6 +123,7 @@
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::Duration;
 
 /// Representation of a running or exited child process.
 ///
@@ -1165,6 +1170,121 @@ fn is_empty(&self) -> bool {
     }
 }
 
+/// A pipeline of commands, where the stdout of each command is connected to
+/// the stdin of the next one, like `a | b | c` in a shell.
+///
+/// The commands are connected with [`io::pipe`], so the bytes go directly
+/// from one child to the next without being copied by the parent process.
+/// The stdin of the first command and the stdout of the last command are
+/// configured on those commands as usual, and so are the stderr of all the
+/// commands.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// use std::process::{Command, Pipeline, Stdio};
+///
+/// // Equivalent to `ls -l | grep .rs | wc -l`.
+/// let mut children = Pipeline::new()
+///     .command(Command::new("ls").arg("-l"))
+///     .command(Command::new("grep").arg(".rs"))
+///     .command(Command::new("wc").arg("-l").stdout(Stdio::piped()))
+///     .spawn()?;
+///
+/// let wc = children.pop().unwrap();
+/// let output = wc.wait_with_output()?;
+/// for mut child in children {
+///     child.wait()?;
+/// }
+/// println!("{} Rust files", String::from_utf8_lossy(&output.stdout).trim());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+#[derive(Debug, Default)]
+pub struct Pipeline<'a> {
+    commands: Vec<&'a mut Command>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Creates an empty pipeline.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn new() -> Pipeline<'a> {
+        Pipeline { commands: Vec::new() }
+    }
+
+    /// Appends a command to the pipeline, which reads the stdout of the
+    /// previous command.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn command(&mut self, command: &'a mut Command) -> &mut Pipeline<'a> {
+        self.commands.push(command);
+        self
+    }
+
+    /// Spawns all the commands of the pipeline, returning their children in
+    /// the same order.
+    ///
+    /// The stdin of every command but the first, and the stdout of every
+    /// command but the last, are replaced by the pipes between the commands,
+    /// and are reset to [`Stdio::inherit`] once the command was spawned.
+    ///
+    /// If a command fails to spawn, the error is returned and the children
+    /// which were already spawned are not waited for. They see the end of
+    /// their stdin, or a broken pipe on their stdout.
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn spawn(&mut self) -> io::Result<Vec<Child>> {
+        let last = self.commands.len().saturating_sub(1);
+        let mut children = Vec::with_capacity(self.commands.len());
+        let mut stdin: Option<io::PipeReader> = None;
+        for (i, command) in self.commands.iter_mut().enumerate() {
+            if let Some(reader) = stdin.take() {
+                command.stdin(reader);
+            }
+            if i < last {
+                let (reader, writer) = io::pipe()?;
+                command.stdout(writer);
+                stdin = Some(reader);
+            }
+            let child = command.spawn();
+            // Drop the ends of the pipes which were given to the child, so that the next
+            // command sees the end of its input once the previous ones exited.
+            if i > 0 {
+                command.stdin(Stdio::inherit());
+            }
+            if i < last {
+                command.stdout(Stdio::inherit());
+            }
+            children.push(child?);
+        }
+        Ok(children)
+    }
+
+    /// Spawns all the commands of the pipeline and waits for all of them to
+    /// exit, returning their exit statuses in the same order.
+    ///
+    /// Unlike a shell, which only reports the status of the last command,
+    /// this allows to check whether any of the commands failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::process::{Command, Pipeline};
+    ///
+    /// let statuses = Pipeline::new()
+    ///     .command(Command::new("cat").arg("Cargo.toml"))
+    ///     .command(Command::new("grep").arg("name"))
+    ///     .status()?;
+    ///
+    /// assert!(statuses.iter().all(|status| status.success()));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn status(&mut self) -> io::Result<Vec<ExitStatus>> {
+        self.spawn()?.iter_mut().map(Child::wait).collect()
+    }
+}
+
 /// The output of a finished process.
 ///
 /// This is returned in a Result by either the [`output`] method of a
@@ -1500,6 +1620,68 @@ fn from(file: fs::File) -> Stdio {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for Stdio {
+    /// Converts the reading end of a pipe into a [`Stdio`], to be used as the
+    /// stdin of a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Write};
+    /// use std::process::{Command, Stdio};
+    ///
+    /// let (reader, mut writer) = io::pipe()?;
+    ///
+    /// let reverse = Command::new("rev")
+    ///     .stdin(reader)  // Converted into a Stdio here
+    ///     .stdout(Stdio::piped())
+    ///     .spawn()?;
+    ///
+    /// writer.write_all(b"Hello, world!")?;
+    /// drop(writer);
+    ///
+    /// let output = reverse.wait_with_output()?;
+    /// assert_eq!(output.stdout, b"!dlrow ,olleH");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn from(pipe: io::PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for Stdio {
+    /// Converts the writing end of a pipe into a [`Stdio`], to be used as the
+    /// stdout or stderr of a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Read};
+    /// use std::process::Command;
+    ///
+    /// let (mut reader, writer) = io::pipe()?;
+    ///
+    /// // The stdout and stderr of the child are sent to the same pipe.
+    /// let mut child = Command::new("ls")
+    ///     .arg("non_existing_file.txt")
+    ///     .stdout(writer.try_clone()?)
+    ///     .stderr(writer)
+    ///     .spawn()?;
+    ///
+    /// let mut output = String::new();
+    /// reader.read_to_string(&mut output)?;
+    /// child.wait()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn from(pipe: io::PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
 #[stable(feature = "stdio_from_stdio", since = "1.74.0")]
 impl From<io::Stdout> for Stdio {
     /// Redirect command stdout/stderr to our stdout
@@ -2110,6 +2292,47 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit for at most `timeout`, returning its exit
+    /// status if it exited in time.
+    ///
+    /// If the child exits before the timeout elapses, then `Ok(Some(status))`
+    /// is returned and, on Unix, the process ID is reaped. If it is still
+    /// running after the timeout, then `Ok(None)` is returned and the child
+    /// keeps running. If an error occurs, then that error is returned.
+    ///
+    /// Like [`try_wait`], this function does not drop stdin, so that the
+    /// child can still be written to if the timeout elapses.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function uses a pidfd on Linux and `WaitForSingleObject` on
+    /// Windows. On other Unix platforms the child is polled with an
+    /// increasing interval, so the exit may be noticed with a small delay.
+    /// Note that this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`try_wait`]: Child::try_wait
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("100").spawn().unwrap();
+    ///
+    /// if child.wait_timeout(Duration::from_secs(1)).unwrap().is_none() {
+    ///     println!("sleep is hung, killing it");
+    ///     child.kill().unwrap();
+    ///     child.wait().unwrap();
+    /// }
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.wait_timeout(timeout)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
@@ -247,6 +247,82 @@ fn test_wait_with_output_once() {
     assert_eq!(stderr, Vec::new());
 }
 
+#[test]
+#[cfg_attr(target_os = "vxworks", ignore)]
+fn test_wait_timeout() {
+    use crate::time::Duration;
+
+    let mut prog = if cfg!(target_os = "windows") {
+        // Run directly rather than through `cmd`, which would leave it running once killed.
+        Command::new("ping")
+            .args(&["-n", "1000", "127.0.0.1"])
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("sleep 1000").spawn().unwrap()
+    };
+    assert!(prog.wait_timeout(Duration::from_millis(100)).unwrap().is_none());
+    prog.kill().unwrap();
+    let status = prog.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+    assert!(!status.success());
+
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "exit 3"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("exit 3").spawn().unwrap()
+    };
+    let status = prog.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+    assert_eq!(status.code(), Some(3));
+    assert_eq!(prog.wait_timeout(Duration::ZERO).unwrap().unwrap().code(), Some(3));
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_stdio_from_pipe() {
+    use crate::io;
+
+    let (mut reader, writer) = io::pipe().unwrap();
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "echo hello"]).stdout(writer).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("echo hello").stdout(writer).spawn().unwrap()
+    };
+
+    let mut output = String::new();
+    reader.read_to_string(&mut output).unwrap();
+    assert!(prog.wait().unwrap().success());
+    assert_eq!(output.trim(), "hello");
+}
+
+#[test]
+#[cfg_attr(any(windows, target_os = "vxworks"), ignore)]
+fn test_pipeline() {
+    use super::Pipeline;
+
+    let mut children = Pipeline::new()
+        .command(shell_cmd().arg("-c").arg("echo hello; echo world"))
+        .command(shell_cmd().arg("-c").arg("read a; read b; echo $b $a"))
+        .command(shell_cmd().arg("-c").arg("cat; exit 3").stdout(Stdio::piped()))
+        .spawn()
+        .unwrap();
+    assert_eq!(children.len(), 3);
+
+    let Output { status, stdout, .. } = children.pop().unwrap().wait_with_output().unwrap();
+    assert_eq!(status.code(), Some(3));
+    assert_eq!(str::from_utf8(&stdout).unwrap().trim(), "world hello");
+    for mut child in children {
+        assert!(child.wait().unwrap().success());
+    }
+
+    let statuses = Pipeline::new()
+        .command(shell_cmd().arg("-c").arg("exit 1"))
+        .command(shell_cmd().arg("-c").arg("cat"))
+        .status()
+        .unwrap();
+    assert_eq!(statuses.iter().map(|s| s.code()).collect::<Vec<_>>(), [Some(1), Some(0)]);
+}
+
 #[cfg(all(unix, not(target_os = "android")))]
 pub fn env_cmd() -> Command {
     Command::new("env")
@@ -1,3 +1,10 @@
+use super::context::Context;
+use super::error::{ReadyTimeoutError, TryReadyError};
+use super::{Receiver, ReceiverFlavor};
+
+use crate::fmt;
+use crate::time::{Duration, Instant};
+
 /// Temporary data that gets initialized during a blocking operation, and is consumed by
 /// `read` or `write`.
 ///
@@ -69,3 +76,217 @@ fn into(self) -> usize {
         }
     }
 }
+
+/// A receive operation which can be waited on by a [`Select`].
+trait SelectHandle {
+    /// Returns `true` if the operation can proceed without blocking.
+    fn is_ready(&self) -> bool;
+
+    /// Registers an operation to be selected once this one is ready.
+    fn watch(&self, oper: Operation, cx: &Context);
+
+    /// Unregisters an operation registered with `watch`.
+    fn unwatch(&self, oper: Operation);
+}
+
+impl<T> SelectHandle for Receiver<T> {
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.is_ready_to_recv(),
+            ReceiverFlavor::List(chan) => chan.is_ready_to_recv(),
+            ReceiverFlavor::Zero(chan) => chan.is_ready_to_recv(),
+        }
+    }
+
+    fn watch(&self, oper: Operation, cx: &Context) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.watch_recv(oper, cx),
+            ReceiverFlavor::List(chan) => chan.watch_recv(oper, cx),
+            ReceiverFlavor::Zero(chan) => chan.watch_recv(oper, cx),
+        }
+    }
+
+    fn unwatch(&self, oper: Operation) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.unwatch_recv(oper),
+            ReceiverFlavor::List(chan) => chan.unwatch_recv(oper),
+            ReceiverFlavor::Zero(chan) => chan.unwatch_recv(oper),
+        }
+    }
+}
+
+/// Waits on several receive operations at once.
+///
+/// Receivers are added with [`recv`], which returns the index of the operation. The `ready`
+/// family of methods then blocks until at least one of the receivers is ready, and returns its
+/// index: a message can be received from it, or it is disconnected.
+///
+/// Another thread receiving from the same channel may take the message first, so the following
+/// [`try_recv`] can still fail with [`TryRecvError::Empty`], in which case the caller should wait
+/// again.
+///
+/// When several operations are ready, they are selected in turn, so that no receiver is starved.
+///
+/// [`recv`]: Select::recv
+/// [`try_recv`]: Receiver::try_recv
+/// [`TryRecvError::Empty`]: super::TryRecvError::Empty
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+///
+/// use std::sync::mpmc::{channel, Select};
+/// use std::thread;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel();
+///
+/// thread::spawn(move || tx1.send(1).unwrap());
+/// thread::spawn(move || tx2.send("two").unwrap());
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&rx1);
+/// let oper2 = sel.recv(&rx2);
+///
+/// let mut received = 0;
+/// while received < 2 {
+///     match sel.ready() {
+///         i if i == oper1 => match rx1.try_recv() {
+///             Ok(n) => assert_eq!(n, 1),
+///             Err(_) => continue,
+///         },
+///         i if i == oper2 => match rx2.try_recv() {
+///             Ok(s) => assert_eq!(s, "two"),
+///             Err(_) => continue,
+///         },
+///         _ => unreachable!(),
+///     }
+///     received += 1;
+/// }
+/// ```
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub struct Select<'a> {
+    /// The added operations, `None` once removed so that the other indices stay valid.
+    handles: Vec<Option<&'a dyn SelectHandle>>,
+
+    /// The index of the operation checked first by the next call, rotated for fairness.
+    next: usize,
+}
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of operations to wait on.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn new() -> Select<'a> {
+        Select { handles: Vec::new(), next: 0 }
+    }
+
+    /// Adds a receive operation, and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        self.handles.push(Some(r));
+        self.handles.len() - 1
+    }
+
+    /// Removes a previously added operation, e.g. once its channel is disconnected.
+    ///
+    /// The indices of the other operations do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is invalid or the operation was already removed.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        match self.handles.get_mut(index) {
+            Some(handle @ Some(_)) => *handle = None,
+            _ => panic!("no operation has been added with index {index}"),
+        }
+    }
+
+    /// Attempts to find a ready operation without blocking, and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn try_ready(&mut self) -> Result<usize, TryReadyError> {
+        self.find_ready().ok_or(TryReadyError)
+    }
+
+    /// Blocks until one of the operations is ready, and returns its index.
+    ///
+    /// If no operations were added, or all of them were removed, this blocks forever.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready(&mut self) -> usize {
+        self.wait_until(None).unwrap()
+    }
+
+    /// Blocks for a limited time until one of the operations is ready, and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, ReadyTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.ready_deadline(deadline),
+            // So far in the future that it's practically the same as waiting indefinitely.
+            None => Ok(self.ready()),
+        }
+    }
+
+    /// Blocks until one of the operations is ready or the deadline is reached, and returns the
+    /// index of the ready operation.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_deadline(&mut self, deadline: Instant) -> Result<usize, ReadyTimeoutError> {
+        self.wait_until(Some(deadline)).ok_or(ReadyTimeoutError)
+    }
+
+    /// Returns the index of a ready operation, starting from `next`.
+    fn find_ready(&mut self) -> Option<usize> {
+        let len = self.handles.len();
+        let start = self.next;
+        let index = (0..len)
+            .map(|i| (start + i) % len)
+            .find(|&i| self.handles[i].is_some_and(|handle| handle.is_ready()))?;
+        self.next = (index + 1) % len;
+        Some(index)
+    }
+
+    fn wait_until(&mut self, deadline: Option<Instant>) -> Option<usize> {
+        loop {
+            if let Some(index) = self.find_ready() {
+                return Some(index);
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return None;
+            }
+
+            Context::with(|cx| {
+                let oper = Operation::hook(self);
+                for handle in self.handles.iter().flatten() {
+                    handle.watch(oper, cx);
+                }
+
+                // An operation may have become ready before it was watched.
+                if self.handles.iter().flatten().any(|handle| handle.is_ready()) {
+                    let _ = cx.try_select(Selected::Aborted);
+                }
+
+                // Block until one of the channels notifies us, then look again for the ready
+                // operation.
+                cx.wait_until(deadline);
+
+                for handle in self.handles.iter().flatten() {
+                    handle.unwatch(oper);
+                }
+            });
+        }
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl Default for Select<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn smoke() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+}
+
+#[test]
+fn cloned_receivers_share_messages() {
+    let (tx, rx) = sync_channel::<usize>(4);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let rx = rx.clone();
+            thread::spawn(move || rx.iter().count())
+        })
+        .collect();
+    drop(rx);
+
+    for i in 0..100 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+
+    let received: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    assert_eq!(received, 100);
+}
+
+#[test]
+fn try_iter() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(rx.try_iter().next(), None);
+}
+
+#[test]
+fn select_empty() {
+    let mut sel = Select::new();
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+    assert_eq!(sel.ready_timeout(Duration::from_millis(1)), Err(ReadyTimeoutError));
+}
+
+#[test]
+fn select_try_ready() {
+    let (tx1, rx1) = channel::<i32>();
+    let (_tx2, rx2) = channel::<i32>();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let _oper2 = sel.recv(&rx2);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    tx1.send(1).unwrap();
+    assert_eq!(sel.try_ready(), Ok(oper1));
+    assert_eq!(rx1.try_recv(), Ok(1));
+}
+
+#[test]
+fn select_blocks_until_send() {
+    let (_tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = sync_channel::<i32>(1);
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        tx2.send(2).unwrap();
+    });
+
+    let mut sel = Select::new();
+    let _oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    assert_eq!(sel.ready(), oper2);
+    assert_eq!(rx2.try_recv(), Ok(2));
+    t.join().unwrap();
+}
+
+#[test]
+fn select_timeout() {
+    let (_tx, rx) = channel::<i32>();
+
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    let start = Instant::now();
+    assert_eq!(sel.ready_timeout(Duration::from_millis(50)), Err(ReadyTimeoutError));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn select_disconnected() {
+    let (tx, rx) = channel::<i32>();
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(tx);
+    });
+
+    let mut sel = Select::new();
+    let oper = sel.recv(&rx);
+    assert_eq!(sel.ready(), oper);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+    t.join().unwrap();
+}
+
+#[test]
+fn select_zero_capacity() {
+    let (tx, rx) = sync_channel::<i32>(0);
+
+    let t = thread::spawn(move || tx.send(7).unwrap());
+
+    let mut sel = Select::new();
+    let oper = sel.recv(&rx);
+    loop {
+        assert_eq!(sel.ready(), oper);
+        match rx.try_recv() {
+            Ok(n) => break assert_eq!(n, 7),
+            Err(TryRecvError::Empty) => continue,
+            Err(TryRecvError::Disconnected) => panic!("sender disconnected"),
+        }
+    }
+    t.join().unwrap();
+}
+
+#[test]
+fn select_is_fair() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    tx1.send(1).unwrap();
+    tx2.send(2).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    assert_eq!(sel.try_ready(), Ok(oper1));
+    assert_eq!(sel.try_ready(), Ok(oper2));
+}
+
+#[test]
+fn select_remove() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    tx1.send(1).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    sel.remove(oper1);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    tx2.send(2).unwrap();
+    assert_eq!(sel.try_ready(), Ok(oper2));
+}
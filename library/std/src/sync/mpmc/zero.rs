@@ -315,4 +315,21 @@ pub(crate) fn is_empty(&self) -> bool {
     pub(crate) fn is_full(&self) -> bool {
         true
     }
+
+    /// Returns `true` if a receive operation can proceed without blocking, because a sender is
+    /// waiting or the channel is disconnected.
+    pub(crate) fn is_ready_to_recv(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.senders.can_select() || inner.is_disconnected
+    }
+
+    /// Registers an operation waiting for a receive operation to be ready.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) {
+        self.inner.lock().unwrap().receivers.watch(oper, cx);
+    }
+
+    /// Unregisters an operation registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        self.inner.lock().unwrap().receivers.unwatch(oper);
+    }
 }
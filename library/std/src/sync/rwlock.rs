@@ -3,9 +3,10 @@
 
 use crate::cell::UnsafeCell;
 use crate::fmt;
+use crate::mem::ManuallyDrop;
 use crate::ops::{Deref, DerefMut};
 use crate::ptr::NonNull;
-use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
+use crate::sync::{poison, LockResult, PoisonError, TryLockError, TryLockResult};
 use crate::sys::locks as sys;
 
 /// A reader-writer lock
@@ -144,6 +145,33 @@ impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
 #[stable(feature = "rwlock_guard_sync", since = "1.23.0")]
 unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 
+/// RAII structure used to release the upgradable read access of a lock when
+/// dropped.
+///
+/// This structure is created by the [`upgradable_read`] and
+/// [`try_upgradable_read`] methods on [`RwLock`]. It can be turned into a
+/// [`RwLockWriteGuard`] with [`upgrade`] or [`try_upgrade`].
+///
+/// [`upgradable_read`]: RwLock::upgradable_read
+/// [`try_upgradable_read`]: RwLock::try_upgradable_read
+/// [`upgrade`]: RwLockUpgradableReadGuard::upgrade
+/// [`try_upgrade`]: RwLockUpgradableReadGuard::try_upgrade
+#[must_use = "if unused the RwLock will immediately unlock"]
+#[must_not_suspend = "holding a RwLockUpgradableReadGuard across suspend \
+                      points can cause deadlocks, delays, \
+                      and cause Futures to not implement `Send`"]
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+#[clippy::has_significant_drop]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> !Send for RwLockUpgradableReadGuard<'_, T> {}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -349,6 +377,106 @@ pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         }
     }
 
+    /// Locks this `RwLock` with upgradable read access, blocking the current
+    /// thread until it can be acquired.
+    ///
+    /// An upgradable read lock is a shared read lock which can later be turned
+    /// into an exclusive write lock with [`RwLockUpgradableReadGuard::upgrade`],
+    /// without any writer acquiring the lock in between. This allows checking
+    /// the data under the lock and only modifying it when necessary, without
+    /// the checked data being changed in the meantime.
+    ///
+    /// Other readers can hold the lock along with the upgradable reader, but
+    /// at most one thread can hold an upgradable read lock at a time, so the
+    /// calling thread will also be blocked while another thread holds one.
+    ///
+    /// Returns an RAII guard which will release this thread's access once it
+    /// is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the `RwLock` is poisoned. An
+    /// `RwLock` is poisoned whenever a writer panics while holding an exclusive
+    /// lock. The failure will occur immediately after the lock has been
+    /// acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when called if the lock is already held by the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(Vec::new());
+    ///
+    /// let data = lock.upgradable_read().unwrap();
+    /// if data.is_empty() {
+    ///     let mut data = RwLockUpgradableReadGuard::upgrade(data);
+    ///     data.push(1);
+    /// }
+    ///
+    /// assert_eq!(*lock.read().unwrap(), [1]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            self.inner.upgradable_read();
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
+    /// Attempts to acquire this `RwLock` with upgradable read access.
+    ///
+    /// If the access could not be granted at this time, then `Err` is returned.
+    /// Otherwise, an RAII guard is returned which will release the access when
+    /// it is dropped.
+    ///
+    /// This function does not block.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the `RwLock` is
+    /// poisoned. An `RwLock` is poisoned whenever a writer panics while holding
+    /// an exclusive lock. `Poisoned` will only be returned if the lock would
+    /// have otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the `RwLock` could
+    /// not be acquired because it was already locked exclusively, or because
+    /// another thread holds an upgradable read lock.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    /// use std::sync::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.try_upgradable_read().unwrap();
+    /// assert_eq!(*n, 1);
+    ///
+    /// assert!(lock.try_upgradable_read().is_err());
+    /// assert!(lock.try_read().is_ok());
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn try_upgradable_read(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_upgradable_read() {
+                Ok(RwLockUpgradableReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the lock is poisoned.
     ///
     /// If another thread is active, the lock can still become poisoned at any
@@ -529,6 +657,154 @@ impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
     unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockWriteGuard<'rwlock, T>> {
         poison::map_result(lock.poison.guard(), |guard| RwLockWriteGuard { lock, poison: guard })
     }
+
+    /// Create a new instance of `RwLockWriteGuard<T>` for an upgraded lock.
+    // SAFETY: if and only if `lock.inner.upgrade()` (or `lock.inner.try_upgrade()`) has been
+    // successfully called from the same thread before instantiating this object.
+    unsafe fn upgraded(lock: &'rwlock RwLock<T>) -> RwLockWriteGuard<'rwlock, T> {
+        // The poison was already reported when the upgradable read lock was acquired, and no
+        // writer could have poisoned the lock since then.
+        let poison = lock.poison.guard().unwrap_or_else(PoisonError::into_inner);
+        RwLockWriteGuard { lock, poison }
+    }
+
+    /// Atomically turns the exclusive write access into shared read access,
+    /// without releasing the lock.
+    ///
+    /// No other writer can acquire the lock in between, so the data seen
+    /// through the read guard is the data written through the write guard.
+    /// Readers waiting for the lock may acquire it along with the returned
+    /// guard.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockWriteGuard::downgrade(...)`. A method would interfere with
+    /// methods of the same name on the contents of the lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_downgrade)]
+    /// use std::sync::{RwLock, RwLockWriteGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let mut n = lock.write().unwrap();
+    /// *n += 1;
+    ///
+    /// let n = RwLockWriteGuard::downgrade(n);
+    /// assert_eq!(*n, 2);
+    /// assert!(lock.try_read().is_ok());
+    /// assert!(lock.try_write().is_err());
+    /// ```
+    #[unstable(feature = "rwlock_downgrade", issue = "none")]
+    pub fn downgrade(s: Self) -> RwLockReadGuard<'rwlock, T> {
+        let s = ManuallyDrop::new(s);
+        let lock = s.lock;
+        lock.poison.done(&s.poison);
+        // SAFETY: the conditions of `RwLockWriteGuard::new` were satisfied when created,
+        // and the write lock is not released when `s` is not dropped.
+        unsafe {
+            lock.inner.downgrade();
+            RwLockReadGuard {
+                data: NonNull::new_unchecked(lock.data.get()),
+                inner_lock: &lock.inner,
+            }
+        }
+    }
+}
+
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    /// Create a new instance of `RwLockUpgradableReadGuard<T>` from a `RwLock<T>`.
+    // SAFETY: if and only if `lock.inner.upgradable_read()` (or
+    // `lock.inner.try_upgradable_read()`) has been successfully called from the same thread
+    // before instantiating this object.
+    unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |()| RwLockUpgradableReadGuard { lock })
+    }
+
+    /// Atomically turns the upgradable read access into exclusive write
+    /// access, blocking the current thread until all the other readers
+    /// released the lock.
+    ///
+    /// No writer can acquire the lock between the upgradable read access and
+    /// the write access, so the data is the same as it was seen through the
+    /// upgradable read guard. New readers wait until the write access is
+    /// released.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::upgrade(...)`. A method would interfere
+    /// with methods of the same name on the contents of the lock.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic, or never return, if the current thread
+    /// holds another read lock on the same `RwLock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.upgradable_read().unwrap();
+    /// let mut n = RwLockUpgradableReadGuard::upgrade(n);
+    /// *n += 1;
+    /// drop(n);
+    ///
+    /// assert_eq!(*lock.read().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn upgrade(orig: Self) -> RwLockWriteGuard<'rwlock, T> {
+        let orig = ManuallyDrop::new(orig);
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created,
+        // and the upgradable read lock is not released when `orig` is not dropped.
+        unsafe {
+            orig.lock.inner.upgrade();
+            RwLockWriteGuard::upgraded(orig.lock)
+        }
+    }
+
+    /// Attempts to atomically turn the upgradable read access into exclusive
+    /// write access.
+    ///
+    /// If other readers hold the lock, the upgradable read guard is returned
+    /// unchanged in `Err`.
+    ///
+    /// This function does not block.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::try_upgrade(...)`. A method would interfere
+    /// with methods of the same name on the contents of the lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let n = lock.upgradable_read().unwrap();
+    /// let r = lock.read().unwrap();
+    /// let n = RwLockUpgradableReadGuard::try_upgrade(n).unwrap_err();
+    /// drop(r);
+    /// let mut n = RwLockUpgradableReadGuard::try_upgrade(n).unwrap();
+    /// *n += 1;
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn try_upgrade(orig: Self) -> Result<RwLockWriteGuard<'rwlock, T>, Self> {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created.
+        unsafe {
+            if orig.lock.inner.try_upgrade() {
+                let orig = ManuallyDrop::new(orig);
+                Ok(RwLockWriteGuard::upgraded(orig.lock))
+            } else {
+                Err(orig)
+            }
+        }
+    }
 }
 
 #[stable(feature = "std_debug", since = "1.16.0")]
@@ -559,6 +835,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
     type Target = T;
@@ -579,6 +869,16 @@ fn deref(&self) -> &T {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Deref for RwLockUpgradableReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
     fn deref_mut(&mut self) -> &mut T {
@@ -607,3 +907,13 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Drop for RwLockUpgradableReadGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created.
+        unsafe {
+            self.lock.inner.upgradable_read_unlock();
+        }
+    }
+}
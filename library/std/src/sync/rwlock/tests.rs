@@ -1,6 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, RwLockReadGuard, TryLockError};
+use crate::sync::{
+    Arc, RwLock, RwLockReadGuard, RwLockUpgradableReadGuard, RwLockWriteGuard, TryLockError,
+};
 use crate::thread;
 use rand::Rng;
 
@@ -257,3 +259,73 @@ fn do_stuff<'a>(_: RwLockReadGuard<'_, &'a i32>, _: &'a i32) {}
     }
     drop(lock);
 }
+
+#[test]
+fn test_downgrade() {
+    let lock = RwLock::new(1);
+    let mut w = lock.write().unwrap();
+    *w = 2;
+    let r = RwLockWriteGuard::downgrade(w);
+    assert_eq!(*r, 2);
+    assert_eq!(*lock.try_read().unwrap(), 2);
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+    drop(r);
+    drop(lock.try_write().unwrap());
+}
+
+#[test]
+fn test_upgradable_read() {
+    let lock = RwLock::new(1);
+    let u = lock.upgradable_read().unwrap();
+    let r = lock.try_read().unwrap();
+    assert!(matches!(lock.try_upgradable_read(), Err(TryLockError::WouldBlock)));
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+
+    let u = RwLockUpgradableReadGuard::try_upgrade(u).unwrap_err();
+    drop(r);
+    let mut w = RwLockUpgradableReadGuard::try_upgrade(u).unwrap();
+    *w = 2;
+    assert!(matches!(lock.try_read(), Err(TryLockError::WouldBlock)));
+    drop(w);
+
+    let u = lock.try_upgradable_read().unwrap();
+    assert_eq!(*u, 2);
+    drop(u);
+    drop(lock.try_write().unwrap());
+}
+
+#[test]
+fn test_upgrade_is_atomic() {
+    const N: usize = 10;
+    const M: usize = if cfg!(miri) { 20 } else { 200 };
+
+    // Every thread only increments the value if it was not changed between the
+    // check and the write, which never happens as the upgrade is atomic.
+    let lock = Arc::new(RwLock::new(0));
+    let threads: Vec<_> = (0..N)
+        .map(|i| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..M {
+                    if i % 2 == 0 {
+                        let u = lock.upgradable_read().unwrap();
+                        let seen = *u;
+                        let mut w = RwLockUpgradableReadGuard::upgrade(u);
+                        assert_eq!(*w, seen);
+                        *w += 1;
+                    } else {
+                        let mut w = lock.write().unwrap();
+                        *w += 1;
+                        let r = RwLockWriteGuard::downgrade(w);
+                        drop(r);
+                        drop(lock.read().unwrap());
+                    }
+                }
+            })
+        })
+        .collect();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), N * M);
+}
@@ -0,0 +1,192 @@
+//! Upgradable reads and downgrading for the platforms whose read-write locks
+//! don't support them natively.
+//!
+//! The upgradable reader, and a writer while it downgrades, hold a mutex and
+//! set `exclusive`. A writer which acquires the lock while `exclusive` is set
+//! might have gotten in between the holder releasing its read lock and
+//! acquiring the write lock, or the other way around, so it releases the lock
+//! again and waits for the mutex before retrying. Only other readers can
+//! acquire the lock in the meantime, which can't observe the difference.
+//!
+//! Plain writers don't touch the mutex unless they have to back off. Nobody
+//! holds the mutex while waiting for a writer which keeps the lock, so a writer
+//! can lock it to downgrade without deadlocking.
+
+// Locking the mutex is unsafe on some platforms.
+#![allow(unused_unsafe)]
+
+use crate::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use crate::sys::locks::Mutex;
+
+/// A read-write lock provided by the platform.
+pub trait RawRwLock {
+    const INIT: Self;
+
+    fn read(&self);
+    fn try_read(&self) -> bool;
+    fn write(&self);
+    fn try_write(&self) -> bool;
+    unsafe fn read_unlock(&self);
+    unsafe fn write_unlock(&self);
+}
+
+pub struct UpgradableRwLock<R> {
+    raw: R,
+    mutex: Mutex,
+    // Only changed while holding `mutex`. The accesses are ordered by the
+    // platform lock: whoever sets it releases its lock afterwards, and writers
+    // check it after acquiring the lock.
+    exclusive: AtomicBool,
+    // Whether the holder of the write lock is an upgraded reader, and so also
+    // holds `mutex`. Only accessed by the holder of the write lock.
+    upgraded: AtomicBool,
+}
+
+impl<R: RawRwLock> UpgradableRwLock<R> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            raw: R::INIT,
+            mutex: Mutex::new(),
+            exclusive: AtomicBool::new(false),
+            upgraded: AtomicBool::new(false),
+        }
+    }
+
+    /// Blocks until the current holder of the mutex is done with it.
+    #[inline]
+    fn wait_for_mutex(&self) {
+        unsafe {
+            self.mutex.lock();
+            self.mutex.unlock();
+        }
+    }
+
+    #[inline]
+    pub fn read(&self) {
+        self.raw.read()
+    }
+
+    #[inline]
+    pub fn try_read(&self) -> bool {
+        self.raw.try_read()
+    }
+
+    #[inline]
+    pub fn write(&self) {
+        loop {
+            self.raw.write();
+            if !self.exclusive.load(Relaxed) {
+                return;
+            }
+            unsafe { self.raw.write_unlock() };
+            self.wait_for_mutex();
+        }
+    }
+
+    #[inline]
+    pub fn try_write(&self) -> bool {
+        if !self.raw.try_write() {
+            return false;
+        }
+        if self.exclusive.load(Relaxed) {
+            unsafe { self.raw.write_unlock() };
+            return false;
+        }
+        true
+    }
+
+    #[inline]
+    pub unsafe fn read_unlock(&self) {
+        self.raw.read_unlock()
+    }
+
+    #[inline]
+    pub unsafe fn write_unlock(&self) {
+        if self.upgraded.load(Relaxed) {
+            self.upgraded.store(false, Relaxed);
+            self.exclusive.store(false, Relaxed);
+            self.raw.write_unlock();
+            self.mutex.unlock();
+        } else {
+            self.raw.write_unlock();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn downgrade(&self) {
+        if self.upgraded.load(Relaxed) {
+            self.upgraded.store(false, Relaxed);
+        } else {
+            // Anyone else holding the mutex releases it without waiting for us.
+            self.mutex.lock();
+            self.exclusive.store(true, Relaxed);
+        }
+        self.raw.write_unlock();
+        self.raw.read();
+        self.exclusive.store(false, Relaxed);
+        self.mutex.unlock();
+    }
+
+    #[inline]
+    pub fn upgradable_read(&self) {
+        loop {
+            unsafe { self.mutex.lock() };
+            self.exclusive.store(true, Relaxed);
+            if self.raw.try_read() {
+                return;
+            }
+            self.exclusive.store(false, Relaxed);
+            unsafe { self.mutex.unlock() };
+            // Wait for the writer without holding the mutex, as it might want
+            // to downgrade.
+            self.raw.read();
+            unsafe { self.raw.read_unlock() };
+        }
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        if !unsafe { self.mutex.try_lock() } {
+            return false;
+        }
+        self.exclusive.store(true, Relaxed);
+        if self.raw.try_read() {
+            true
+        } else {
+            self.exclusive.store(false, Relaxed);
+            unsafe { self.mutex.unlock() };
+            false
+        }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.exclusive.store(false, Relaxed);
+        self.raw.read_unlock();
+        self.mutex.unlock();
+    }
+
+    /// Turns the upgradable read lock into a write lock, which is then
+    /// released with `write_unlock`.
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        self.raw.read_unlock();
+        self.raw.write();
+        self.upgraded.store(true, Relaxed);
+    }
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        self.raw.read_unlock();
+        if self.raw.try_write() {
+            self.upgraded.store(true, Relaxed);
+            true
+        } else {
+            // Writers back off while `exclusive` is set, so this doesn't wait
+            // for long.
+            self.raw.read();
+            false
+        }
+    }
+}
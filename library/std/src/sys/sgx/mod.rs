@@ -44,7 +44,9 @@
 pub mod locks {
     pub use super::condvar::*;
     pub use super::mutex::*;
-    pub use super::rwlock::*;
+
+    pub type RwLock =
+        crate::sys::common::upgradable_rwlock::UpgradableRwLock<super::rwlock::RwLock>;
 }
 
 // SAFETY: must be called only once during runtime initialization.
@@ -2,6 +2,7 @@
 mod tests;
 
 use crate::num::NonZeroUsize;
+use crate::sys::common::upgradable_rwlock::RawRwLock;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
 
 use super::waitqueue::{
@@ -186,6 +187,40 @@ unsafe fn unlock(&self) {
     }
 }
 
+impl RawRwLock for RwLock {
+    const INIT: RwLock = RwLock::new();
+
+    #[inline]
+    fn read(&self) {
+        RwLock::read(self)
+    }
+
+    #[inline]
+    fn try_read(&self) -> bool {
+        unsafe { RwLock::try_read(self) }
+    }
+
+    #[inline]
+    fn write(&self) {
+        RwLock::write(self)
+    }
+
+    #[inline]
+    fn try_write(&self) -> bool {
+        RwLock::try_write(self)
+    }
+
+    #[inline]
+    unsafe fn read_unlock(&self) {
+        RwLock::read_unlock(self)
+    }
+
+    #[inline]
+    unsafe fn write_unlock(&self) {
+        RwLock::write_unlock(self)
+    }
+}
+
 // The following functions are needed by libunwind. These symbols are named
 // in pre-link args for the target specification, so keep that in sync.
 #[cfg(not(test))]
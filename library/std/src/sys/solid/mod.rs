@@ -51,7 +51,9 @@ mod itron {
 pub mod locks {
     pub use super::itron::condvar::*;
     pub use super::itron::mutex::*;
-    pub use super::rwlock::*;
+
+    pub type RwLock =
+        crate::sys::common::upgradable_rwlock::UpgradableRwLock<super::rwlock::RwLock>;
 }
 
 // SAFETY: must be called only once during runtime initialization.
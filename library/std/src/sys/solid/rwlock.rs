@@ -6,6 +6,7 @@
         spin::SpinIdOnceCell,
     },
 };
+use crate::sys::common::upgradable_rwlock::RawRwLock;
 
 pub struct RwLock {
     /// The ID of the underlying mutex object
@@ -83,6 +84,40 @@ pub unsafe fn write_unlock(&self) {
     }
 }
 
+impl RawRwLock for RwLock {
+    const INIT: RwLock = RwLock::new();
+
+    #[inline]
+    fn read(&self) {
+        RwLock::read(self)
+    }
+
+    #[inline]
+    fn try_read(&self) -> bool {
+        RwLock::try_read(self)
+    }
+
+    #[inline]
+    fn write(&self) {
+        RwLock::write(self)
+    }
+
+    #[inline]
+    fn try_write(&self) -> bool {
+        RwLock::try_write(self)
+    }
+
+    #[inline]
+    unsafe fn read_unlock(&self) {
+        RwLock::read_unlock(self)
+    }
+
+    #[inline]
+    unsafe fn write_unlock(&self) {
+        RwLock::write_unlock(self)
+    }
+}
+
 impl Drop for RwLock {
     #[inline]
     fn drop(&mut self) {
@@ -1080,6 +1080,13 @@ pub fn mode(&mut self, mode: u32) {
         self.mode = mode as mode_t;
     }
 
+    fn get_flags(&self) -> io::Result<c_int> {
+        Ok(libc::O_CLOEXEC
+            | self.get_access_mode()?
+            | self.get_creation_mode()?
+            | (self.custom_flags as c_int & !libc::O_ACCMODE))
+    }
+
     fn get_access_mode(&self) -> io::Result<c_int> {
         match (self.read, self.write, self.append) {
             (true, false, false) => Ok(libc::O_RDONLY),
@@ -1122,10 +1129,7 @@ pub fn open(path: &Path, opts: &OpenOptions) -> io::Result<File> {
     }
 
     pub fn open_c(path: &CStr, opts: &OpenOptions) -> io::Result<File> {
-        let flags = libc::O_CLOEXEC
-            | opts.get_access_mode()?
-            | opts.get_creation_mode()?
-            | (opts.custom_flags as c_int & !libc::O_ACCMODE);
+        let flags = opts.get_flags()?;
         // The third argument of `open64` is documented to have type `mode_t`. On
         // some platforms (like macOS, where `open64` is actually `open`), `mode_t` is `u16`.
         // However, since this is a variadic function, C integer promotion rules mean that on
@@ -1388,6 +1392,97 @@ pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
     }
 }
 
+// Advisory whole-file locks, using `flock` where it is available
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "hurd",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "tvos",
+    target_os = "watchos",
+))]
+impl File {
+    pub fn lock(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_EX) })?;
+        Ok(())
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_SH) })?;
+        Ok(())
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_EX)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_SH)
+    }
+
+    fn try_flock(&self, operation: c_int) -> io::Result<bool> {
+        match cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation | libc::LOCK_NB) }) {
+            Ok(_) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_UN) })?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "hurd",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "tvos",
+    target_os = "watchos",
+)))]
+impl File {
+    const LOCK_UNSUPPORTED: io::Error = io::const_io_error!(
+        io::ErrorKind::Unsupported,
+        "file locking is not supported on this platform",
+    );
+
+    pub fn lock(&self) -> io::Result<()> {
+        Err(Self::LOCK_UNSUPPORTED)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(Self::LOCK_UNSUPPORTED)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        Err(Self::LOCK_UNSUPPORTED)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        Err(Self::LOCK_UNSUPPORTED)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(Self::LOCK_UNSUPPORTED)
+    }
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder { mode: 0o777 }
@@ -1948,6 +2043,7 @@ pub fn chroot(dir: &Path) -> io::Result<()> {
     run_path_with_cstr(dir, |dir| cvt(unsafe { libc::chroot(dir.as_ptr()) }).map(|_| ()))
 }
 
+pub use dir_impl::DirHandle;
 pub use remove_dir_impl::remove_dir_all;
 
 // Fallback for REDOX, ESP-ID, Horizon, Vita and Miri
@@ -2160,3 +2256,194 @@ pub fn remove_dir_all(p: &Path) -> io::Result<()> {
         }
     }
 }
+
+// Fallback for the same platforms as `remove_dir_impl`, which lack the `*at()` functions
+#[cfg(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    miri
+))]
+mod dir_impl {
+    pub use crate::sys_common::fs::DirHandle;
+}
+
+// Directory handles using openat() and friends, relative to an open file descriptor
+#[cfg(not(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    miri
+)))]
+mod dir_impl {
+    use super::{stat64, Dir, File, FileAttr, InnerReadDir, OpenOptions, ReadDir};
+    use crate::ffi::CStr;
+    use crate::fmt;
+    use crate::io;
+    use crate::mem;
+    use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+    use crate::path::{Path, PathBuf};
+    use crate::sys::common::small_c_string::run_path_with_cstr;
+    use crate::sys::fd::FileDesc;
+    use crate::sys::{cvt, cvt_r};
+
+    #[cfg(any(
+        all(target_os = "linux", not(target_env = "musl")),
+        target_os = "emscripten",
+        target_os = "android",
+        target_os = "hurd"
+    ))]
+    use super::fstatat64;
+    #[cfg(not(any(
+        all(target_os = "linux", not(target_env = "musl")),
+        target_os = "emscripten",
+        target_os = "android",
+        target_os = "hurd"
+    )))]
+    use libc::fstatat as fstatat64;
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    use libc::openat;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    use libc::openat64 as openat;
+
+    /// An open directory, along with the path it was opened with, which is only used to build
+    /// the paths of the entries returned by `read_dir`.
+    pub struct DirHandle {
+        fd: OwnedFd,
+        root: PathBuf,
+    }
+
+    impl DirHandle {
+        pub fn open(path: &Path) -> io::Result<DirHandle> {
+            run_path_with_cstr(path, |p| {
+                DirHandle::open_c(libc::AT_FDCWD, p, 0, path.to_path_buf())
+            })
+        }
+
+        pub fn open_dir(&self, path: &Path) -> io::Result<DirHandle> {
+            // Relative to a handle, a symlink as the last component is never followed, so that
+            // walking a tree one name at a time can't be led out of it.
+            run_path_with_cstr(path, |p| {
+                DirHandle::open_c(self.fd.as_raw_fd(), p, libc::O_NOFOLLOW, self.root.join(path))
+            })
+        }
+
+        fn open_c(
+            dir_fd: RawFd,
+            p: &CStr,
+            extra_flags: libc::c_int,
+            root: PathBuf,
+        ) -> io::Result<DirHandle> {
+            let fd = cvt_r(|| unsafe {
+                openat(
+                    dir_fd,
+                    p.as_ptr(),
+                    libc::O_CLOEXEC | libc::O_RDONLY | libc::O_DIRECTORY | extra_flags,
+                )
+            })?;
+            Ok(DirHandle { fd: unsafe { OwnedFd::from_raw_fd(fd) }, root })
+        }
+
+        pub fn open_file(&self, path: &Path, opts: &OpenOptions) -> io::Result<File> {
+            // Like `open_dir`, this doesn't follow a symlink as the last component.
+            let flags = opts.get_flags()? | libc::O_NOFOLLOW;
+            run_path_with_cstr(path, |p| {
+                // See `File::open_c` for why the mode is passed as a `c_int`.
+                let fd = cvt_r(|| unsafe {
+                    openat(self.fd.as_raw_fd(), p.as_ptr(), flags, opts.mode as libc::c_int)
+                })?;
+                Ok(File(unsafe { FileDesc::from_raw_fd(fd) }))
+            })
+        }
+
+        pub fn create_dir(&self, path: &Path) -> io::Result<()> {
+            run_path_with_cstr(path, |p| {
+                cvt(unsafe { libc::mkdirat(self.fd.as_raw_fd(), p.as_ptr(), 0o777) }).map(|_| ())
+            })
+        }
+
+        pub fn remove_file(&self, path: &Path) -> io::Result<()> {
+            run_path_with_cstr(path, |p| {
+                cvt(unsafe { libc::unlinkat(self.fd.as_raw_fd(), p.as_ptr(), 0) }).map(|_| ())
+            })
+        }
+
+        pub fn remove_dir(&self, path: &Path) -> io::Result<()> {
+            run_path_with_cstr(path, |p| {
+                cvt(unsafe { libc::unlinkat(self.fd.as_raw_fd(), p.as_ptr(), libc::AT_REMOVEDIR) })
+                    .map(|_| ())
+            })
+        }
+
+        pub fn rename_to(&self, from: &Path, to_dir: &DirHandle, to: &Path) -> io::Result<()> {
+            run_path_with_cstr(from, |from| {
+                run_path_with_cstr(to, |to| {
+                    cvt(unsafe {
+                        libc::renameat(
+                            self.fd.as_raw_fd(),
+                            from.as_ptr(),
+                            to_dir.fd.as_raw_fd(),
+                            to.as_ptr(),
+                        )
+                    })
+                    .map(|_| ())
+                })
+            })
+        }
+
+        pub fn symlink_metadata(&self, path: &Path) -> io::Result<FileAttr> {
+            run_path_with_cstr(path, |p| {
+                cfg_has_statx! {
+                    if let Some(ret) = unsafe { super::try_statx(
+                        self.fd.as_raw_fd(),
+                        p.as_ptr(),
+                        libc::AT_SYMLINK_NOFOLLOW | libc::AT_STATX_SYNC_AS_STAT,
+                        libc::STATX_ALL,
+                    ) } {
+                        return ret;
+                    }
+                }
+
+                let mut stat: stat64 = unsafe { mem::zeroed() };
+                cvt(unsafe {
+                    fstatat64(self.fd.as_raw_fd(), p.as_ptr(), &mut stat, libc::AT_SYMLINK_NOFOLLOW)
+                })?;
+                Ok(FileAttr::from_stat64(stat))
+            })
+        }
+
+        pub fn read_dir(&self) -> io::Result<ReadDir> {
+            // Open the directory again rather than duplicating the descriptor, so that the
+            // stream has its own position and reading it twice lists all the entries again.
+            let fd = cvt_r(|| unsafe {
+                openat(
+                    self.fd.as_raw_fd(),
+                    b".\0".as_ptr() as *const libc::c_char,
+                    libc::O_CLOEXEC | libc::O_RDONLY | libc::O_DIRECTORY,
+                )
+            })?;
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            let ptr = unsafe { libc::fdopendir(fd.as_raw_fd()) };
+            if ptr.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            // the file descriptor is closed by libc::closedir() now, so give up ownership
+            let _ = fd.into_raw_fd();
+            let inner = InnerReadDir { dirp: Dir(ptr), root: self.root.clone() };
+            Ok(ReadDir::new(inner))
+        }
+    }
+
+    impl fmt::Debug for DirHandle {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Dir")
+                .field("fd", &self.fd.as_raw_fd())
+                .field("path", &self.root)
+                .finish()
+        }
+    }
+}
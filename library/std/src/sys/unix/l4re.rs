@@ -11,7 +11,7 @@ pub mod net {
     #![allow(warnings)]
     use crate::fmt;
     use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-    use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+    use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketOptions};
     use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
     use crate::sys::fd::FileDesc;
     use crate::sys_common::{AsInner, FromInner, IntoInner};
@@ -185,6 +185,14 @@ pub fn connect_timeout(_: &SocketAddr, _: Duration) -> io::Result<TcpStream> {
             unimpl!();
         }
 
+        pub fn connect_with(
+            _: io::Result<&SocketAddr>,
+            _: &SocketOptions,
+            _: Option<Duration>,
+        ) -> io::Result<TcpStream> {
+            unimpl!();
+        }
+
         #[inline]
         pub fn socket(&self) -> &Socket {
             &self.inner
@@ -312,6 +320,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
             unimpl!();
         }
 
+        pub fn bind_with(_: io::Result<&SocketAddr>, _: &SocketOptions) -> io::Result<TcpListener> {
+            unimpl!();
+        }
+
         #[inline]
         pub fn socket(&self) -> &Socket {
             &self.inner
@@ -379,6 +391,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
             unimpl!();
         }
 
+        pub fn bind_with(_: io::Result<&SocketAddr>, _: &SocketOptions) -> io::Result<UdpSocket> {
+            unimpl!();
+        }
+
         #[inline]
         pub fn socket(&self) -> &Socket {
             &self.inner
@@ -3,25 +3,31 @@
     Ordering::{Acquire, Relaxed, Release},
 };
 use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::sys::locks::Mutex;
 
 pub struct RwLock {
-    // The state consists of a 30-bit reader counter, a 'readers waiting' flag, and a 'writers waiting' flag.
-    // Bits 0..30:
+    // The state consists of a 29-bit reader counter, an 'upgrading' flag, a 'readers waiting' flag,
+    // and a 'writers waiting' flag.
+    // Bits 0..29:
     //   0: Unlocked
-    //   1..=0x3FFF_FFFE: Locked by N readers
-    //   0x3FFF_FFFF: Write locked
+    //   1..=0x1FFF_FFFE: Locked by N readers
+    //   0x1FFF_FFFF: Write locked
+    // Bit 29: The upgradable reader is waiting on this futex for the other readers to unlock.
     // Bit 30: Readers are waiting on this futex.
     // Bit 31: Writers are waiting on the writer_notify futex.
     state: AtomicU32,
     // The 'condition variable' to notify writers through.
     // Incremented on every signal.
     writer_notify: AtomicU32,
+    // Held by the upgradable reader, in addition to its read lock.
+    upgradable: Mutex,
 }
 
 const READ_LOCKED: u32 = 1;
-const MASK: u32 = (1 << 30) - 1;
+const MASK: u32 = (1 << 29) - 1;
 const WRITE_LOCKED: u32 = MASK;
 const MAX_READERS: u32 = MASK - 1;
+const UPGRADING: u32 = 1 << 29;
 const READERS_WAITING: u32 = 1 << 30;
 const WRITERS_WAITING: u32 = 1 << 31;
 
@@ -45,6 +51,11 @@ fn has_writers_waiting(state: u32) -> bool {
     state & WRITERS_WAITING != 0
 }
 
+#[inline]
+fn is_upgrading(state: u32) -> bool {
+    state & UPGRADING != 0
+}
+
 #[inline]
 fn is_read_lockable(state: u32) -> bool {
     // This also returns false if the counter could overflow if we tried to read lock it.
@@ -53,7 +64,13 @@ fn is_read_lockable(state: u32) -> bool {
     // and there's no writers waiting. The only situation when this happens is after unlocking,
     // at which point the unlocking thread might be waking up writers, which have priority over readers.
     // The unlocking thread will clear the readers waiting bit and wake up readers, if necessary.
-    state & MASK < MAX_READERS && !has_readers_waiting(state) && !has_writers_waiting(state)
+    //
+    // New readers also wait while the upgradable reader is upgrading its lock, as it would
+    // otherwise never get to lock it for writing.
+    state & MASK < MAX_READERS
+        && !has_readers_waiting(state)
+        && !has_writers_waiting(state)
+        && !is_upgrading(state)
 }
 
 #[inline]
@@ -64,7 +81,11 @@ fn has_reached_max_readers(state: u32) -> bool {
 impl RwLock {
     #[inline]
     pub const fn new() -> Self {
-        Self { state: AtomicU32::new(0), writer_notify: AtomicU32::new(0) }
+        Self {
+            state: AtomicU32::new(0),
+            writer_notify: AtomicU32::new(0),
+            upgradable: Mutex::new(),
+        }
     }
 
     #[inline]
@@ -92,13 +113,20 @@ pub unsafe fn read_unlock(&self) {
         let state = self.state.fetch_sub(READ_LOCKED, Release) - READ_LOCKED;
 
         // It's impossible for a reader to be waiting on a read-locked RwLock,
-        // except if there is also a writer waiting.
-        debug_assert!(!has_readers_waiting(state) || has_writers_waiting(state));
+        // except if there is also a writer waiting or the upgradable reader is upgrading.
+        debug_assert!(
+            !has_readers_waiting(state) || has_writers_waiting(state) || is_upgrading(state)
+        );
 
         // Wake up a writer if we were the last reader and there's a writer waiting.
         if is_unlocked(state) && has_writers_waiting(state) {
             self.wake_writer_or_readers(state);
         }
+
+        // Wake up the upgrading reader if it's the only reader left.
+        if is_upgrading(state) && state & MASK == READ_LOCKED {
+            self.wake_upgrader();
+        }
     }
 
     #[cold]
@@ -222,6 +250,128 @@ fn write_contended(&self) {
         }
     }
 
+    #[inline]
+    pub unsafe fn downgrade(&self) {
+        // Waiting readers can read along with us now, unless writers are waiting too,
+        // which have priority over readers.
+        let state = self
+            .state
+            .fetch_update(Release, Relaxed, |s| {
+                let s = s - WRITE_LOCKED + READ_LOCKED;
+                Some(if has_writers_waiting(s) { s } else { s & !READERS_WAITING })
+            })
+            .unwrap();
+
+        if has_readers_waiting(state) && !has_writers_waiting(state) {
+            futex_wake_all(&self.state);
+        }
+    }
+
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.upgradable.lock();
+        self.read();
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        if !self.upgradable.try_lock() {
+            return false;
+        }
+        if self.try_read() {
+            true
+        } else {
+            unsafe { self.upgradable.unlock() };
+            false
+        }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.read_unlock();
+        self.upgradable.unlock();
+    }
+
+    /// Turns the upgradable read lock into a write lock, which is then
+    /// released with `write_unlock`.
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        if !self.try_upgrade_locked() {
+            self.upgrade_contended();
+        }
+        self.upgradable.unlock();
+    }
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        if self.try_upgrade_locked() {
+            self.upgradable.unlock();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Turns our read lock into a write lock if there are no other readers.
+    ///
+    /// Writers can't lock the lock in the meantime, as we still hold a read lock,
+    /// and the waiting bits are kept for `write_unlock` to handle.
+    #[inline]
+    fn try_upgrade_locked(&self) -> bool {
+        self.state
+            .fetch_update(Acquire, Relaxed, |s| {
+                (s & MASK == READ_LOCKED).then(|| (s & !UPGRADING) - READ_LOCKED + WRITE_LOCKED)
+            })
+            .is_ok()
+    }
+
+    #[cold]
+    fn upgrade_contended(&self) {
+        let mut state = self.state.load(Relaxed);
+
+        loop {
+            // If we are the only reader left, lock it for writing.
+            if state & MASK == READ_LOCKED {
+                match self.state.compare_exchange_weak(
+                    state,
+                    (state & !UPGRADING) - READ_LOCKED + WRITE_LOCKED,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => return, // Locked!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Make sure the upgrading bit is set before we go to sleep,
+            // which also stops new readers from locking it.
+            if !is_upgrading(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | UPGRADING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Wait for the other readers to unlock.
+            futex_wait(&self.state, state | UPGRADING, None);
+
+            state = self.state.load(Relaxed);
+        }
+    }
+
+    /// Wakes up the upgrading reader, after the last other reader unlocked.
+    #[cold]
+    fn wake_upgrader(&self) {
+        // Readers might be waiting on the same futex, so we have to wake them all up.
+        // They will go back to sleep, as the lock is about to be locked for writing.
+        futex_wake_all(&self.state);
+    }
+
     /// Wake up waiting threads after unlocking.
     ///
     /// If both are waiting, this will wake up only one writer, but will fall
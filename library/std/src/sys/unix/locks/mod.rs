@@ -25,7 +25,8 @@
         mod pthread_rwlock;
         mod pthread_condvar;
         pub(crate) use pthread_mutex::Mutex;
-        pub(crate) use pthread_rwlock::RwLock;
+        pub(crate) type RwLock =
+            crate::sys::common::upgradable_rwlock::UpgradableRwLock<pthread_rwlock::RwLock>;
         pub(crate) use pthread_condvar::Condvar;
     }
 }
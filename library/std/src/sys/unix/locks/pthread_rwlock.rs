@@ -1,6 +1,7 @@
 use crate::cell::UnsafeCell;
 use crate::mem::forget;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sys::common::upgradable_rwlock::RawRwLock;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
 
 struct AllocatedRwLock {
@@ -193,3 +194,37 @@ pub unsafe fn write_unlock(&self) {
         lock.raw_unlock();
     }
 }
+
+impl RawRwLock for RwLock {
+    const INIT: RwLock = RwLock::new();
+
+    #[inline]
+    fn read(&self) {
+        RwLock::read(self)
+    }
+
+    #[inline]
+    fn try_read(&self) -> bool {
+        RwLock::try_read(self)
+    }
+
+    #[inline]
+    fn write(&self) {
+        RwLock::write(self)
+    }
+
+    #[inline]
+    fn try_write(&self) -> bool {
+        unsafe { RwLock::try_write(self) }
+    }
+
+    #[inline]
+    unsafe fn read_unlock(&self) {
+        RwLock::read_unlock(self)
+    }
+
+    #[inline]
+    unsafe fn write_unlock(&self) {
+        RwLock::write_unlock(self)
+    }
+}
@@ -45,7 +45,18 @@ pub fn anon_pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     }
 }
 
+/// The type of the ends of a pipe created by `std::io::pipe`.
+pub type PipeEnd = AnonPipe;
+
+pub fn pipe() -> io::Result<(PipeEnd, PipeEnd)> {
+    anon_pipe()
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.duplicate().map(Self)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -108,6 +108,8 @@ pub struct Command {
     #[cfg(target_os = "linux")]
     create_pidfd: bool,
     pgroup: Option<pid_t>,
+    setsid: bool,
+    kill_process_group: bool,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -199,6 +201,8 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             pgroup: None,
+            setsid: false,
+            kill_process_group: false,
         }
     }
 
@@ -224,6 +228,8 @@ pub fn new(program: &OsStr) -> Command {
             stderr: None,
             create_pidfd: false,
             pgroup: None,
+            setsid: false,
+            kill_process_group: false,
         }
     }
 
@@ -262,6 +268,12 @@ pub fn groups(&mut self, groups: &[gid_t]) {
     pub fn pgroup(&mut self, pgroup: pid_t) {
         self.pgroup = Some(pgroup);
     }
+    pub fn setsid(&mut self, setsid: bool) {
+        self.setsid = setsid;
+    }
+    pub fn kill_process_group(&mut self, kill: bool) {
+        self.kill_process_group = kill;
+    }
 
     #[cfg(target_os = "linux")]
     pub fn create_pidfd(&mut self, val: bool) {
@@ -332,8 +344,30 @@ pub fn get_groups(&self) -> Option<&[gid_t]> {
     }
     #[allow(dead_code)]
     pub fn get_pgroup(&self) -> Option<pid_t> {
+        // Killing the process group requires the child to be in a group of its own, unless it
+        // starts a new session, which also makes it the leader of a new group.
+        if self.pgroup.is_none() && self.kill_process_group && !self.setsid {
+            return Some(0);
+        }
         self.pgroup
     }
+    #[allow(dead_code)]
+    pub fn get_setsid(&self) -> bool {
+        self.setsid
+    }
+    /// Returns the process group to signal when killing the child with the given pid, if the
+    /// whole group should be killed. Only a group led by the child is ever signaled.
+    #[allow(dead_code)]
+    pub fn get_kill_pgroup(&self, pid: pid_t) -> Option<pid_t> {
+        if !self.kill_process_group {
+            return None;
+        }
+        match self.pgroup {
+            // The child joined an existing group, which holds other processes than its own.
+            Some(pgroup) if pgroup != 0 && !self.setsid => None,
+            _ => Some(pid),
+        }
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -576,6 +610,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             if self.pgroup.is_some() {
                 debug_command.field("pgroup", &self.pgroup);
             }
+            if self.setsid {
+                debug_command.field("setsid", &self.setsid);
+            }
+            if self.kill_process_group {
+                debug_command.field("kill_process_group", &self.kill_process_group);
+            }
 
             #[cfg(target_os = "linux")]
             {
@@ -6,6 +6,7 @@
 
 use crate::sys::process::process_common::*;
 use crate::sys::process::zircon::{zx_handle_t, Handle};
+use crate::time::Duration;
 
 use libc::{c_int, size_t};
 
@@ -197,6 +198,10 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
         Ok(ExitStatus(proc_info.return_code))
     }
 
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        crate::sys_common::process::wait_timeout_by_polling(self, timeout)
+    }
+
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         use crate::sys::process::zircon::*;
 
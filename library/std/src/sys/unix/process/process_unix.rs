@@ -5,8 +5,12 @@
 use crate::sys;
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
+use crate::time::Duration;
 use core::ffi::NonZero_c_int;
 
+#[cfg(target_os = "linux")]
+use crate::time::Instant;
+
 #[cfg(target_os = "linux")]
 use crate::os::linux::process::PidFd;
 #[cfg(target_os = "linux")]
@@ -41,7 +45,6 @@
     if #[cfg(all(target_os = "nto", target_env = "nto71"))] {
         use crate::thread;
         use libc::{c_char, posix_spawn_file_actions_t, posix_spawnattr_t};
-        use crate::time::Duration;
         use crate::sync::LazyLock;
         // Get smallest amount of time we can sleep.
         // Return a common value if it cannot be determined.
@@ -150,6 +153,7 @@ pub fn spawn(
         // Safety: We obtained the pidfd from calling `clone3` with
         // `CLONE_PIDFD` so it's valid an otherwise unowned.
         let mut p = unsafe { Process::new(pid, pidfd) };
+        p.kill_pgroup = self.get_kill_pgroup(pid);
         let mut bytes = [0; 8];
 
         // loop to handle EINTR
@@ -348,7 +352,11 @@ unsafe fn do_exec(
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
-        if let Some(pgroup) = self.get_pgroup() {
+        // A new session also makes the child the leader of a new process group, and it
+        // could not join another group afterwards.
+        if self.get_setsid() {
+            cvt(libc::setsid())?;
+        } else if let Some(pgroup) = self.get_pgroup() {
             cvt(libc::setpgid(0, pgroup))?;
         }
 
@@ -467,6 +475,7 @@ fn posix_spawn(
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
             || self.get_create_pidfd()
+            || self.get_setsid()
         {
             return Ok(None);
         }
@@ -664,6 +673,7 @@ fn drop(&mut self) {
             let spawn_res = spawn_res?;
 
             cvt_nz(spawn_res)?;
+            p.kill_pgroup = self.get_kill_pgroup(p.pid);
             Ok(Some(p))
         }
     }
@@ -787,6 +797,8 @@ union Cmsg {
 pub struct Process {
     pid: pid_t,
     status: Option<ExitStatus>,
+    // The process group to signal instead of the process itself on `kill`, if any.
+    kill_pgroup: Option<pid_t>,
     // On Linux, stores the pidfd created for this child.
     // This is None if the user did not request pidfd creation,
     // or if the pidfd could not be created for some reason
@@ -802,12 +814,12 @@ unsafe fn new(pid: pid_t, pidfd: pid_t) -> Self {
         use crate::sys_common::FromInner;
         // Safety: If `pidfd` is nonnegative, we assume it's valid and otherwise unowned.
         let pidfd = (pidfd >= 0).then(|| PidFd::from_inner(sys::fd::FileDesc::from_raw_fd(pidfd)));
-        Process { pid, status: None, pidfd }
+        Process { pid, status: None, kill_pgroup: None, pidfd }
     }
 
     #[cfg(not(target_os = "linux"))]
     unsafe fn new(pid: pid_t, _pidfd: pid_t) -> Self {
-        Process { pid, status: None }
+        Process { pid, status: None, kill_pgroup: None }
     }
 
     pub fn id(&self) -> u32 {
@@ -821,6 +833,10 @@ pub fn kill(&mut self) -> io::Result<()> {
         if self.status.is_some() {
             return Ok(());
         }
+        if let Some(pgroup) = self.kill_pgroup {
+            // The group cannot be reused while the child, its leader or a member, is not reaped.
+            return cvt(unsafe { libc::kill(-pgroup, libc::SIGKILL) }).map(drop);
+        }
         #[cfg(target_os = "linux")]
         if let Some(pid_fd) = self.pidfd.as_ref() {
             // pidfd_send_signal predates pidfd_open. so if we were able to get an fd then sending signals will work too
@@ -860,6 +876,31 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
         Ok(ExitStatus::new(status))
     }
 
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.status {
+            return Ok(Some(status));
+        }
+        #[cfg(target_os = "linux")]
+        {
+            // Block until the pidfd becomes readable, which happens when the child exits. If no
+            // pidfd was requested, open one for this wait: the pid cannot be reused before the
+            // child is reaped.
+            let opened_pidfd;
+            let pidfd = match self.pidfd.as_ref() {
+                Some(pidfd) => Some(pidfd.as_raw_fd()),
+                None => {
+                    opened_pidfd = pidfd_open(self.pid);
+                    opened_pidfd.as_ref().map(|fd| fd.as_raw_fd())
+                }
+            };
+            if let Some(pidfd) = pidfd {
+                poll_pidfd(pidfd, timeout)?;
+                return self.try_wait();
+            }
+        }
+        crate::sys_common::process::wait_timeout_by_polling(self, timeout)
+    }
+
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         if let Some(status) = self.status {
             return Ok(Some(status));
@@ -894,6 +935,42 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
     }
 }
 
+/// Opens a pidfd for the child, or returns `None` if pidfds are not supported.
+#[cfg(target_os = "linux")]
+fn pidfd_open(pid: pid_t) -> Option<crate::os::fd::OwnedFd> {
+    use crate::os::unix::io::FromRawFd;
+
+    let pidfd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+    // Safety: a nonnegative return value is a new file descriptor, which we own.
+    (pidfd >= 0).then(|| unsafe { crate::os::fd::OwnedFd::from_raw_fd(pidfd as c_int) })
+}
+
+/// Waits until the pidfd is readable, or the timeout elapsed.
+#[cfg(target_os = "linux")]
+fn poll_pidfd(pidfd: c_int, timeout: Duration) -> io::Result<()> {
+    let deadline = Instant::now().checked_add(timeout);
+    let mut pollfd = libc::pollfd { fd: pidfd, events: libc::POLLIN, revents: 0 };
+    loop {
+        let timeout_ms = match deadline {
+            // Round up, so that the wait does not end before the deadline.
+            Some(deadline) => deadline
+                .saturating_duration_since(Instant::now())
+                .as_nanos()
+                .div_ceil(1_000_000)
+                .try_into()
+                .unwrap_or(c_int::MAX),
+            None => -1,
+        };
+        match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout_ms) }) {
+            // Timeouts longer than `c_int::MAX` milliseconds take several polls.
+            Ok(0) if deadline.is_some_and(|deadline| Instant::now() < deadline) => {}
+            Ok(_) => return Ok(()),
+            Err(e) if e.is_interrupted() => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 /// Unix exit statuses
 //
 // This is not actually an "exit status" in Unix terminology.  Rather, it is a "wait status".
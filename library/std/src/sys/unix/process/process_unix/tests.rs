@@ -98,3 +98,61 @@ fn test_command_pidfd() {
     let status = child.wait().expect("error waiting on pidfd");
     assert_eq!(status.signal(), Some(libc::SIGKILL));
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_command_kill_process_group() {
+    use crate::fs;
+    use crate::io::{BufRead, BufReader};
+    use crate::process::Stdio;
+    use crate::thread;
+    use crate::time::{Duration, Instant};
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg("sleep 1000 & echo $!; wait")
+        .stdout(Stdio::piped())
+        .kill_process_group(true)
+        .spawn()
+        .unwrap();
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+    let grandchild: u32 = line.trim().parse().unwrap();
+
+    child.kill().unwrap();
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+    // The grandchild is reaped by another process, so wait until it is gone or a zombie.
+    let deadline = Instant::now() + Duration::from_secs(60);
+    loop {
+        match fs::read_to_string(format!("/proc/{grandchild}/stat")) {
+            Ok(stat) if !stat.rsplit_once(')').unwrap().1.trim_start().starts_with('Z') => {
+                assert!(Instant::now() < deadline, "grandchild was not killed");
+                thread::sleep(Duration::from_millis(10));
+            }
+            _ => break,
+        }
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_command_kill_process_group_only_if_led_by_child() {
+    use crate::assert_matches::assert_matches;
+
+    let mut leader = Command::new("sleep").arg("1000").process_group(0).spawn().unwrap();
+
+    // The child joins the group of `leader`, which must survive the child being killed.
+    let mut child = Command::new("sleep")
+        .arg("1000")
+        .process_group(leader.id() as i32)
+        .kill_process_group(true)
+        .spawn()
+        .unwrap();
+    child.kill().unwrap();
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+    assert_matches!(leader.try_wait(), Ok(None));
+
+    leader.kill().unwrap();
+    leader.wait().unwrap();
+}
@@ -3,6 +3,7 @@
 pub struct RwLock {
     // This platform has no threads, so we can use a Cell here.
     mode: Cell<isize>,
+    upgradable: Cell<bool>,
 }
 
 unsafe impl Send for RwLock {}
@@ -12,7 +13,7 @@ impl RwLock {
     #[inline]
     #[rustc_const_stable(feature = "const_locks", since = "1.63.0")]
     pub const fn new() -> RwLock {
-        RwLock { mode: Cell::new(0) }
+        RwLock { mode: Cell::new(0), upgradable: Cell::new(false) }
     }
 
     #[inline]
@@ -62,4 +63,52 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         assert_eq!(self.mode.replace(0), -1);
     }
+
+    #[inline]
+    pub unsafe fn downgrade(&self) {
+        assert_eq!(self.mode.replace(1), -1);
+    }
+
+    #[inline]
+    pub fn upgradable_read(&self) {
+        if self.upgradable.replace(true) {
+            rtabort!("rwlock locked for upgradable reading");
+        }
+        self.read();
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        if self.upgradable.get() || self.mode.get() < 0 {
+            false
+        } else {
+            self.upgradable_read();
+            true
+        }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.read_unlock();
+        self.upgradable.set(false);
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        if self.mode.get() != 1 {
+            rtabort!("rwlock locked for reading");
+        }
+        self.mode.set(-1);
+        self.upgradable.set(false);
+    }
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        if self.mode.get() == 1 {
+            self.upgrade();
+            true
+        } else {
+            false
+        }
+    }
 }
@@ -78,6 +78,13 @@ pub struct FileType {
 #[derive(Debug)]
 pub struct DirBuilder {}
 
+/// An open directory, along with the path it was opened with, which is only used to build the
+/// paths of the entries returned by `read_dir`.
+pub struct DirHandle {
+    fd: WasiFd,
+    root: PathBuf,
+}
+
 impl FileAttr {
     pub fn size(&self) -> u64 {
         self.meta.size
@@ -423,6 +430,26 @@ pub fn datasync(&self) -> io::Result<()> {
         self.fd.datasync()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         self.fd.filestat_set_size(size)
     }
@@ -542,6 +569,75 @@ unsafe fn from_raw_fd(raw_fd: RawFd) -> Self {
     }
 }
 
+impl DirHandle {
+    pub fn open(path: &Path) -> io::Result<DirHandle> {
+        let (dir, file) = open_parent(path)?;
+        DirHandle::open_at(&dir, &file, wasi::LOOKUPFLAGS_SYMLINK_FOLLOW, path.to_path_buf())
+    }
+
+    pub fn open_dir(&self, path: &Path) -> io::Result<DirHandle> {
+        // Relative to a handle, a symlink as the last component is never followed, so that
+        // walking a tree one name at a time can't be led out of it.
+        DirHandle::open_at(&self.fd, path, 0, self.root.join(path))
+    }
+
+    fn open_at(
+        fd: &WasiFd,
+        path: &Path,
+        lookup_flags: wasi::Lookupflags,
+        root: PathBuf,
+    ) -> io::Result<DirHandle> {
+        let mut opts = OpenOptions::new();
+        opts.directory(true);
+        opts.read(true);
+        opts.lookup_flags(lookup_flags);
+        let dir = open_at(fd, path, &opts)?;
+        Ok(DirHandle { fd: dir.fd, root })
+    }
+
+    pub fn open_file(&self, path: &Path, opts: &OpenOptions) -> io::Result<File> {
+        // Like `open_dir`, this doesn't follow a symlink as the last component.
+        let mut opts = opts.clone();
+        opts.lookup_flags(0);
+        open_at(&self.fd, path, &opts)
+    }
+
+    pub fn create_dir(&self, path: &Path) -> io::Result<()> {
+        self.fd.create_directory(osstr2str(path.as_ref())?)
+    }
+
+    pub fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.fd.unlink_file(osstr2str(path.as_ref())?)
+    }
+
+    pub fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        self.fd.remove_directory(osstr2str(path.as_ref())?)
+    }
+
+    pub fn rename_to(&self, from: &Path, to_dir: &DirHandle, to: &Path) -> io::Result<()> {
+        self.fd.rename(osstr2str(from.as_ref())?, &to_dir.fd, osstr2str(to.as_ref())?)
+    }
+
+    pub fn symlink_metadata(&self, path: &Path) -> io::Result<FileAttr> {
+        metadata_at(&self.fd, 0, path)
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        // The directory is opened again so that the stream has its own cookie.
+        let mut opts = OpenOptions::new();
+        opts.directory(true);
+        opts.read(true);
+        let dir = open_at(&self.fd, Path::new("."), &opts)?;
+        Ok(ReadDir::new(dir, self.root.clone()))
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("fd", &self.fd.as_raw_fd()).field("path", &self.root).finish()
+    }
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder {}
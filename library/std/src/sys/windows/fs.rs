@@ -21,6 +21,8 @@
 use super::path::maybe_verbatim;
 use super::{api, to_u16s, IoResult};
 
+pub use crate::sys_common::fs::DirHandle;
+
 pub struct File {
     handle: Handle,
 }
@@ -317,6 +319,52 @@ pub fn datasync(&self) -> io::Result<()> {
         self.fsync()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_range(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_range(0)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.try_lock_range(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_lock_range(0)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, u32::MAX, u32::MAX) })?;
+        Ok(())
+    }
+
+    fn try_lock_range(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<bool> {
+        match self.lock_range(flags | c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as _) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn lock_range(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<()> {
+        // Lock the largest possible range, so that the lock covers the whole file even if it
+        // grows later, like `flock` on Unix.
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        cvt(unsafe {
+            c::LockFileEx(
+                self.handle.as_raw_handle(),
+                flags,
+                0,
+                u32::MAX,
+                u32::MAX,
+                &mut overlapped,
+            )
+        })?;
+        Ok(())
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         let info = c::FILE_END_OF_FILE_INFO { EndOfFile: size as i64 };
         api::set_file_information_by_handle(self.handle.as_raw_handle(), &info).io_result()
@@ -3,4 +3,4 @@
 mod rwlock;
 pub use condvar::Condvar;
 pub use mutex::Mutex;
-pub use rwlock::RwLock;
+pub type RwLock = crate::sys::common::upgradable_rwlock::UpgradableRwLock<rwlock::RwLock>;
@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sys::c;
+use crate::sys::common::upgradable_rwlock::RawRwLock;
 
 pub struct RwLock {
     inner: UnsafeCell<c::SRWLOCK>,
@@ -38,3 +39,37 @@ pub unsafe fn write_unlock(&self) {
         c::ReleaseSRWLockExclusive(self.inner.get())
     }
 }
+
+impl RawRwLock for RwLock {
+    const INIT: RwLock = RwLock::new();
+
+    #[inline]
+    fn read(&self) {
+        RwLock::read(self)
+    }
+
+    #[inline]
+    fn try_read(&self) -> bool {
+        RwLock::try_read(self)
+    }
+
+    #[inline]
+    fn write(&self) {
+        RwLock::write(self)
+    }
+
+    #[inline]
+    fn try_write(&self) -> bool {
+        RwLock::try_write(self)
+    }
+
+    #[inline]
+    unsafe fn read_unlock(&self) {
+        RwLock::read_unlock(self)
+    }
+
+    #[inline]
+    unsafe fn write_unlock(&self) {
+        RwLock::write_unlock(self)
+    }
+}
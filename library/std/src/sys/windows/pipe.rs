@@ -9,6 +9,7 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
@@ -171,6 +172,23 @@ pub fn anon_pipe(ours_readable: bool, their_handle_inheritable: bool) -> io::Res
     }
 }
 
+/// The type of the ends of a pipe created by `std::io::pipe`.
+///
+/// Unlike the `ours` end of `anon_pipe`, these are not opened in overlapped
+/// mode, so that they can be read from and written to like any other handle,
+/// and can be given to a child process without a relay thread.
+pub type PipeEnd = Handle;
+
+pub fn pipe() -> io::Result<(PipeEnd, PipeEnd)> {
+    unsafe {
+        let mut read_pipe = c::INVALID_HANDLE_VALUE;
+        let mut write_pipe = c::INVALID_HANDLE_VALUE;
+        // The handles are not inheritable, they are duplicated when given to a child process.
+        cvt(c::CreatePipe(&mut read_pipe, &mut write_pipe, ptr::null(), 0))?;
+        Ok((Handle::from_raw_handle(read_pipe), Handle::from_raw_handle(write_pipe)))
+    }
+}
+
 /// Takes an asynchronous source pipe and returns a synchronous pipe suitable
 /// for sending to a child process.
 ///
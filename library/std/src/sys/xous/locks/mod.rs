@@ -4,4 +4,4 @@
 
 pub use condvar::*;
 pub use mutex::*;
-pub use rwlock::*;
+pub type RwLock = crate::sys::common::upgradable_rwlock::UpgradableRwLock<rwlock::RwLock>;
@@ -1,5 +1,6 @@
 use crate::os::xous::ffi::do_yield;
 use crate::sync::atomic::{AtomicIsize, Ordering::SeqCst};
+use crate::sys::common::upgradable_rwlock::RawRwLock;
 
 pub struct RwLock {
     /// The "mode" value indicates how many threads are waiting on this
@@ -70,3 +71,37 @@ pub unsafe fn write_unlock(&self) {
         assert_eq!(self.mode.compare_exchange(-1, 0, SeqCst, SeqCst), Ok(-1));
     }
 }
+
+impl RawRwLock for RwLock {
+    const INIT: RwLock = RwLock::new();
+
+    #[inline]
+    fn read(&self) {
+        unsafe { RwLock::read(self) }
+    }
+
+    #[inline]
+    fn try_read(&self) -> bool {
+        unsafe { RwLock::try_read(self) }
+    }
+
+    #[inline]
+    fn write(&self) {
+        unsafe { RwLock::write(self) }
+    }
+
+    #[inline]
+    fn try_write(&self) -> bool {
+        unsafe { RwLock::try_write(self) }
+    }
+
+    #[inline]
+    unsafe fn read_unlock(&self) {
+        RwLock::read_unlock(self)
+    }
+
+    #[inline]
+    unsafe fn write_unlock(&self) {
+        RwLock::write_unlock(self)
+    }
+}
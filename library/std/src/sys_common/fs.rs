@@ -1,8 +1,10 @@
 #![allow(dead_code)] // not used on all platforms
 
+use crate::fmt;
 use crate::fs;
 use crate::io::{self, Error, ErrorKind};
 use crate::path::Path;
+use crate::sys::fs::{File, FileAttr, OpenOptions, ReadDir};
 
 pub(crate) const NOT_FILE_ERROR: Error = io::const_io_error!(
     ErrorKind::InvalidInput,
@@ -49,3 +51,53 @@ pub fn try_exists(path: &Path) -> io::Result<bool> {
         Err(error) => Err(error),
     }
 }
+
+/// A directory handle for platforms which cannot operate relative to an open directory.
+pub struct DirHandle(!);
+
+impl DirHandle {
+    pub fn open(_path: &Path) -> io::Result<DirHandle> {
+        Err(io::const_io_error!(
+            ErrorKind::Unsupported,
+            "directory handles are not supported on this platform",
+        ))
+    }
+
+    pub fn open_dir(&self, _path: &Path) -> io::Result<DirHandle> {
+        self.0
+    }
+
+    pub fn open_file(&self, _path: &Path, _opts: &OpenOptions) -> io::Result<File> {
+        self.0
+    }
+
+    pub fn create_dir(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn remove_file(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn remove_dir(&self, _path: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn rename_to(&self, _from: &Path, _to_dir: &DirHandle, _to: &Path) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn symlink_metadata(&self, _path: &Path) -> io::Result<FileAttr> {
+        self.0
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        self.0
+    }
+}
+
+impl fmt::Debug for DirHandle {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+    }
+}
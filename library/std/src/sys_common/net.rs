@@ -5,7 +5,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, ErrorKind, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketOptions};
 use crate::ptr;
 use crate::sys::common::small_c_string::run_with_cstr;
 use crate::sys::net::netc as c;
@@ -122,6 +122,49 @@ pub fn sockaddr_to_addr(storage: &c::sockaddr_storage, len: usize) -> io::Result
     }
 }
 
+fn bind(sock: &Socket, addr: &SocketAddr) -> io::Result<()> {
+    let (addr, len) = addr.into_inner();
+    cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
+    Ok(())
+}
+
+/// Creates a socket for the address family of `addr`, with the options set before binding or
+/// connecting it.
+fn socket_with(addr: &SocketAddr, ty: c_int, opts: &SocketOptions) -> io::Result<Socket> {
+    init();
+
+    let sock = Socket::new(addr, ty)?;
+    if let Some(reuse_address) = opts.reuse_address {
+        setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, reuse_address as c_int)?;
+    }
+    if opts.reuse_port {
+        set_reuse_port(&sock)?;
+    }
+    if let (Some(only_v6), SocketAddr::V6(..)) = (opts.only_v6, addr) {
+        setsockopt(&sock, c::IPPROTO_IPV6, c::IPV6_V6ONLY, only_v6 as c_int)?;
+    }
+    Ok(sock)
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "dragonfly", target_os = "freebsd",
+        target_os = "openbsd", target_os = "netbsd",
+        target_os = "ios", target_os = "tvos", target_os = "macos", target_os = "watchos"))] {
+        fn set_reuse_port(sock: &Socket) -> io::Result<()> {
+            setsockopt(sock, c::SOL_SOCKET, c::SO_REUSEPORT, 1 as c_int)
+        }
+    } else {
+        fn set_reuse_port(_sock: &Socket) -> io::Result<()> {
+            Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "SO_REUSEPORT is not supported on this platform",
+            ))
+        }
+    }
+}
+
 #[cfg(target_os = "android")]
 fn to_ipv6mr_interface(value: u32) -> c_int {
     value as c_int
@@ -238,6 +281,24 @@ pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpSt
         Ok(TcpStream { inner: sock })
     }
 
+    pub fn connect_with(
+        addr: io::Result<&SocketAddr>,
+        opts: &SocketOptions,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        let addr = addr?;
+
+        let sock = socket_with(addr, c::SOCK_STREAM, opts)?;
+        if let Some(local_addr) = &opts.local_addr {
+            bind(&sock, local_addr)?;
+        }
+        match timeout {
+            Some(timeout) => sock.connect_timeout(addr, timeout)?,
+            None => sock.connect(addr)?,
+        }
+        Ok(TcpStream { inner: sock })
+    }
+
     #[inline]
     pub fn socket(&self) -> &Socket {
         &self.inner
@@ -391,11 +452,16 @@ pub struct TcpListener {
 
 impl TcpListener {
     pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
-        let addr = addr?;
+        TcpListener::bind_with(addr, &SocketOptions::default())
+    }
 
-        init();
+    pub fn bind_with(
+        addr: io::Result<&SocketAddr>,
+        opts: &SocketOptions,
+    ) -> io::Result<TcpListener> {
+        let addr = addr?;
 
-        let sock = Socket::new(addr, c::SOCK_STREAM)?;
+        let sock = socket_with(addr, c::SOCK_STREAM, opts)?;
 
         // On platforms with Berkeley-derived sockets, this allows to quickly
         // rebind a socket, without needing to wait for the OS to clean up the
@@ -405,23 +471,27 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         // which allows “socket hijacking”, so we explicitly don't set it here.
         // https://docs.microsoft.com/en-us/windows/win32/winsock/using-so-reuseaddr-and-so-exclusiveaddruse
         #[cfg(not(windows))]
-        setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
+        if opts.reuse_address.is_none() {
+            setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
+        }
 
         // Bind our new socket
-        let (addr, len) = addr.into_inner();
-        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
+        bind(&sock, addr)?;
 
         cfg_if::cfg_if! {
             if #[cfg(target_os = "horizon")] {
                 // The 3DS doesn't support a big connection backlog. Sometimes
                 // it allows up to about 37, but other times it doesn't even
                 // accept 32. There may be a global limitation causing this.
-                let backlog = 20;
+                let default_backlog = 20;
             } else {
                 // The default for all other platforms
-                let backlog = 128;
+                let default_backlog = 128;
             }
         }
+        let backlog = opts
+            .backlog
+            .map_or(default_backlog, |backlog| backlog.try_into().unwrap_or(c_int::MAX));
 
         // Start listening
         cvt(unsafe { c::listen(sock.as_raw(), backlog) })?;
@@ -509,13 +579,14 @@ pub struct UdpSocket {
 
 impl UdpSocket {
     pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
-        let addr = addr?;
+        UdpSocket::bind_with(addr, &SocketOptions::default())
+    }
 
-        init();
+    pub fn bind_with(addr: io::Result<&SocketAddr>, opts: &SocketOptions) -> io::Result<UdpSocket> {
+        let addr = addr?;
 
-        let sock = Socket::new(addr, c::SOCK_DGRAM)?;
-        let (addr, len) = addr.into_inner();
-        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
+        let sock = socket_with(addr, c::SOCK_DGRAM, opts)?;
+        bind(&sock, addr)?;
         Ok(UdpSocket { inner: sock })
     }
 
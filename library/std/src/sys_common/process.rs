@@ -8,6 +8,8 @@
 use crate::io;
 use crate::sys::pipe::read2;
 use crate::sys::process::{EnvKey, ExitStatus, Process, StdioPipes};
+use crate::thread;
+use crate::time::{Duration, Instant};
 
 // Stores a set of changes to an environment
 #[derive(Clone)]
@@ -159,3 +161,34 @@ pub fn wait_with_output(
     let status = process.wait()?;
     Ok((status, stdout, stderr))
 }
+
+/// Waits for the process to exit by polling `try_wait`, for platforms which
+/// cannot block on a process with a timeout.
+pub fn wait_timeout_by_polling(
+    process: &mut Process,
+    timeout: Duration,
+) -> io::Result<Option<ExitStatus>> {
+    // The interval starts short so that quick processes are reaped promptly,
+    // and is capped so that slow ones are not noticed much later.
+    const MAX_INTERVAL: Duration = Duration::from_millis(50);
+
+    let deadline = Instant::now().checked_add(timeout);
+    let mut interval = Duration::from_millis(1);
+    loop {
+        if let Some(status) = process.try_wait()? {
+            return Ok(Some(status));
+        }
+        let sleep = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Ok(None);
+                }
+                interval.min(remaining)
+            }
+            None => interval,
+        };
+        thread::sleep(sleep);
+        interval = (interval * 2).min(MAX_INTERVAL);
+    }
+}
@@ -1,9 +1,10 @@
 //! Module converting command-line arguments into test configuration.
 
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use super::options::{ColorConfig, Options, OutputFormat, RunIgnored};
+use super::helpers::shard::read_timings;
+use super::options::{ColorConfig, Options, OutputFormat, RunIgnored, TestShard};
 use super::time::TestTimeOptions;
 use std::io::{self, IsTerminal};
 
@@ -25,6 +26,13 @@ pub struct TestOpts {
     pub shuffle_seed: Option<u64>,
     pub test_threads: Option<usize>,
     pub skip: Vec<String>,
+    /// Number of times a failing test is run again before it is reported as failed.
+    pub retries: usize,
+    /// Filters of the tests whose failures are reported, but do not fail the test run.
+    pub quarantine: Vec<String>,
+    pub shard: Option<TestShard>,
+    /// File where the durations of the tests are recorded, and used to balance the shards.
+    pub partition_by_time: Option<PathBuf>,
     pub time_options: Option<TestTimeOptions>,
     /// Stop at first failing test.
     /// May run a few more tests due to threading, but will
@@ -148,6 +156,35 @@ fn optgroups() -> getopts::Options {
             "shuffle-seed",
             "Run tests in random order; seed the random number generator with SEED",
             "SEED",
+        )
+        .optopt(
+            "",
+            "retries",
+            "Run failing tests again up to N times; tests which eventually pass are \
+             reported as flaky",
+            "N",
+        )
+        .optmulti(
+            "",
+            "quarantine",
+            "Report failures of tests whose names contain FILTER without failing the \
+             test run (this flag can be used multiple times)",
+            "FILTER",
+        )
+        .optopt(
+            "",
+            "shard-index",
+            "Run only the tests of shard I, out of the shards given by --shard-total \
+             (starting from 0)",
+            "I",
+        )
+        .optopt("", "shard-total", "Split the tests in N shards, see --shard-index", "N")
+        .optopt(
+            "",
+            "partition-by-time",
+            "Balance the shards using the test durations recorded in PATH by previous \
+             runs, and record the durations of this run in PATH",
+            "PATH",
         );
     opts
 }
@@ -250,6 +287,21 @@ macro_rules! unstable_optopt {
     }};
 }
 
+// Gets the option values and checks if unstable features are enabled.
+macro_rules! unstable_optmulti {
+    ($matches:ident, $allow_unstable:ident, $option_name:literal) => {{
+        let opt = $matches.opt_strs($option_name);
+        if !$allow_unstable && !opt.is_empty() {
+            return Err(format!(
+                "The \"{}\" option is only accepted on the nightly compiler with -Z unstable-options",
+                $option_name
+            ));
+        }
+
+        opt
+    }};
+}
+
 // Implementation of `parse_opts` that doesn't care about help message
 // and returns a `Result`.
 fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
@@ -261,6 +313,11 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
     let time_options = get_time_options(&matches, allow_unstable)?;
     let shuffle = get_shuffle(&matches, allow_unstable)?;
     let shuffle_seed = get_shuffle_seed(&matches, allow_unstable)?;
+    let retries = get_retries(&matches, allow_unstable)?;
+    let quarantine = unstable_optmulti!(matches, allow_unstable, "quarantine");
+    let partition_by_time =
+        unstable_optopt!(matches, allow_unstable, "partition-by-time").map(PathBuf::from);
+    let shard = get_shard(&matches, allow_unstable, partition_by_time.as_deref())?;
 
     let include_ignored = matches.opt_present("include-ignored");
     let quiet = matches.opt_present("quiet");
@@ -277,7 +334,7 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
     let nocapture = get_nocapture(&matches)?;
     let test_threads = get_test_threads(&matches)?;
     let color = get_color_config(&matches)?;
-    let format = get_format(&matches, quiet, allow_unstable)?;
+    let format = get_format(&matches, quiet)?;
 
     let options = Options::new().display_output(matches.opt_present("show-output"));
 
@@ -298,6 +355,10 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         shuffle_seed,
         test_threads,
         skip,
+        retries,
+        quarantine,
+        shard,
+        partition_by_time,
         time_options,
         options,
         fail_fast: false,
@@ -375,6 +436,59 @@ fn get_shuffle_seed(matches: &getopts::Matches, allow_unstable: bool) -> OptPart
     Ok(shuffle_seed)
 }
 
+fn get_retries(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<usize> {
+    let retries = match unstable_optopt!(matches, allow_unstable, "retries") {
+        Some(n_str) => match n_str.parse::<usize>() {
+            Ok(n) => n,
+            Err(e) => {
+                return Err(format!(
+                    "argument for --retries must be a number \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => 0,
+    };
+
+    Ok(retries)
+}
+
+fn get_shard(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+    partition_by_time: Option<&Path>,
+) -> OptPartRes<Option<TestShard>> {
+    let parse = |option: &str, value: String| {
+        value.parse::<usize>().map_err(|e| {
+            format!(
+                "argument for --{option} must be a number \
+                 (error: {e})"
+            )
+        })
+    };
+    let index = unstable_optopt!(matches, allow_unstable, "shard-index");
+    let total = unstable_optopt!(matches, allow_unstable, "shard-total");
+    let (index, total) = match (index, total) {
+        (Some(index), Some(total)) => (parse("shard-index", index)?, parse("shard-total", total)?),
+        (None, None) => return Ok(None),
+        _ => return Err("--shard-index and --shard-total must be used together".to_string()),
+    };
+    if index >= total {
+        return Err(format!(
+            "argument for --shard-index must be less than --shard-total ({index} >= {total})"
+        ));
+    }
+
+    let timings = match partition_by_time {
+        Some(path) => Some(read_timings(path).map_err(|e| {
+            format!("failed to read the test durations from `{}`: {e}", path.display())
+        })?),
+        None => None,
+    };
+
+    Ok(Some(TestShard { index, total, timings }))
+}
+
 fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     let test_threads = match matches.opt_str("test-threads") {
         Some(n_str) => match n_str.parse::<usize>() {
@@ -393,27 +507,13 @@ fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     Ok(test_threads)
 }
 
-fn get_format(
-    matches: &getopts::Matches,
-    quiet: bool,
-    allow_unstable: bool,
-) -> OptPartRes<OutputFormat> {
+fn get_format(matches: &getopts::Matches, quiet: bool) -> OptPartRes<OutputFormat> {
     let format = match matches.opt_str("format").as_deref() {
         None if quiet => OutputFormat::Terse,
         Some("pretty") | None => OutputFormat::Pretty,
         Some("terse") => OutputFormat::Terse,
-        Some("json") => {
-            if !allow_unstable {
-                return Err("The \"json\" format is only accepted on the nightly compiler with -Z unstable-options".into());
-            }
-            OutputFormat::Json
-        }
-        Some("junit") => {
-            if !allow_unstable {
-                return Err("The \"junit\" format is only accepted on the nightly compiler with -Z unstable-options".into());
-            }
-            OutputFormat::Junit
-        }
+        Some("json") => OutputFormat::Json,
+        Some("junit") => OutputFormat::Junit,
         Some(v) => {
             return Err(format!(
                 "argument for --format must be pretty, terse, json or junit (was \
@@ -11,7 +11,7 @@
     event::{CompletedTest, TestEvent},
     filter_tests,
     formatters::{JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter, TerseFormatter},
-    helpers::{concurrency::get_concurrency, metrics::MetricMap},
+    helpers::{concurrency::get_concurrency, metrics::MetricMap, shard::write_timings},
     options::{Options, OutputFormat},
     run_tests, term,
     test_result::TestResult,
@@ -86,6 +86,8 @@ pub struct ConsoleTestState {
     pub total: usize,
     pub passed: usize,
     pub failed: usize,
+    pub flaky: usize,
+    pub quarantined: usize,
     pub ignored: usize,
     pub filtered_out: usize,
     pub measured: usize,
@@ -95,6 +97,8 @@ pub struct ConsoleTestState {
     pub not_failures: Vec<(TestDesc, Vec<u8>)>,
     pub ignores: Vec<(TestDesc, Vec<u8>)>,
     pub time_failures: Vec<(TestDesc, Vec<u8>)>,
+    pub flakes: Vec<(TestDesc, Vec<u8>)>,
+    pub quarantined_failures: Vec<(TestDesc, Vec<u8>)>,
     pub options: Options,
 }
 
@@ -110,6 +114,8 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             total: 0,
             passed: 0,
             failed: 0,
+            flaky: 0,
+            quarantined: 0,
             ignored: 0,
             filtered_out: 0,
             measured: 0,
@@ -119,6 +125,8 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             not_failures: Vec::new(),
             ignores: Vec::new(),
             time_failures: Vec::new(),
+            flakes: Vec::new(),
+            quarantined_failures: Vec::new(),
             options: opts.options,
         })
     }
@@ -161,6 +169,9 @@ pub fn write_log_result(
                     }
                     TestResult::TrBench(ref bs) => fmt_bench_samples(bs),
                     TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
+                    TestResult::TrFlaky(failed_attempts) => {
+                        format!("flaky (failed {failed_attempts} times)")
+                    }
                 },
                 name,
             )
@@ -172,7 +183,7 @@ pub fn write_log_result(
     }
 
     fn current_test_count(&self) -> usize {
-        self.passed + self.failed + self.ignored + self.measured
+        self.passed + self.failed + self.flaky + self.quarantined + self.ignored + self.measured
     }
 }
 
@@ -239,6 +250,20 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             );
             st.measured += 1
         }
+        TestResult::TrFlaky(_) => {
+            st.flaky += 1;
+            st.flakes.push((test, stdout));
+        }
+        TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrTimedFail
+            if completed_test.quarantined =>
+        {
+            let mut stdout = stdout;
+            if let TestResult::TrFailedMsg(msg) = completed_test.result {
+                stdout.extend_from_slice(format!("note: {msg}").as_bytes());
+            }
+            st.quarantined += 1;
+            st.quarantined_failures.push((test, stdout));
+        }
         TestResult::TrFailed => {
             st.failed += 1;
             st.failures.push((test, stdout));
@@ -326,9 +351,21 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
     let is_instant_supported = !cfg!(target_family = "wasm") && !cfg!(miri);
 
     let start_time = is_instant_supported.then(Instant::now);
-    run_tests(opts, tests, |x| on_test_event(&x, &mut st, &mut *out))?;
+    let mut timings = Vec::new();
+    run_tests(opts, tests, |x| {
+        if let TestEvent::TeResult(CompletedTest { ref desc, exec_time: Some(ref t), .. }) = x {
+            if opts.partition_by_time.is_some() {
+                timings.push((desc.name.as_slice().to_owned(), t.0));
+            }
+        }
+        on_test_event(&x, &mut st, &mut *out)
+    })?;
     st.exec_time = start_time.map(|t| TestSuiteExecTime(t.elapsed()));
 
+    if let Some(ref path) = opts.partition_by_time {
+        write_timings(path, timings)?;
+    }
+
     assert!(opts.fail_fast || st.current_test_count() == st.total);
 
     out.write_run_finish(&st)
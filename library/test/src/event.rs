@@ -12,6 +12,9 @@ pub struct CompletedTest {
     pub result: TestResult,
     pub exec_time: Option<TestExecTime>,
     pub stdout: Vec<u8>,
+    /// Whether the test matches a `--quarantine` filter, so that its failure does not fail the
+    /// test run.
+    pub quarantined: bool,
 }
 
 impl CompletedTest {
@@ -22,7 +25,7 @@ pub fn new(
         exec_time: Option<TestExecTime>,
         stdout: Vec<u8>,
     ) -> Self {
-        Self { id, desc, result, exec_time, stdout }
+        Self { id, desc, result, exec_time, stdout, quarantined: false }
     }
 }
 
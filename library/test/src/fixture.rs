@@ -0,0 +1,124 @@
+//! Test fixtures, registered with the `#[test_fixture]` attribute.
+//!
+//! A fixture is set up by calling its function, and torn down by dropping the value it returned.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+use super::types::TestDesc;
+
+/// The value returned by the setup of a fixture, which tears it down when dropped.
+pub type FixtureGuard = Box<dyn Any>;
+
+/// Which tests a fixture is set up for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FixtureScope {
+    /// Set up once before running the tests, and torn down after all of them completed.
+    Global,
+    /// Set up before and torn down after each test of the module with the given path, including
+    /// its submodules. The path of the crate root is empty.
+    Module(&'static str),
+}
+
+/// A fixture generated by `#[test_fixture]`.
+#[derive(Copy, Clone, Debug)]
+pub struct TestFixture {
+    pub scope: FixtureScope,
+    pub setup: fn() -> FixtureGuard,
+}
+
+/// Invoked to set up a fixture, boxing the value returned by the fixture function.
+pub fn fixture_guard<T: 'static>(value: T) -> FixtureGuard {
+    Box::new(value)
+}
+
+impl FixtureScope {
+    fn applies_to(&self, desc: &TestDesc) -> bool {
+        match *self {
+            FixtureScope::Global => false,
+            FixtureScope::Module("") => true,
+            FixtureScope::Module(path) => {
+                desc.name.as_slice().strip_prefix(path).is_some_and(|name| name.starts_with("::"))
+            }
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match *self {
+            FixtureScope::Global | FixtureScope::Module("") => 0,
+            FixtureScope::Module(path) => path.split("::").count(),
+        }
+    }
+}
+
+/// The fixtures which were set up, torn down in the reverse order when dropped.
+pub(crate) struct FixtureGuards(Vec<FixtureGuard>);
+
+impl Drop for FixtureGuards {
+    fn drop(&mut self) {
+        while let Some(guard) = self.0.pop() {
+            drop(guard);
+        }
+    }
+}
+
+fn setup_fixtures<'a>(fixtures: impl Iterator<Item = &'a TestFixture>) -> FixtureGuards {
+    // If a fixture panics, the ones which were already set up are torn down while unwinding.
+    let mut guards = FixtureGuards(Vec::new());
+    for fixture in fixtures {
+        guards.0.push((fixture.setup)());
+    }
+    guards
+}
+
+/// Sets up the global fixtures.
+pub(crate) fn setup_global_fixtures(fixtures: &[&TestFixture]) -> FixtureGuards {
+    setup_fixtures(fixtures.iter().copied().filter(|f| f.scope == FixtureScope::Global))
+}
+
+/// The fixtures of the test, the fixtures of outer modules first.
+fn test_fixtures<'a>(fixtures: &[&'a TestFixture], desc: &TestDesc) -> Vec<&'a TestFixture> {
+    let mut applicable: Vec<_> =
+        fixtures.iter().copied().filter(|f| f.scope.applies_to(desc)).collect();
+    applicable.sort_by_key(|f| f.scope.depth());
+    applicable
+}
+
+/// Runs `f` between the setup and the teardown of the fixtures of the test, the fixtures of
+/// outer modules being set up first. The fixtures are torn down even if `f` panics.
+pub(crate) fn run_with_fixtures<T>(
+    fixtures: &[&TestFixture],
+    desc: &TestDesc,
+    f: impl FnOnce() -> T,
+) -> T {
+    let applicable = test_fixtures(fixtures, desc);
+    if applicable.is_empty() {
+        return f();
+    }
+
+    let guards = setup_fixtures(applicable.into_iter());
+    let result = catch_unwind(AssertUnwindSafe(f));
+    drop(guards);
+    result.unwrap_or_else(|payload| resume_unwind(payload))
+}
+
+thread_local! {
+    /// The fixtures of the test run by a panic=abort subprocess.
+    static SUBPROCESS_FIXTURES: RefCell<Option<FixtureGuards>> = RefCell::new(None);
+}
+
+/// Sets up the fixtures of the test run by a panic=abort subprocess. That process exits from
+/// the panic hook when the test panics, so they're torn down by
+/// [`teardown_subprocess_fixtures`] instead of being dropped at the end of a scope.
+pub(crate) fn setup_subprocess_fixtures(fixtures: &[&TestFixture], desc: &TestDesc) {
+    let guards = setup_fixtures(test_fixtures(fixtures, desc).into_iter());
+    SUBPROCESS_FIXTURES.with(|cell| *cell.borrow_mut() = Some(guards));
+}
+
+/// Tears down the fixtures set up by [`setup_subprocess_fixtures`] on this thread, if any.
+pub(crate) fn teardown_subprocess_fixtures() {
+    // Taken out of the cell first, so that a panicking teardown doesn't find it borrowed.
+    let guards = SUBPROCESS_FIXTURES.with(|cell| cell.borrow_mut().take());
+    drop(guards);
+}
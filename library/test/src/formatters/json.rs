@@ -1,8 +1,10 @@
 use std::{borrow::Cow, io, io::prelude::Write};
 
-use super::OutputFormatter;
+use super::{split_stderr, OutputFormatter};
 use crate::{
     console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    is_quarantined,
+    options::ShouldPanic,
     test_result::TestResult,
     time,
     types::TestDesc,
@@ -33,6 +35,7 @@ fn write_event(
         event: &str,
         exec_time: Option<&time::TestExecTime>,
         stdout: Option<Cow<'_, str>>,
+        stderr: Option<Cow<'_, str>>,
         extra: Option<&str>,
     ) -> io::Result<()> {
         // A doc test's name includes a filename which must be escaped for correct json.
@@ -47,12 +50,17 @@ fn write_event(
         } else {
             String::from("")
         };
+        let stderr_json = if let Some(stderr) = stderr {
+            format!(r#", "stderr": "{}""#, EscapedString(stderr))
+        } else {
+            String::from("")
+        };
         let extra_json =
             if let Some(extra) = extra { format!(r#", {extra}"#) } else { String::from("") };
         let newline = "\n";
 
         self.writeln_message(&format!(
-                r#"{{ "type": "{ty}", "name": "{name}", "event": "{event}"{exec_time_json}{stdout_json}{extra_json} }}{newline}"#))
+                r#"{{ "type": "{ty}", "name": "{name}", "event": "{event}"{exec_time_json}{stdout_json}{stderr_json}{extra_json} }}{newline}"#))
     }
 }
 
@@ -108,9 +116,17 @@ fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> i
 
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
         let name = EscapedString(desc.name.as_slice());
+        let should_panic_json = match desc.should_panic {
+            ShouldPanic::No => String::new(),
+            ShouldPanic::Yes => String::from(r#", "should_panic": true"#),
+            ShouldPanic::YesWithMessage(msg) => format!(
+                r#", "should_panic": true, "should_panic_message": "{}""#,
+                EscapedString(msg)
+            ),
+        };
         let newline = "\n";
         self.writeln_message(&format!(
-            r#"{{ "type": "test", "event": "started", "name": "{name}" }}{newline}"#
+            r#"{{ "type": "test", "event": "started", "name": "{name}"{should_panic_json} }}{newline}"#
         ))
     }
 
@@ -122,20 +138,34 @@ fn write_result(
         stdout: &[u8],
         state: &ConsoleTestState,
     ) -> io::Result<()> {
-        let display_stdout = state.options.display_output || *result != TestResult::TrOk;
-        let stdout = if display_stdout && !stdout.is_empty() {
-            Some(String::from_utf8_lossy(stdout))
-        } else {
-            None
-        };
+        let display_output = state.options.display_output || *result != TestResult::TrOk;
+        let (stdout, stderr) =
+            if display_output { split_stderr(stdout, &desc.name) } else { (&[][..], &[][..]) };
+        let stdout = if !stdout.is_empty() { Some(String::from_utf8_lossy(stdout)) } else { None };
+        let stderr = if !stderr.is_empty() { Some(String::from_utf8_lossy(stderr)) } else { None };
+        // Failures of quarantined tests don't fail the run, so consumers need to tell them apart.
+        let quarantined = is_quarantined(&state.options, desc);
+        let quarantined_json = if quarantined { r#", "quarantined": true"# } else { "" };
         match *result {
-            TestResult::TrOk => {
-                self.write_event("test", desc.name.as_slice(), "ok", exec_time, stdout, None)
-            }
+            TestResult::TrOk => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "ok",
+                exec_time,
+                stdout,
+                stderr,
+                None,
+            ),
 
-            TestResult::TrFailed => {
-                self.write_event("test", desc.name.as_slice(), "failed", exec_time, stdout, None)
-            }
+            TestResult::TrFailed => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "failed",
+                exec_time,
+                stdout,
+                stderr,
+                quarantined.then_some(r#""quarantined": true"#),
+            ),
 
             TestResult::TrTimedFail => self.write_event(
                 "test",
@@ -143,7 +173,8 @@ fn write_result(
                 "failed",
                 exec_time,
                 stdout,
-                Some(r#""reason": "time limit exceeded""#),
+                stderr,
+                Some(&*format!(r#""reason": "time limit exceeded"{quarantined_json}"#)),
             ),
 
             TestResult::TrFailedMsg(ref m) => self.write_event(
@@ -152,7 +183,18 @@ fn write_result(
                 "failed",
                 exec_time,
                 stdout,
-                Some(&*format!(r#""message": "{}""#, EscapedString(m))),
+                stderr,
+                Some(&*format!(r#""message": "{}"{quarantined_json}"#, EscapedString(m))),
+            ),
+
+            TestResult::TrFlaky(failed_attempts) => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "flaky",
+                exec_time,
+                stdout,
+                stderr,
+                Some(&*format!(r#""failed_attempts": {failed_attempts}"#)),
             ),
 
             TestResult::TrIgnored => self.write_event(
@@ -161,6 +203,7 @@ fn write_result(
                 "ignored",
                 exec_time,
                 stdout,
+                stderr,
                 desc.ignore_message
                     .map(|msg| format!(r#""message": "{}""#, EscapedString(msg)))
                     .as_deref(),
@@ -199,6 +242,8 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         let event = if state.failed == 0 { "ok" } else { "failed" };
         let passed = state.passed;
         let failed = state.failed;
+        let flaky = state.flaky;
+        let quarantined = state.quarantined;
         let ignored = state.ignored;
         let measured = state.measured;
         let filtered_out = state.filtered_out;
@@ -210,7 +255,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         let newline = "\n";
 
         self.writeln_message(&format!(
-            r#"{{ "type": "suite", "event": "{event}", "passed": {passed}, "failed": {failed}, "ignored": {ignored}, "measured": {measured}, "filtered_out": {filtered_out}{exec_time_json} }}{newline}"#
+            r#"{{ "type": "suite", "event": "{event}", "passed": {passed}, "failed": {failed}, "flaky": {flaky}, "quarantined": {quarantined}, "ignored": {ignored}, "measured": {measured}, "filtered_out": {filtered_out}{exec_time_json} }}{newline}"#
         ))?;
 
         Ok(state.failed == 0)
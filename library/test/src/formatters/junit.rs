@@ -1,9 +1,11 @@
+use std::fmt;
 use std::io::{self, prelude::Write};
 use std::time::Duration;
 
-use super::OutputFormatter;
+use super::{split_stderr, OutputFormatter};
 use crate::{
     console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    is_quarantined,
     test_result::TestResult,
     time,
     types::{TestDesc, TestType},
@@ -11,12 +13,13 @@
 
 pub struct JunitFormatter<T> {
     out: OutputLocation<T>,
+    shuffle_seed: Option<u64>,
     results: Vec<(TestDesc, TestResult, Duration, Vec<u8>)>,
 }
 
 impl<T: Write> JunitFormatter<T> {
     pub fn new(out: OutputLocation<T>) -> Self {
-        Self { out, results: Vec::new() }
+        Self { out, shuffle_seed: None, results: Vec::new() }
     }
 
     fn write_message(&mut self, s: &str) -> io::Result<()> {
@@ -24,6 +27,15 @@ fn write_message(&mut self, s: &str) -> io::Result<()> {
 
         self.out.write_all(s.as_ref())
     }
+
+    fn write_output(&mut self, tag: &str, output: &[u8]) -> io::Result<()> {
+        if !output.is_empty() {
+            self.write_message(&format!("<{tag}>"))?;
+            self.write_message(&str_to_cdata(&String::from_utf8_lossy(output)))?;
+            self.write_message(&format!("</{tag}>"))?;
+        }
+        Ok(())
+    }
 }
 
 fn str_to_cdata(s: &str) -> String {
@@ -51,11 +63,9 @@ fn write_discovery_finish(&mut self, _state: &ConsoleTestDiscoveryState) -> io::
         Err(io::Error::new(io::ErrorKind::NotFound, "Not yet implemented!"))
     }
 
-    fn write_run_start(
-        &mut self,
-        _test_count: usize,
-        _shuffle_seed: Option<u64>,
-    ) -> io::Result<()> {
+    fn write_run_start(&mut self, _test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
+        // The seed is reported as a property of the testsuite, once the run is complete.
+        self.shuffle_seed = shuffle_seed;
         // We write xml header on run start
         self.write_message("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
     }
@@ -85,94 +95,95 @@ fn write_result(
         self.results.push((desc.clone(), result.clone(), duration, stdout.to_vec()));
         Ok(())
     }
+
     fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         self.write_message("<testsuites>")?;
 
+        let suite_time = state.exec_time.as_ref().map(|t| t.0).unwrap_or_default();
         self.write_message(&format!(
             "<testsuite name=\"test\" package=\"test\" id=\"0\" \
              errors=\"0\" \
              failures=\"{}\" \
              tests=\"{}\" \
              skipped=\"{}\" \
+             time=\"{}\" \
              >",
-            state.failed, state.total, state.ignored
+            state.failed + state.quarantined,
+            state.total,
+            state.ignored,
+            suite_time.as_secs_f64()
         ))?;
+        if let Some(shuffle_seed) = self.shuffle_seed {
+            self.write_message(&format!(
+                "<properties><property name=\"shuffle_seed\" value=\"{shuffle_seed}\"/>\
+                 </properties>"
+            ))?;
+        }
         for (desc, result, duration, stdout) in std::mem::take(&mut self.results) {
             let (class_name, test_name) = parse_class_name(&desc);
-            match result {
-                TestResult::TrIgnored => { /* no-op */ }
-                TestResult::TrFailed => {
-                    self.write_message(&format!(
-                        "<testcase classname=\"{}\" \
-                         name=\"{}\" time=\"{}\">",
-                        class_name,
-                        test_name,
-                        duration.as_secs_f64()
-                    ))?;
-                    self.write_message("<failure type=\"assert\"/>")?;
-                    if !stdout.is_empty() {
-                        self.write_message("<system-out>")?;
-                        self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
-                        self.write_message("</system-out>")?;
-                    }
-                    self.write_message("</testcase>")?;
-                }
-
-                TestResult::TrFailedMsg(ref m) => {
-                    self.write_message(&format!(
-                        "<testcase classname=\"{}\" \
-                         name=\"{}\" time=\"{}\">",
-                        class_name,
-                        test_name,
-                        duration.as_secs_f64()
-                    ))?;
-                    self.write_message(&format!("<failure message=\"{m}\" type=\"assert\"/>"))?;
-                    if !stdout.is_empty() {
-                        self.write_message("<system-out>")?;
-                        self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
-                        self.write_message("</system-out>")?;
-                    }
-                    self.write_message("</testcase>")?;
-                }
-
-                TestResult::TrTimedFail => {
-                    self.write_message(&format!(
-                        "<testcase classname=\"{}\" \
-                         name=\"{}\" time=\"{}\">",
-                        class_name,
-                        test_name,
-                        duration.as_secs_f64()
-                    ))?;
-                    self.write_message("<failure type=\"timeout\"/>")?;
-                    self.write_message("</testcase>")?;
-                }
+            let (class_name, test_name) = (XmlEscaped(&class_name), XmlEscaped(&test_name));
 
+            // The element describing the outcome of the test, and whether its output is reported.
+            let (outcome, display_output) = match result {
                 TestResult::TrBench(ref b) => {
                     self.write_message(&format!(
                         "<testcase classname=\"benchmark::{}\" \
                          name=\"{}\" time=\"{}\" />",
                         class_name, test_name, b.ns_iter_summ.sum
                     ))?;
+                    continue;
                 }
-
-                TestResult::TrOk => {
-                    self.write_message(&format!(
-                        "<testcase classname=\"{}\" \
-                         name=\"{}\" time=\"{}\"",
-                        class_name,
-                        test_name,
-                        duration.as_secs_f64()
-                    ))?;
-                    if stdout.is_empty() || !state.options.display_output {
-                        self.write_message("/>")?;
-                    } else {
-                        self.write_message("><system-out>")?;
-                        self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
-                        self.write_message("</system-out>")?;
-                        self.write_message("</testcase>")?;
-                    }
+                TestResult::TrOk => (None, state.options.display_output),
+                TestResult::TrIgnored => {
+                    let skipped = match desc.ignore_message {
+                        Some(msg) => format!("<skipped message=\"{}\"/>", XmlEscaped(msg)),
+                        None => String::from("<skipped/>"),
+                    };
+                    (Some(skipped), false)
                 }
+                TestResult::TrFailed => (Some(String::from("<failure type=\"assert\"/>")), true),
+                TestResult::TrFailedMsg(ref m) => (
+                    Some(format!("<failure message=\"{}\" type=\"assert\"/>", XmlEscaped(m))),
+                    true,
+                ),
+                TestResult::TrTimedFail => {
+                    (Some(String::from("<failure type=\"timeout\"/>")), true)
+                }
+                // Reported like the reruns of the Maven Surefire plugin.
+                TestResult::TrFlaky(failed_attempts) => {
+                    (Some("<flakyFailure type=\"assert\"/>".repeat(failed_attempts)), true)
+                }
+            };
+            let quarantined = matches!(
+                result,
+                TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrTimedFail
+            ) && is_quarantined(&state.options, &desc);
+            let (stdout, stderr) =
+                if display_output { split_stderr(&stdout, &desc.name) } else { (&[][..], &[][..]) };
+
+            self.write_message(&format!(
+                "<testcase classname=\"{}\" \
+                 name=\"{}\" time=\"{}\"",
+                class_name,
+                test_name,
+                duration.as_secs_f64()
+            ))?;
+            if outcome.is_none() && stdout.is_empty() && stderr.is_empty() {
+                self.write_message("/>")?;
+                continue;
             }
+            self.write_message(">")?;
+            if quarantined {
+                self.write_message(
+                    "<properties><property name=\"quarantined\" value=\"true\"/></properties>",
+                )?;
+            }
+            if let Some(outcome) = outcome {
+                self.write_message(&outcome)?;
+            }
+            self.write_output("system-out", stdout)?;
+            self.write_output("system-err", stderr)?;
+            self.write_message("</testcase>")?;
         }
         self.write_message("<system-out/>")?;
         self.write_message("<system-err/>")?;
@@ -185,6 +196,34 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
     }
 }
 
+/// A formatting utility used to print strings as XML attribute values.
+struct XmlEscaped<'a>(&'a str);
+
+impl fmt::Display for XmlEscaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut start = 0;
+
+        for (i, c) in self.0.char_indices() {
+            let escaped = match c {
+                '&' => "&amp;",
+                '<' => "&lt;",
+                '>' => "&gt;",
+                '"' => "&quot;",
+                '\'' => "&apos;",
+                '\n' => "&#xA;",
+                '\r' => "&#xD;",
+                '\t' => "&#x9;",
+                _ => continue,
+            };
+            f.write_str(&self.0[start..i])?;
+            f.write_str(escaped)?;
+            start = i + c.len_utf8();
+        }
+
+        f.write_str(&self.0[start..])
+    }
+}
+
 fn parse_class_name(desc: &TestDesc) -> (String, String) {
     match desc.test_type {
         TestType::UnitTest => parse_class_name_unit(desc),
@@ -44,3 +44,18 @@ pub(crate) fn write_stderr_delimiter(test_output: &mut Vec<u8>, test_name: &Test
     }
     writeln!(test_output, "---- {test_name} stderr ----").unwrap();
 }
+
+/// Splits the captured output of a test at the delimiter written by [`write_stderr_delimiter`].
+/// The output of tests which did not run in a subprocess is all reported as stdout.
+pub(crate) fn split_stderr<'a>(
+    test_output: &'a [u8],
+    test_name: &TestName,
+) -> (&'a [u8], &'a [u8]) {
+    let delimiter = format!("---- {test_name} stderr ----\n");
+    let position =
+        test_output.windows(delimiter.len()).position(|window| window == delimiter.as_bytes());
+    match position {
+        Some(position) => (&test_output[..position], &test_output[position + delimiter.len()..]),
+        None => (test_output, &[]),
+    }
+}
@@ -57,6 +57,14 @@ pub fn write_time_failed(&mut self) -> io::Result<()> {
         self.write_short_result("FAILED (time limit exceeded)", term::color::RED)
     }
 
+    pub fn write_flaky(&mut self, failed_attempts: usize) -> io::Result<()> {
+        let noun = if failed_attempts != 1 { "times" } else { "time" };
+        self.write_short_result(
+            &format!("ok (flaky, failed {failed_attempts} {noun})"),
+            term::color::YELLOW,
+        )
+    }
+
     pub fn write_bench(&mut self) -> io::Result<()> {
         self.write_pretty("bench", term::color::CYAN)
     }
@@ -168,6 +176,14 @@ pub fn write_time_failures(&mut self, state: &ConsoleTestState) -> io::Result<()
         self.write_results(&state.time_failures, "failures (time limit exceeded)")
     }
 
+    pub fn write_flakes(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.flakes, "flaky")
+    }
+
+    pub fn write_quarantined_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.quarantined_failures, "failures (quarantined)")
+    }
+
     fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
         let name = desc.padded_name(self.max_name_len, desc.name.padding());
         if let Some(test_mode) = desc.test_mode() {
@@ -251,6 +267,7 @@ fn write_result(
                 self.write_plain(format!(": {}", fmt_bench_samples(bs)))?;
             }
             TestResult::TrTimedFail => self.write_time_failed()?,
+            TestResult::TrFlaky(failed_attempts) => self.write_flaky(failed_attempts)?,
         }
 
         self.write_time(desc, exec_time)?;
@@ -279,6 +296,12 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
                 self.write_time_failures(state)?;
             }
         }
+        if !state.flakes.is_empty() {
+            self.write_flakes(state)?;
+        }
+        if !state.quarantined_failures.is_empty() {
+            self.write_quarantined_failures(state)?;
+        }
 
         self.write_plain("\ntest result: ")?;
 
@@ -289,10 +312,17 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_pretty("FAILED", term::color::RED)?;
         }
 
-        let s = format!(
-            ". {} passed; {} failed; {} ignored; {} measured; {} filtered out",
-            state.passed, state.failed, state.ignored, state.measured, state.filtered_out
-        );
+        let mut s = format!(". {} passed; {} failed", state.passed, state.failed);
+        if state.flaky != 0 {
+            s.push_str(&format!("; {} flaky", state.flaky));
+        }
+        if state.quarantined != 0 {
+            s.push_str(&format!("; {} quarantined", state.quarantined));
+        }
+        s.push_str(&format!(
+            "; {} ignored; {} measured; {} filtered out",
+            state.ignored, state.measured, state.filtered_out
+        ));
 
         self.write_plain(s)?;
 
@@ -55,6 +55,10 @@ pub fn write_ignored(&mut self) -> io::Result<()> {
         self.write_short_result("i", term::color::YELLOW)
     }
 
+    pub fn write_flaky(&mut self) -> io::Result<()> {
+        self.write_short_result("f", term::color::YELLOW)
+    }
+
     pub fn write_bench(&mut self) -> io::Result<()> {
         self.write_pretty("bench", term::color::CYAN)
     }
@@ -128,32 +132,50 @@ pub fn write_outputs(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         Ok(())
     }
 
-    pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
-        self.write_plain("\nfailures:\n")?;
-        let mut failures = Vec::new();
-        let mut fail_out = String::new();
-        for (f, stdout) in &state.failures {
-            failures.push(f.name.to_string());
+    fn write_results(
+        &mut self,
+        inputs: &Vec<(TestDesc, Vec<u8>)>,
+        results_type: &str,
+    ) -> io::Result<()> {
+        let results_out_str = format!("\n{results_type}:\n");
+
+        self.write_plain(&results_out_str)?;
+        let mut results = Vec::new();
+        let mut results_out = String::new();
+        for (f, stdout) in inputs {
+            results.push(f.name.to_string());
             if !stdout.is_empty() {
-                fail_out.push_str(&format!("---- {} stdout ----\n", f.name));
+                results_out.push_str(&format!("---- {} stdout ----\n", f.name));
                 let output = String::from_utf8_lossy(stdout);
-                fail_out.push_str(&output);
-                fail_out.push('\n');
+                results_out.push_str(&output);
+                results_out.push('\n');
             }
         }
-        if !fail_out.is_empty() {
+        if !results_out.is_empty() {
             self.write_plain("\n")?;
-            self.write_plain(&fail_out)?;
+            self.write_plain(&results_out)?;
         }
 
-        self.write_plain("\nfailures:\n")?;
-        failures.sort();
-        for name in &failures {
+        self.write_plain(&results_out_str)?;
+        results.sort();
+        for name in &results {
             self.write_plain(&format!("    {name}\n"))?;
         }
         Ok(())
     }
 
+    pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.failures, "failures")
+    }
+
+    pub fn write_flakes(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.flakes, "flaky")
+    }
+
+    pub fn write_quarantined_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.quarantined_failures, "failures (quarantined)")
+    }
+
     fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
         let name = desc.padded_name(self.max_name_len, desc.name.padding());
         if let Some(test_mode) = desc.test_mode() {
@@ -216,6 +238,7 @@ fn write_result(
                 self.write_failed()
             }
             TestResult::TrIgnored => self.write_ignored(),
+            TestResult::TrFlaky(_) => self.write_flaky(),
             TestResult::TrBench(ref bs) => {
                 if self.is_multithreaded {
                     self.write_test_name(desc)?;
@@ -242,6 +265,12 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         if !success {
             self.write_failures(state)?;
         }
+        if !state.flakes.is_empty() {
+            self.write_flakes(state)?;
+        }
+        if !state.quarantined_failures.is_empty() {
+            self.write_quarantined_failures(state)?;
+        }
 
         self.write_plain("\ntest result: ")?;
 
@@ -252,10 +281,17 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_pretty("FAILED", term::color::RED)?;
         }
 
-        let s = format!(
-            ". {} passed; {} failed; {} ignored; {} measured; {} filtered out",
-            state.passed, state.failed, state.ignored, state.measured, state.filtered_out
-        );
+        let mut s = format!(". {} passed; {} failed", state.passed, state.failed);
+        if state.flaky != 0 {
+            s.push_str(&format!("; {} flaky", state.flaky));
+        }
+        if state.quarantined != 0 {
+            s.push_str(&format!("; {} quarantined", state.quarantined));
+        }
+        s.push_str(&format!(
+            "; {} ignored; {} measured; {} filtered out",
+            state.ignored, state.measured, state.filtered_out
+        ));
 
         self.write_plain(s)?;
 
@@ -0,0 +1,112 @@
+//! Splitting the tests in shards, which together run all the tests.
+
+use crate::options::TestShard;
+use crate::types::TestDescAndFn;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::hash::{DefaultHasher, Hasher};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Keeps the tests of the given shard.
+///
+/// The tests are balanced using their durations in a previous run if available, and assigned
+/// using a hash of their names otherwise. Either way, every shard must be given the same tests
+/// so that each test runs in exactly one of them.
+pub fn shard_tests(shard: &TestShard, tests: &mut Vec<TestDescAndFn>) {
+    let shards = match shard.timings {
+        Some(ref timings) => partition_by_time(shard.total, tests, timings),
+        None => tests
+            .iter()
+            .map(|test| (hash_name(test.desc.name.as_slice()) % shard.total as u64) as usize)
+            .collect(),
+    };
+    let mut shards = shards.into_iter();
+    tests.retain(|_| shards.next() == Some(shard.index));
+}
+
+fn hash_name(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(name.as_bytes());
+    hasher.finish()
+}
+
+// Assigns the longest tests first, each to the shard with the shortest total duration so far.
+fn partition_by_time(
+    total: usize,
+    tests: &[TestDescAndFn],
+    timings: &HashMap<String, Duration>,
+) -> Vec<usize> {
+    // Tests which did not run before are assumed to take the average duration.
+    let known: Vec<Duration> =
+        tests.iter().filter_map(|test| timings.get(test.desc.name.as_slice())).copied().collect();
+    let default = match known.len() {
+        0 => Duration::from_secs(1),
+        len => known.iter().sum::<Duration>() / len as u32,
+    };
+    let durations: Vec<Duration> = tests
+        .iter()
+        .map(|test| {
+            if test.desc.ignore {
+                Duration::ZERO
+            } else {
+                timings.get(test.desc.name.as_slice()).copied().unwrap_or(default)
+            }
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..tests.len()).collect();
+    order.sort_by(|&a, &b| {
+        let name = |i: usize| tests[i].desc.name.as_slice();
+        durations[b].cmp(&durations[a]).then_with(|| name(a).cmp(name(b)))
+    });
+
+    let mut shard_durations = vec![Duration::ZERO; total];
+    let mut shards = vec![0; tests.len()];
+    for i in order {
+        let (shard, _) = shard_durations.iter().enumerate().min_by_key(|&(_, d)| *d).unwrap();
+        shard_durations[shard] += durations[i];
+        shards[i] = shard;
+    }
+    shards
+}
+
+/// Reads the durations of the tests recorded by previous runs. A missing file is treated as empty.
+///
+/// Each line holds the duration in seconds, followed by a space and the test name. When a test
+/// appears more than once, the last line wins, so that the files written by each shard can be
+/// concatenated.
+pub fn read_timings(path: &Path) -> io::Result<HashMap<String, Duration>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut timings = HashMap::new();
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        let invalid_line =
+            || io::Error::new(io::ErrorKind::InvalidData, format!("invalid timing: `{line}`"));
+        let (secs, name) = line.split_once(' ').ok_or_else(invalid_line)?;
+        let secs = secs.parse::<f64>().map_err(|_| invalid_line())?;
+        let duration = Duration::try_from_secs_f64(secs).map_err(|_| invalid_line())?;
+        timings.insert(name.to_owned(), duration);
+    }
+    Ok(timings)
+}
+
+/// Records the durations of the tests of this run, along with the ones previously recorded for
+/// other tests.
+pub fn write_timings(path: &Path, new_timings: Vec<(String, Duration)>) -> io::Result<()> {
+    let mut timings = read_timings(path)?;
+    timings.extend(new_timings);
+
+    let mut timings: Vec<_> = timings.into_iter().collect();
+    timings.sort();
+    let mut file = BufWriter::new(File::create(path)?);
+    for (name, duration) in timings {
+        writeln!(file, "{} {name}", duration.as_secs_f64())?;
+    }
+    file.flush()
+}
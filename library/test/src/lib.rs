@@ -28,7 +28,8 @@
 // Public reexports
 pub use self::bench::{black_box, Bencher};
 pub use self::console::run_tests_console;
-pub use self::options::{ColorConfig, Options, OutputFormat, RunIgnored, ShouldPanic};
+pub use self::fixture::{fixture_guard, FixtureGuard, FixtureScope, TestFixture};
+pub use self::options::{ColorConfig, Options, OutputFormat, RunIgnored, ShouldPanic, TestShard};
 pub use self::types::TestName::*;
 pub use self::types::*;
 pub use self::ColorConfig::*;
@@ -70,6 +71,7 @@ pub mod test {
 mod cli;
 mod console;
 mod event;
+mod fixture;
 mod formatters;
 mod helpers;
 mod options;
@@ -86,6 +88,7 @@ pub mod test {
 use event::{CompletedTest, TestEvent};
 use helpers::concurrency::get_concurrency;
 use helpers::exit_code::get_exit_code;
+use helpers::shard::shard_tests;
 use helpers::shuffle::{get_shuffle_seed, shuffle_tests};
 use options::RunStrategy;
 use test_result::*;
@@ -109,7 +112,9 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
         None => return,
     };
     if let Some(options) = options {
-        opts.options = options;
+        // `--show-output` is only known from the command line.
+        opts.options =
+            options.display_output(options.display_output || opts.options.display_output);
     }
     if opts.list {
         if let Err(e) = console::list_tests_console(&opts, tests) {
@@ -162,6 +167,19 @@ pub fn test_main_static(tests: &[&TestDescAndFn]) {
     test_main(&args, owned_tests, None)
 }
 
+/// A variant of `test_main_static` which runs the given fixtures around the tests.
+///
+/// This is the entry point for the main function generated by `rustc --test`
+/// when panic=unwind and the crate has `#[test_fixture]`s.
+pub fn test_main_static_with_fixtures(
+    tests: &[&TestDescAndFn],
+    fixtures: &'static [&'static TestFixture],
+) {
+    let args = env::args().collect::<Vec<_>>();
+    let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
+    test_main(&args, owned_tests, Some(Options::new().fixtures(fixtures)))
+}
+
 /// A variant optimized for invocation with a static test vector.
 /// This will panic (intentionally) when fed any dynamic tests.
 ///
@@ -171,6 +189,21 @@ pub fn test_main_static(tests: &[&TestDescAndFn]) {
 /// This is the entry point for the main function generated by `rustc --test`
 /// when panic=abort.
 pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
+    test_main_static_abort_with_fixtures(tests, &[])
+}
+
+/// A variant of `test_main_static_abort` which runs the given fixtures around the tests.
+///
+/// The global fixtures run in the primary process, around all the subprocesses.
+/// The fixtures of each test run in the subprocess spawned for it, around the
+/// test, and are torn down by the panic hook when the test panics.
+///
+/// This is the entry point for the main function generated by `rustc --test`
+/// when panic=abort and the crate has `#[test_fixture]`s.
+pub fn test_main_static_abort_with_fixtures(
+    tests: &[&TestDescAndFn],
+    fixtures: &'static [&'static TestFixture],
+) {
     // If we're being run in SpawnedSecondary mode, run the test here. run_test
     // will then exit the process.
     if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
@@ -194,7 +227,7 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
                 if runnable_test.is_dynamic() {
                     panic!("only static tests are supported");
                 }
-                run_test_in_spawned_subprocess(desc, runnable_test);
+                run_test_in_spawned_subprocess(desc, runnable_test, fixtures);
             }
             Runnable::Bench(_) => {
                 panic!("benchmarks should not be executed into child processes")
@@ -204,7 +237,7 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
 
     let args = env::args().collect::<Vec<_>>();
     let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
-    test_main(&args, owned_tests, Some(Options::new().panic_abort(true)))
+    test_main(&args, owned_tests, Some(Options::new().panic_abort(true).fixtures(fixtures)))
 }
 
 /// Clones static values for putting into a dynamic vector, which test_main()
@@ -294,6 +327,16 @@ struct TimeoutEntry {
         timeout: Instant,
     }
 
+    /// A test which is run again when it fails, see `--retries`.
+    struct RetriedTest {
+        testfn: TestFn,
+        failed_attempts: usize,
+        /// The output of the previous attempts.
+        stdout: Vec<u8>,
+    }
+
+    type RetryMap = HashMap<TestId, RetriedTest, BuildHasherDefault<DefaultHasher>>;
+
     let tests_len = tests.len();
 
     let mut filtered = FilteredTests { tests: Vec::new(), benches: Vec::new(), next_id: 0 };
@@ -335,6 +378,15 @@ struct TimeoutEntry {
     let concurrency = opts.test_threads.unwrap_or_else(get_concurrency);
 
     let mut remaining = filtered.tests;
+    if opts.retries > 0 {
+        let not_retried = remaining.iter().filter(|test| test.testfn.try_clone().is_none()).count();
+        if not_retried > 0 {
+            eprintln!(
+                "warning: --retries does not apply to {not_retried} dynamic test(s), \
+                 like doctests, which can only be run once"
+            );
+        }
+    }
     if let Some(shuffle_seed) = shuffle_seed {
         shuffle_tests(shuffle_seed, &mut remaining);
     }
@@ -352,6 +404,10 @@ struct TimeoutEntry {
 
     let mut running_tests: TestMap = HashMap::default();
     let mut timeout_queue: VecDeque<TimeoutEntry> = VecDeque::new();
+    let mut retried_tests: RetryMap = HashMap::default();
+
+    // Torn down when returning, once all the tests completed.
+    let _global_fixtures = fixture::setup_global_fixtures(opts.options.fixtures);
 
     fn get_timed_out_tests(
         running_tests: &TestMap,
@@ -378,19 +434,81 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
         })
     }
 
+    fn register_retries(
+        opts: &TestOpts,
+        retried_tests: &mut RetryMap,
+        id: TestId,
+        test: &TestDescAndFn,
+    ) {
+        // Dynamic tests can only be run once.
+        if opts.retries > 0 {
+            if let Some(testfn) = test.testfn.try_clone() {
+                let retried_test = RetriedTest { testfn, failed_attempts: 0, stdout: Vec::new() };
+                retried_tests.insert(id, retried_test);
+            }
+        }
+    }
+
+    // Returns the test to run again if it failed and has retries left. Otherwise, the result of
+    // the last attempt is reported, along with the output of all attempts.
+    fn retry_failed_test(
+        opts: &TestOpts,
+        retried_tests: &mut RetryMap,
+        completed_test: &mut CompletedTest,
+    ) -> Option<TestDescAndFn> {
+        let retried_test = retried_tests.get_mut(&completed_test.id)?;
+        let failed = matches!(completed_test.result, TrFailed | TrFailedMsg(_) | TrTimedFail);
+
+        if failed && retried_test.failed_attempts < opts.retries {
+            retried_test.failed_attempts += 1;
+            let stdout = &mut retried_test.stdout;
+            writeln!(stdout, "---- attempt {} ----", retried_test.failed_attempts).unwrap();
+            stdout.append(&mut completed_test.stdout);
+            if let TrFailedMsg(ref msg) = completed_test.result {
+                writeln!(stdout, "note: {msg}").unwrap();
+            }
+            let testfn = retried_test.testfn.try_clone().unwrap();
+            return Some(TestDescAndFn { desc: completed_test.desc.clone(), testfn });
+        }
+
+        let RetriedTest { failed_attempts, mut stdout, .. } =
+            retried_tests.remove(&completed_test.id).unwrap();
+        if failed_attempts > 0 {
+            writeln!(stdout, "---- attempt {} ----", failed_attempts + 1).unwrap();
+            stdout.append(&mut completed_test.stdout);
+            completed_test.stdout = stdout;
+            if completed_test.result == TrOk {
+                completed_test.result = TrFlaky(failed_attempts);
+            }
+        }
+        None
+    }
+
     if concurrency == 1 {
         while !remaining.is_empty() {
-            let (id, test) = remaining.pop_front().unwrap();
+            let (id, mut test) = remaining.pop_front().unwrap();
             let event = TestEvent::TeWait(test.desc.clone());
             notify_about_test_event(event)?;
-            let join_handle = run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
-            // Wait for the test to complete.
-            let mut completed_test = rx.recv().unwrap();
-            RunningTest { join_handle }.join(&mut completed_test);
+            register_retries(opts, &mut retried_tests, id, &test);
+            let mut completed_test = loop {
+                let join_handle =
+                    run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
+                // Wait for the test to complete.
+                let mut completed_test = rx.recv().unwrap();
+                RunningTest { join_handle }.join(&mut completed_test);
+
+                match retry_failed_test(opts, &mut retried_tests, &mut completed_test) {
+                    Some(retried_test) => test = retried_test,
+                    None => break completed_test,
+                }
+            };
+            completed_test.quarantined = is_quarantined(opts, &completed_test.desc);
 
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
-                TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
+                TrFailed | TrFailedMsg(_) | TrTimedFail => {
+                    opts.fail_fast && !completed_test.quarantined
+                }
             };
 
             let event = TestEvent::TeResult(completed_test);
@@ -409,6 +527,7 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
 
                 let event = TestEvent::TeWait(desc.clone());
                 notify_about_test_event(event)?; //here no pad
+                register_retries(opts, &mut retried_tests, id, &test);
                 let join_handle =
                     run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
                 running_tests.insert(id, RunningTest { join_handle });
@@ -441,12 +560,28 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
             }
 
             let mut completed_test = res.unwrap();
-            let running_test = running_tests.remove(&completed_test.id).unwrap();
+            let id = completed_test.id;
+            let running_test = running_tests.remove(&id).unwrap();
             running_test.join(&mut completed_test);
 
+            if let Some(test) = retry_failed_test(opts, &mut retried_tests, &mut completed_test) {
+                // The test stays pending, and is not reported until its last attempt.
+                let timeout = time::get_default_test_timeout();
+                let desc = test.desc.clone();
+                let join_handle =
+                    run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
+                running_tests.insert(id, RunningTest { join_handle });
+                timeout_queue.retain(|entry| entry.id != id);
+                timeout_queue.push_back(TimeoutEntry { id, desc, timeout });
+                continue;
+            }
+            completed_test.quarantined = is_quarantined(opts, &completed_test.desc);
+
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
-                TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
+                TrFailed | TrFailedMsg(_) | TrTimedFail => {
+                    opts.fail_fast && !completed_test.quarantined
+                }
             };
 
             let event = TestEvent::TeResult(completed_test);
@@ -478,25 +613,32 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
     Ok(())
 }
 
+fn matches_filter(opts: &TestOpts, desc: &TestDesc, filter: &str) -> bool {
+    let test_name = desc.name.as_slice();
+
+    match opts.filter_exact {
+        true => test_name == filter,
+        false => test_name.contains(filter),
+    }
+}
+
+fn is_quarantined(opts: &TestOpts, desc: &TestDesc) -> bool {
+    opts.quarantine.iter().any(|filter| matches_filter(opts, desc, filter))
+}
+
 pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescAndFn> {
     let mut filtered = tests;
-    let matches_filter = |test: &TestDescAndFn, filter: &str| {
-        let test_name = test.desc.name.as_slice();
-
-        match opts.filter_exact {
-            true => test_name == filter,
-            false => test_name.contains(filter),
-        }
-    };
 
     // Remove tests that don't match the test filter
     if !opts.filters.is_empty() {
-        filtered.retain(|test| opts.filters.iter().any(|filter| matches_filter(test, filter)));
+        filtered.retain(|test| {
+            opts.filters.iter().any(|filter| matches_filter(opts, &test.desc, filter))
+        });
     }
 
     // Skip tests that match any of the skip filters
     if !opts.skip.is_empty() {
-        filtered.retain(|test| !opts.skip.iter().any(|sf| matches_filter(test, sf)));
+        filtered.retain(|test| !opts.skip.iter().any(|sf| matches_filter(opts, &test.desc, sf)));
     }
 
     // Excludes #[should_panic] tests
@@ -516,6 +658,11 @@ pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescA
         RunIgnored::No => {}
     }
 
+    // Keep the tests of the requested shard
+    if let Some(ref shard) = opts.shard {
+        shard_tests(shard, &mut filtered);
+    }
+
     filtered
 }
 
@@ -567,23 +714,32 @@ pub fn run_test(
             let name = desc.name.clone();
             let nocapture = opts.nocapture;
             let time_options = opts.time_options;
+            // Structured formats always report how long each test took, and the durations
+            // are recorded for `--partition-by-time`.
+            let report_time = time_options.is_some()
+                || ((matches!(opts.format, OutputFormat::Json | OutputFormat::Junit)
+                    || opts.partition_by_time.is_some())
+                    && !cfg!(target_family = "wasm")
+                    && !cfg!(miri));
             let bench_benchmarks = opts.bench_benchmarks;
+            let fixtures = opts.options.fixtures;
 
             let runtest = move || match strategy {
                 RunStrategy::InProcess => run_test_in_process(
                     id,
                     desc,
                     nocapture,
-                    time_options.is_some(),
+                    report_time,
                     runnable_test,
                     monitor_ch,
                     time_options,
+                    fixtures,
                 ),
                 RunStrategy::SpawnPrimary => spawn_test_subprocess(
                     id,
                     desc,
                     nocapture,
-                    time_options.is_some(),
+                    report_time,
                     monitor_ch,
                     time_options,
                     bench_benchmarks,
@@ -638,6 +794,7 @@ fn run_test_in_process(
     runnable_test: RunnableTest,
     monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
+    fixtures: &[&TestFixture],
 ) {
     // Buffer for capturing standard I/O
     let data = Arc::new(Mutex::new(Vec::new()));
@@ -647,7 +804,9 @@ fn run_test_in_process(
     }
 
     let start = report_time.then(Instant::now);
-    let result = fold_err(catch_unwind(AssertUnwindSafe(|| runnable_test.run())));
+    let result = fold_err(catch_unwind(AssertUnwindSafe(|| {
+        fixture::run_with_fixtures(fixtures, &desc, || runnable_test.run())
+    })));
     let exec_time = start.map(|start| {
         let duration = start.elapsed();
         TestExecTime(duration)
@@ -736,8 +895,13 @@ fn spawn_test_subprocess(
     monitor_ch.send(message).unwrap();
 }
 
-fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -> ! {
+fn run_test_in_spawned_subprocess(
+    desc: TestDesc,
+    runnable_test: RunnableTest,
+    fixtures: &[&TestFixture],
+) -> ! {
     let builtin_panic_hook = panic::take_hook();
+    let fixture_desc = desc.clone();
     let record_result = Arc::new(move |panic_info: Option<&'_ PanicInfo<'_>>| {
         let test_result = match panic_info {
             Some(info) => calc_result(&desc, Err(info.payload()), &None, &None),
@@ -754,6 +918,9 @@ fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -
             builtin_panic_hook(info);
         }
 
+        // The process exits below, so nothing would be dropped otherwise.
+        fixture::teardown_subprocess_fixtures();
+
         if let TrOk = test_result {
             process::exit(test_result::TR_OK);
         } else {
@@ -762,6 +929,7 @@ fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -
     });
     let record_result2 = record_result.clone();
     panic::set_hook(Box::new(move |info| record_result2(Some(info))));
+    fixture::setup_subprocess_fixtures(fixtures, &fixture_desc);
     if let Err(message) = runnable_test.run() {
         panic!("{}", message);
     }
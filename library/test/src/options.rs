@@ -1,5 +1,10 @@
 //! Enums denoting options for test execution.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::fixture::TestFixture;
+
 /// Number of times to run a benchmarked function
 #[derive(Clone, PartialEq, Eq)]
 pub enum BenchMode {
@@ -47,6 +52,17 @@ pub enum RunIgnored {
     Only,
 }
 
+/// The part of the tests to run, when they are split in several shards, e.g. to run them
+/// on different machines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestShard {
+    /// Index of the shard to run, starting from 0.
+    pub index: usize,
+    pub total: usize,
+    /// Durations of the tests in a previous run, used to balance the shards.
+    pub timings: Option<HashMap<String, Duration>>,
+}
+
 #[derive(Clone, Copy)]
 pub enum RunStrategy {
     /// Runs the test in the current process, and sends the result back over the
@@ -65,11 +81,13 @@ pub enum RunStrategy {
 pub struct Options {
     pub display_output: bool,
     pub panic_abort: bool,
+    /// The fixtures registered with `#[test_fixture]`.
+    pub fixtures: &'static [&'static TestFixture],
 }
 
 impl Options {
     pub fn new() -> Options {
-        Options { display_output: false, panic_abort: false }
+        Options { display_output: false, panic_abort: false, fixtures: &[] }
     }
 
     pub fn display_output(mut self, display_output: bool) -> Options {
@@ -81,4 +99,9 @@ pub fn panic_abort(mut self, panic_abort: bool) -> Options {
         self.panic_abort = panic_abort;
         self
     }
+
+    pub fn fixtures(mut self, fixtures: &'static [&'static TestFixture]) -> Options {
+        self.fixtures = fixtures;
+        self
+    }
 }
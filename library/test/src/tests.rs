@@ -27,6 +27,7 @@
     },
     time::{TestTimeOptions, TimeThreshold},
 };
+use std::collections::HashMap;
 use std::sync::mpsc::channel;
 use std::time::Duration;
 
@@ -49,6 +50,10 @@ fn new() -> TestOpts {
             shuffle_seed: None,
             test_threads: None,
             skip: vec![],
+            retries: 0,
+            quarantine: vec![],
+            shard: None,
+            partition_by_time: None,
             time_options: None,
             options: Options::new(),
             fail_fast: false,
@@ -748,6 +753,64 @@ pub fn order_depends_on_more_than_seed() {
     assert!(left.iter().zip(right).any(|(a, b)| a.0 != b.0));
 }
 
+fn shard_names(shard: &TestShard) -> Vec<String> {
+    let mut tests = sample_tests();
+    helpers::shard::shard_tests(shard, &mut tests);
+    tests.into_iter().map(|test| test.desc.name.to_string()).collect()
+}
+
+#[test]
+pub fn shards_run_each_test_once() {
+    let mut sharded: Vec<String> = (0..3)
+        .flat_map(|index| shard_names(&TestShard { index, total: 3, timings: None }))
+        .collect();
+    sharded.sort();
+
+    let mut all: Vec<String> =
+        sample_tests().iter().map(|test| test.desc.name.to_string()).collect();
+    all.sort();
+    assert_eq!(sharded, all);
+
+    let shard = TestShard { index: 1, total: 3, timings: None };
+    assert_eq!(shard_names(&shard), shard_names(&shard));
+}
+
+#[test]
+pub fn shards_partitioned_by_time() {
+    let timings: HashMap<String, Duration> = [
+        ("sha1::test", 10),
+        ("isize::test_to_str", 4),
+        ("isize::test_pow", 3),
+        ("test::sort_tests", 3),
+    ]
+    .into_iter()
+    .map(|(name, secs)| (name.to_string(), Duration::from_secs(secs)))
+    .collect();
+    // The 7 other tests take the average of 5 seconds, for a total of 55 seconds.
+    let shard = |index| TestShard { index, total: 2, timings: Some(timings.clone()) };
+
+    let first = shard_names(&shard(0));
+    let second = shard_names(&shard(1));
+    assert_eq!(first.len() + second.len(), sample_tests().len());
+    assert!(first.contains(&"sha1::test".to_string()));
+    let duration = |names: &[String]| -> u64 {
+        names.iter().map(|name| timings.get(name).map_or(5, |d| d.as_secs())).sum()
+    };
+    assert_eq!(duration(&first), 28);
+    assert_eq!(duration(&second), 27);
+}
+
+#[test]
+fn parse_shard_options() {
+    let args = |shard_args: &[&str]| {
+        let mut args = vec!["progname".to_string(), "-Zunstable-options".to_string()];
+        args.extend(shard_args.iter().map(|arg| arg.to_string()));
+        args
+    };
+    assert!(parse_opts(&args(&["--shard-index", "1"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--shard-index", "2", "--shard-total", "2"])).unwrap().is_err());
+}
+
 #[test]
 pub fn test_metricmap_compare() {
     let mut m1 = MetricMap::new();
@@ -882,6 +945,8 @@ fn should_sort_failures_before_printing_them() {
         total: 0,
         passed: 0,
         failed: 0,
+        flaky: 0,
+        quarantined: 0,
         ignored: 0,
         filtered_out: 0,
         measured: 0,
@@ -892,6 +957,8 @@ fn should_sort_failures_before_printing_them() {
         not_failures: Vec::new(),
         ignores: Vec::new(),
         time_failures: Vec::new(),
+        flakes: Vec::new(),
+        quarantined_failures: Vec::new(),
     };
 
     out.write_failures(&st).unwrap();
@@ -939,3 +1006,162 @@ fn f(_: &mut Bencher) -> Result<(), String> {
     let result = rx.recv().unwrap().result;
     assert_eq!(result, TrFailed);
 }
+
+fn desc_for_retries(name: &'static str, testfn: TestFn) -> TestDescAndFn {
+    TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        },
+        testfn,
+    }
+}
+
+fn run_tests_for_retries(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<CompletedTest> {
+    let (tx, rx) = channel();
+    let notify = move |event: TestEvent| {
+        if let TestEvent::TeResult(result) = event {
+            tx.send(result).unwrap();
+        }
+        Ok(())
+    };
+    run_tests(opts, tests, notify).unwrap();
+    rx.iter().collect()
+}
+
+#[test]
+fn test_retries_report_flaky_test() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+    fn f() -> Result<(), String> {
+        if ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2 { Err("flaky".into()) } else { Ok(()) }
+    }
+
+    let opts = TestOpts { run_tests: true, retries: 3, ..TestOpts::new() };
+    let completed = run_tests_for_retries(&opts, vec![desc_for_retries("flaky", StaticTestFn(f))]);
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].result, TrFlaky(2));
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_retries_report_last_failure() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+    fn f() -> Result<(), String> {
+        ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+        Err("always fails".into())
+    }
+
+    let opts = TestOpts { run_tests: true, retries: 2, ..TestOpts::new() };
+    let completed = run_tests_for_retries(&opts, vec![desc_for_retries("fails", StaticTestFn(f))]);
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].result, TrFailed);
+    assert!(!completed[0].quarantined);
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_quarantined_failure() {
+    fn f() -> Result<(), String> {
+        Err("always fails".into())
+    }
+
+    let opts =
+        TestOpts { run_tests: true, quarantine: vec!["quarantined".into()], ..TestOpts::new() };
+    let tests = vec![
+        desc_for_retries("quarantined", StaticTestFn(f)),
+        desc_for_retries("not::this::one", StaticTestFn(f)),
+    ];
+    let mut completed = run_tests_for_retries(&opts, tests);
+    completed.sort_by_key(|test| test.desc.name.as_slice().to_owned());
+    assert_eq!(completed.len(), 2);
+    assert!(!completed[0].quarantined);
+    assert!(completed[1].quarantined);
+    assert_eq!(completed[1].result, TrFailed);
+}
+
+#[test]
+fn parse_retries_flag_requires_unstable_options() {
+    let args = vec!["progname".to_string(), "--retries".to_string(), "2".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec!["progname".to_string(), "--quarantine".to_string(), "name".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn test_fixtures_run_around_tests() {
+    use std::sync::Mutex;
+
+    static LOG: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    struct Guard(&'static str);
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            LOG.lock().unwrap().push(self.0);
+        }
+    }
+
+    fn global() -> Guard {
+        LOG.lock().unwrap().push("setup global");
+        Guard("teardown global")
+    }
+    fn module() -> Guard {
+        LOG.lock().unwrap().push("setup module");
+        Guard("teardown module")
+    }
+    static FIXTURES: &[&TestFixture] = &[
+        &TestFixture { scope: FixtureScope::Global, setup: || fixture_guard(global()) },
+        &TestFixture { scope: FixtureScope::Module("module"), setup: || fixture_guard(module()) },
+    ];
+
+    fn passes() -> Result<(), String> {
+        LOG.lock().unwrap().push("passes");
+        Ok(())
+    }
+    fn panics() -> Result<(), String> {
+        LOG.lock().unwrap().push("panics");
+        panic!("test failed")
+    }
+
+    let opts = TestOpts {
+        run_tests: true,
+        test_threads: Some(1),
+        options: Options::new().fixtures(FIXTURES),
+        ..TestOpts::new()
+    };
+    let tests = vec![
+        desc_for_retries("module::passes", StaticTestFn(passes)),
+        desc_for_retries("module::panics", StaticTestFn(panics)),
+        desc_for_retries("module_not_included", StaticTestFn(passes)),
+    ];
+    let completed = run_tests_for_retries(&opts, tests);
+    assert_eq!(completed.len(), 3);
+    assert_eq!(completed[1].result, TrFailed);
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        [
+            "setup global",
+            "setup module",
+            "passes",
+            "teardown module",
+            "setup module",
+            "panics",
+            "teardown module",
+            "passes",
+            "teardown global",
+        ]
+    );
+}
@@ -68,6 +68,11 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
             && let LitKind::Float(sym, lit_float_ty) = lit.node
         {
             let sym_str = sym.as_str();
+            // Hexadecimal literals like `0x1.8p3` spell out the exact bits of the
+            // value, and there is no decimal form to suggest for them.
+            if sym_str.starts_with("0x") {
+                return;
+            }
             let formatter = FloatFormat::new(sym_str);
             // Try to bail out if the float is for sure fine.
             // If its within the 2 decimal digits of being out of precision we
@@ -76,8 +81,10 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
             let digits = count_digits(sym_str);
             let max = max_digits(fty);
             let type_suffix = match lit_float_ty {
+                LitFloatType::Suffixed(ast::FloatTy::F16) => Some("f16"),
                 LitFloatType::Suffixed(ast::FloatTy::F32) => Some("f32"),
                 LitFloatType::Suffixed(ast::FloatTy::F64) => Some("f64"),
+                LitFloatType::Suffixed(ast::FloatTy::F128) => Some("f128"),
                 LitFloatType::Unsuffixed => None,
             };
             let (is_whole, is_inf, mut float_str) = match fty {
@@ -91,6 +98,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
 
                     (value.fract() == 0.0, value.is_infinite(), formatter.format(value))
                 },
+                // The host can't represent these yet.
+                FloatTy::F16 | FloatTy::F128 => return,
             };
 
             if is_inf {
@@ -135,8 +144,10 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
 #[must_use]
 fn max_digits(fty: FloatTy) -> u32 {
     match fty {
+        FloatTy::F16 => 3,
         FloatTy::F32 => f32::DIGITS,
         FloatTy::F64 => f64::DIGITS,
+        FloatTy::F128 => 33,
     }
 }
 
@@ -176,6 +176,8 @@ fn check_self_in_format_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>,
                     FormatTrait::Debug => sym::Debug,
                     FormatTrait::LowerExp => sym!(LowerExp),
                     FormatTrait::UpperExp => sym!(UpperExp),
+                    FormatTrait::LowerHexFloat => sym!(LowerHexFloat),
+                    FormatTrait::UpperHexFloat => sym!(UpperHexFloat),
                     FormatTrait::Octal => sym!(Octal),
                     FormatTrait::Pointer => sym::Pointer,
                     FormatTrait::Binary => sym!(Binary),
@@ -0,0 +1,12 @@
+#![feature(f16)]
+#![feature(f128)]
+
+/// Tests that constant evaluation doesn't panic on `f16` and `f128` literals,
+/// suffixed or not.
+fn main() {
+    let a = 1.5_f16 * 2.0;
+    let b: f16 = 1.5;
+    let c = 1.5_f128 * -1.0;
+    let d: f128 = 1.5;
+    let _ = (a, b, c, d);
+}
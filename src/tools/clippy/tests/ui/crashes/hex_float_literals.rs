@@ -0,0 +1,12 @@
+#![feature(hex_float_literals)]
+#![warn(clippy::excessive_precision, clippy::lossy_float_literal)]
+
+/// Tests that hexadecimal float literals, which the host's `FromStr` doesn't
+/// accept, don't make the float literal lints panic.
+fn main() {
+    let a = 0x1.8p3;
+    let b = 0x1p-2f32;
+    let c: f64 = 0x1.fffffffffffffp1023;
+    let d = 0x1.0000000000001_8p0f64;
+    let _ = (a, b, c, d);
+}
@@ -64,6 +64,7 @@ enum Op {
                             match float_ty {
                                 FloatTy::F32 => Scalar::from_f32(op.to_f32()?.abs()),
                                 FloatTy::F64 => Scalar::from_f64(op.to_f64()?.abs()),
+                                FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
                             }
                         }
                         Op::Sqrt => {
@@ -82,6 +83,7 @@ enum Op {
                                     let res = f.sqrt();
                                     Scalar::from_u64(res.to_bits())
                                 }
+                                FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
                             }
                         }
                         Op::Round(rounding) => {
@@ -99,6 +101,7 @@ enum Op {
                                     let res = f.round_to_integral(rounding).value;
                                     Scalar::from_f64(res)
                                 }
+                                FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
                             }
                         }
                     };
@@ -255,6 +258,7 @@ enum Op {
                             let res = a.mul_add(b, c);
                             Scalar::from_u64(res.to_bits())
                         }
+                        FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
                     };
                     this.write_scalar(val, &dest)?;
                 }
@@ -655,6 +659,7 @@ fn fmax_op<'tcx>(
     Ok(match float_ty {
         FloatTy::F32 => Scalar::from_f32(left.to_f32()?.max(right.to_f32()?)),
         FloatTy::F64 => Scalar::from_f64(left.to_f64()?.max(right.to_f64()?)),
+        FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
     })
 }
 
@@ -669,5 +674,6 @@ fn fmin_op<'tcx>(
     Ok(match float_ty {
         FloatTy::F32 => Scalar::from_f32(left.to_f32()?.min(right.to_f32()?)),
         FloatTy::F64 => Scalar::from_f64(left.to_f64()?.min(right.to_f64()?)),
+        FloatTy::F16 | FloatTy::F128 => unimplemented!("f16_f128"),
     })
 }
@@ -0,0 +1,62 @@
+// Checks that `f16` and `f128` are lowered to LLVM's `half` and `fp128` types.
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(f16)]
+#![feature(f128)]
+
+// CHECK-LABEL: half @f16_add(half{{.*}} %a, half{{.*}} %b)
+#[no_mangle]
+pub fn f16_add(a: f16, b: f16) -> f16 {
+    // CHECK: fadd half %a, %b
+    a + b
+}
+
+// CHECK-LABEL: fp128 @f128_mul(fp128{{.*}} %a, fp128{{.*}} %b)
+#[no_mangle]
+pub fn f128_mul(a: f128, b: f128) -> f128 {
+    // CHECK: fmul fp128 %a, %b
+    a * b
+}
+
+// CHECK-LABEL: @f16_to_f32
+#[no_mangle]
+pub fn f16_to_f32(a: f16) -> f32 {
+    // CHECK: fpext half %a to float
+    a as f32
+}
+
+// CHECK-LABEL: @f128_to_f64
+#[no_mangle]
+pub fn f128_to_f64(a: f128) -> f64 {
+    // CHECK: fptrunc fp128 %a to double
+    a as f64
+}
+
+// CHECK-LABEL: @f16_bits
+#[no_mangle]
+pub fn f16_bits(a: f16) -> u16 {
+    // CHECK: bitcast half %a to i16
+    unsafe { std::mem::transmute(a) }
+}
+
+// CHECK-LABEL: @f128_bits
+#[no_mangle]
+pub fn f128_bits(a: f128) -> u128 {
+    // CHECK: bitcast fp128 %a to i128
+    unsafe { std::mem::transmute(a) }
+}
+
+// CHECK-LABEL: @f16_one
+#[no_mangle]
+pub fn f16_one() -> f16 {
+    // CHECK: ret half 0xH3C00
+    1.0
+}
+
+// CHECK-LABEL: @f128_one
+#[no_mangle]
+pub fn f128_one() -> f128 {
+    // CHECK: ret fp128 0xL00000000000000003FFF000000000000
+    1.0
+}
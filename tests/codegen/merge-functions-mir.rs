@@ -0,0 +1,40 @@
+// Checks that instances of a generic function with the same MIR are merged
+// into aliases of one of them before codegen. LLVM passes are disabled as
+// GlobalOpt replaces uses of internal aliases with their aliasee.
+//
+// compile-flags: -O -C no-prepopulate-passes -Z merge-functions=mir -C codegen-units=1
+
+#![crate_type = "lib"]
+
+#[inline(never)]
+fn double_len<T>(v: &Vec<T>) -> usize {
+    v.len() * 2
+}
+
+#[inline(never)]
+fn is_negative<T: Into<i64>>(x: T) -> bool {
+    x.into() < 0
+}
+
+// CHECK: @{{.*}}10double_len{{.*}} = internal alias {{.*}} @{{.*}}10double_len
+// CHECK-NOT: @{{.*}}11is_negative{{.*}} = {{.*}}alias
+
+pub fn double_len_u32(v: &Vec<u32>) -> usize {
+    double_len(v)
+}
+
+pub fn double_len_i32(v: &Vec<i32>) -> usize {
+    double_len(v)
+}
+
+// `u32` and `i32` are converted into `i64` differently, so these stay apart.
+pub fn is_negative_u32(x: u32) -> bool {
+    is_negative(x)
+}
+
+pub fn is_negative_i32(x: i32) -> bool {
+    is_negative(x)
+}
+
+// CHECK: define internal {{.*}}10double_len
+// CHECK-NOT: define {{.*}}10double_len
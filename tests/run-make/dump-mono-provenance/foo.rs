@@ -0,0 +1,12 @@
+fn wrap<T>(value: T) -> Option<T> {
+    Some(value)
+}
+
+fn outer<T>(value: T) -> Option<T> {
+    wrap(value)
+}
+
+pub fn run() {
+    outer(1u32);
+    outer(1u64);
+}
@@ -0,0 +1,12 @@
+#[inline(never)]
+fn generic<T: Copy>(values: &[T]) -> Option<T> {
+    values.iter().rev().copied().next()
+}
+
+pub fn last_u32(values: &[u32]) -> Option<u32> {
+    generic(values)
+}
+
+pub fn last_u64(values: &[u64]) -> Option<u64> {
+    generic(values)
+}
@@ -0,0 +1,20 @@
+#!/usr/bin/env python
+
+import sys
+import json
+
+with open(sys.argv[1]) as f:
+    report = json.load(f)
+
+assert report["attributed_bytes"] > 0, report["attributed_bytes"]
+assert report["attributed_bytes"] <= report["total_bytes"], report
+
+instances = {instance["name"]: instance for instance in report["instances"]}
+generic_instances = ["fn generic::<u32>", "fn generic::<u64>"]
+for name in generic_instances:
+    assert name in instances, sorted(instances)
+    assert instances[name]["bytes"] > 0, instances[name]
+
+[generic] = [function for function in report["functions"] if function["name"] == "foo::generic"]
+assert generic["instances"] == 2, generic
+assert generic["bytes"] == sum(instances[name]["bytes"] for name in generic_instances), generic
@@ -0,0 +1,11 @@
+pub mod changed {
+    pub fn changed_fn() -> u32 {
+        1
+    }
+}
+
+pub mod unchanged {
+    pub fn unchanged_fn() -> u32 {
+        2
+    }
+}
@@ -0,0 +1,21 @@
+#!/usr/bin/env python
+
+import sys
+import json
+
+# `json.load` rejects anything after the first document, so this fails if
+# several logs were printed.
+with open(sys.argv[1]) as f:
+    log = json.load(f)
+
+assert log["version"] == "2.1.0", log["version"]
+[run] = log["runs"]
+assert run["tool"]["driver"]["name"] == "rustc"
+
+results = run["results"]
+# The early warning about `-C remark` without debuginfo.
+assert any(
+    r["level"] == "warning" and "-C remark" in r["message"]["text"] for r in results
+), results
+# The type error reported by the session.
+assert any(r["level"] == "error" and r.get("ruleId") == "E0308" for r in results), results
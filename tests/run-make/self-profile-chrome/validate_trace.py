@@ -0,0 +1,20 @@
+#!/usr/bin/env python
+
+import sys
+import json
+import os
+
+# The trace is written next to the raw event data, as `<crate>-<pid>.json`.
+directory = sys.argv[1]
+[trace] = [name for name in os.listdir(directory) if name.endswith(".json")]
+assert trace.startswith("lib-"), trace
+
+with open(os.path.join(directory, trace)) as f:
+    events = json.load(f)["traceEvents"]
+
+queries = [e for e in events if e["ph"] == "X" and e["cat"] == "Query"]
+assert queries, "no query events"
+for event in queries:
+    assert event["dur"] >= 0, event
+# With `-Z self-profile-events=query-keys`, the key of each query is its argument.
+assert any(e["name"] == "typeck" and "add" in e["args"].get("arg0", "") for e in queries), queries
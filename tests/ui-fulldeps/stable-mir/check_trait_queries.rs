@@ -0,0 +1,139 @@
+// run-pass
+//! Test that users are able to solve trait and type relating queries using stable mir APIs.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+// edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+#![feature(control_flow_enum)]
+
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate stable_mir;
+
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::ty::{
+    ClauseKind, GenericArgs, ParamEnv, ProjectionPredicate, RigidTy, TermKind, TraitDef, Ty, TyKind,
+};
+use stable_mir::{CrateDef, CrateItem, CrateItems};
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "input";
+
+/// This function uses the Stable MIR APIs to get information about the test crate.
+fn test_stable_mir(_tcx: TyCtxt<'_>) -> ControlFlow<()> {
+    let items = stable_mir::all_local_items();
+    let foo = *stable_mir::all_trait_decls()
+        .iter()
+        .find(|trait_def| trait_def.trimmed_name() == "Foo")
+        .unwrap();
+
+    test_concrete(foo, get_item(&items, "concrete").unwrap());
+    test_generic(foo, get_item(&items, "generic").unwrap());
+
+    ControlFlow::Continue(())
+}
+
+/// Check queries that do not depend on where clauses: `concrete(_x: &u8, _y: u16)`.
+fn test_concrete(foo: TraitDef, item: &CrateItem) {
+    let body = item.body();
+    let ref_ty = arg_ty(&body, 0);
+    let TyKind::RigidTy(RigidTy::Ref(_, u8_ty, _)) = ref_ty.kind() else { unreachable!() };
+    let u16_ty = arg_ty(&body, 1);
+    let no_args = GenericArgs(vec![]);
+    let empty = ParamEnv::empty();
+
+    assert!(u8_ty.implements_trait(foo, &no_args, &empty));
+    assert!(!u16_ty.implements_trait(foo, &no_args, &empty));
+    assert!(!ref_ty.implements_trait(foo, &no_args, &empty));
+
+    assert!(ref_ty.is_subtype(ref_ty, &empty));
+    assert!(!ref_ty.is_subtype(u8_ty, &empty));
+    assert!(!u8_ty.is_subtype(u16_ty, &empty));
+
+    assert_eq!(u8_ty.normalize(&empty).unwrap(), u8_ty);
+}
+
+/// Check queries that require the item where clauses: `generic<T: Foo>`.
+fn test_generic(foo: TraitDef, item: &CrateItem) {
+    let body = item.body();
+    let param_ty = arg_ty(&body, 0);
+    let projection_ty = arg_ty(&body, 1);
+    let u32_ty = arg_ty(&body, 2);
+    let no_args = GenericArgs(vec![]);
+
+    assert!(!param_ty.implements_trait(foo, &no_args, &ParamEnv::empty()));
+
+    let mut param_env = ParamEnv::for_def(item.0);
+    assert!(param_ty.implements_trait(foo, &no_args, &param_env));
+
+    let predicates = stable_mir::trait_decl(&foo).predicates_of();
+    assert_eq!(ParamEnv::from_predicates(&predicates).caller_bounds.len(), 1);
+
+    // Without more information, the projection cannot be normalized any further.
+    let TyKind::Alias(_, alias) = projection_ty.kind() else { unreachable!() };
+    assert_eq!(projection_ty.normalize(&param_env).unwrap(), projection_ty);
+    assert!(!u32_ty.is_subtype(projection_ty, &param_env));
+
+    param_env.caller_bounds.push(ClauseKind::Projection(ProjectionPredicate {
+        projection_ty: alias,
+        term: TermKind::Type(u32_ty),
+    }));
+    assert_eq!(projection_ty.normalize(&param_env).unwrap(), u32_ty);
+    assert!(u32_ty.is_subtype(projection_ty, &param_env));
+}
+
+fn arg_ty(body: &stable_mir::mir::Body, index: usize) -> Ty {
+    body.arg_locals()[index].ty
+}
+
+fn get_item<'a>(items: &'a CrateItems, name: &str) -> Option<&'a CrateItem> {
+    items.iter().find(|item| item.trimmed_name() == name)
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler.
+fn main() {
+    let path = "trait_queries_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    run!(args, tcx, test_stable_mir(tcx)).unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        pub trait Foo {{
+            type Assoc;
+        }}
+
+        impl Foo for u8 {{
+            type Assoc = u16;
+        }}
+
+        pub fn concrete(_x: &u8, _y: u16) {{}}
+
+        pub fn generic<T: Foo>(_t: T, _assoc: T::Assoc, _u: u32) {{}}
+        "#
+    )?;
+    Ok(())
+}
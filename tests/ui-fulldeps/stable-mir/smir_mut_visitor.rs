@@ -0,0 +1,149 @@
+// run-pass
+//! Sanity check the Stable MIR mutable visitor and body modification APIs.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+// edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+#![feature(control_flow_enum)]
+
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate stable_mir;
+
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::mir::{MirVisitor, MutMirVisitor};
+use stable_mir::*;
+use std::assert_matches::assert_matches;
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "input";
+
+fn test_mut_visitor(_tcx: TyCtxt<'_>) -> ControlFlow<()> {
+    let main_fn = stable_mir::entry_fn().unwrap();
+    let mut body = main_fn.body();
+    let num_blocks = body.blocks.len();
+    let num_stmts = body.blocks[0].statements.len();
+    assert!(num_stmts > 0);
+
+    // Introduce a new local to replace the return local with.
+    let ret = body.ret_local().clone();
+    let new_local = body.add_local(ret.ty, ret.span, mir::Mutability::Mut);
+    assert_eq!(new_local, body.locals().len() - 1);
+    assert_eq!(body.inner_locals().last().unwrap().ty, ret.ty);
+
+    // Split the first block in two.
+    let new_bb = body.split_block(0, 1);
+    assert_eq!(new_bb, num_blocks);
+    assert_eq!(body.blocks.len(), num_blocks + 1);
+    assert_eq!(body.blocks[0].statements.len(), 1);
+    assert_eq!(body.blocks[new_bb].statements.len(), num_stmts - 1);
+    assert_matches!(
+        body.blocks[0].terminator.kind,
+        mir::TerminatorKind::Goto { target } if target == new_bb
+    );
+
+    let mut replacer = LocalReplacer { from: mir::RETURN_LOCAL, to: new_local, replaced: 0 };
+    replacer.visit_body(&mut body);
+    assert!(replacer.replaced > 0);
+
+    // The `Return` terminator implicitly uses the return local, which is
+    // visited but can't be rewritten, so there is one less use to find.
+    let mut counter = LocalCounter { local: new_local, uses: 0 };
+    counter.visit_body(&body);
+    assert_eq!(counter.uses, replacer.replaced - 1);
+
+    // Mark the storage of the new local as live before anything else.
+    let storage_live =
+        mir::Statement { kind: mir::StatementKind::StorageLive(new_local), span: ret.span };
+    body.insert_statement(0, 0, storage_live.clone());
+    assert_eq!(body.blocks[0].statements.len(), 2);
+    assert_eq!(body.blocks[0].statements[0], storage_live);
+
+    let mut dump = Vec::new();
+    body.dump(&mut dump).unwrap();
+    let dump = String::from_utf8(dump).unwrap();
+    assert!(dump.contains(&format!("goto -> bb{new_bb}")), "Unexpected dump: {dump}");
+    assert!(dump.contains(&format!("StorageLive(_{new_local})")), "Unexpected dump: {dump}");
+    ControlFlow::Continue(())
+}
+
+/// Replaces every use of `from` in places by `to`.
+struct LocalReplacer {
+    from: mir::Local,
+    to: mir::Local,
+    replaced: usize,
+}
+
+impl mir::MutMirVisitor for LocalReplacer {
+    fn visit_local(
+        &mut self,
+        local: &mut mir::Local,
+        _ptx: mir::visit::PlaceContext,
+        _location: mir::visit::Location,
+    ) {
+        if *local == self.from {
+            *local = self.to;
+            self.replaced += 1;
+        }
+    }
+}
+
+struct LocalCounter {
+    local: mir::Local,
+    uses: usize,
+}
+
+impl mir::MirVisitor for LocalCounter {
+    fn visit_local(
+        &mut self,
+        local: &mir::Local,
+        _ptx: mir::visit::PlaceContext,
+        _location: mir::visit::Location,
+    ) {
+        if *local == self.local {
+            self.uses += 1;
+        }
+    }
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler.
+fn main() {
+    let path = "smir_mut_visitor_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "-Cpanic=abort".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    run!(args, tcx, test_mut_visitor(tcx)).unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+    fn main() -> std::process::ExitCode {{
+        let a = 1u8;
+        let b = a + 2;
+        std::process::ExitCode::from(b)
+    }}
+    "#
+    )?;
+    Ok(())
+}
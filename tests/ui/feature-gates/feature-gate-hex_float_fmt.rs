@@ -0,0 +1,4 @@
+fn main() {
+    let _ = format!("{:a}", 1.5); //~ ERROR hexadecimal float formatting is experimental
+    let _ = format!("{:A}", 1.5); //~ ERROR hexadecimal float formatting is experimental
+}
@@ -0,0 +1,4 @@
+fn main() {
+    let _ = 0x1.8p3; //~ ERROR hexadecimal float literals are experimental
+    let _ = 0x1p-2f32; //~ ERROR hexadecimal float literals are experimental
+}
@@ -0,0 +1,6 @@
+// compile-flags: --test
+
+#[test_fixture] //~ ERROR use of unstable library feature 'test_fixture'
+fn fixture() {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![feature(f16)]
+#![feature(f128)]
+#![deny(overflowing_literals)]
+
+fn main() {
+    let x = 65504f16;
+    let x = 70000f16; //~ ERROR literal out of range for `f16`
+    let x = -6.6e4_f16; //~ ERROR literal out of range for `f16`
+    let x = 1.18973149535723176508575932662800702e4932_f128;
+    let x = 1e5000f128; //~ ERROR literal out of range for `f128`
+    let x = -1.2e4932_f128; //~ ERROR literal out of range for `f128`
+}
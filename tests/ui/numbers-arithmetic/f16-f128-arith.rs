@@ -0,0 +1,31 @@
+// run-pass
+// Arithmetic and casts on `f16` and `f128` at runtime, which go through LLVM and the
+// soft-float routines of the runtime libraries.
+// only-x86_64
+// only-linux
+
+#![feature(f16)]
+#![feature(f128)]
+#![feature(test)]
+
+extern crate test;
+use test::black_box as b;
+
+fn main() {
+    let half: f16 = b(1.0) / b(2.0);
+    assert_eq!(half.to_bits(), 0.5_f16.to_bits());
+    assert_eq!((b(half) * 4.0 - 1.0).to_bits(), 1.0_f16.to_bits());
+    assert!(b(-half) < 0.0);
+    assert!((b(65504.0_f16) * 2.0).is_infinite());
+    assert_eq!(b(half) as f32, 0.5);
+    assert!(b(3u8) as f16 == 3.0);
+    assert_eq!(b(2.9_f16) as i32, 2);
+
+    let half: f128 = b(1.0) / b(2.0);
+    assert_eq!(half.to_bits(), 0.5_f128.to_bits());
+    assert_eq!(b(half) as f64, 0.5);
+    assert!(b(u128::MAX) as f128 > b(u64::MAX) as f128);
+    // `f128` keeps digits that `f64` rounds away.
+    assert!(b(1.0_f128) + b(1e-30) != 1.0);
+    assert!(b(1.0_f64) + b(1e-30) == 1.0);
+}
@@ -0,0 +1,25 @@
+// check-pass
+// Arithmetic and casts on `f16` and `f128` in constants, which go through apfloat.
+
+#![feature(f16)]
+#![feature(f128)]
+
+const HALF_F16: f16 = 1.0 / 2.0;
+const HALF_F128: f128 = 1.0 / 2.0;
+
+const _: () = assert!(HALF_F16 == 0.5);
+const _: () = assert!(HALF_F16 * 4.0 - 1.0 == 1.0);
+const _: () = assert!(-HALF_F16 < 0.0);
+const _: () = assert!(65504.0_f16 * 2.0 == f16::INFINITY);
+const _: () = assert!(HALF_F16 as f32 == 0.5);
+const _: () = assert!(3u8 as f16 == 3.0);
+const _: () = assert!(2.9_f16 as i32 == 2);
+
+const _: () = assert!(HALF_F128 == 0.5);
+const _: () = assert!(HALF_F128 as f64 == 0.5);
+const _: () = assert!(u128::MAX as f128 > u64::MAX as f128);
+// `f128` keeps digits that `f64` rounds away.
+const _: () = assert!(1.0_f128 + 1e-30 != 1.0);
+const _: () = assert!(1.0_f64 + 1e-30 == 1.0);
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![feature(hex_float_literals)]
+#![deny(overflowing_literals)]
+
+fn main() {
+    let x = 0x1.fffffep127f32;
+    let x = 0x1.ffffffp127f32; //~ ERROR literal out of range for `f32`
+    let x = -0x1p128f32; //~ ERROR literal out of range for `f32`
+    let x = 0x1.fffffffffffffp1023f64;
+    let x = 0x1p1024; //~ ERROR literal out of range for `f64`
+}
@@ -0,0 +1,14 @@
+// run-pass
+
+#![feature(hex_float_literals)]
+
+fn main() {
+    assert_eq!(0x1.8p3, 12.0);
+    assert_eq!(0x1p-2f32, 0.25f32);
+    assert_eq!(0xA.8P+1_0, 10752.0);
+    assert_eq!(-0x1.fffffep127f32, f32::MIN);
+    assert_eq!(0x1.fffffffffffffp1023, f64::MAX);
+    assert_eq!(0x1p-1074, 5e-324);
+    assert_eq!(0x1.0000000000000_8p0, 1.0);
+    assert_eq!(0x1.0000000000001_8p0, 0x1.0000000000002p0);
+}
@@ -5,5 +5,5 @@ fn main() {
     //~^ ERROR hexadecimal float literal is not supported
     0xDEAD.BEEFp-2f;
     //~^ ERROR invalid suffix `f` for float literal
-    //~| ERROR `{integer}` is a primitive type and therefore doesn't have fields
+    //~| ERROR hexadecimal float literals are experimental
 }
@@ -0,0 +1,79 @@
+// run-pass
+// no-prefer-dynamic
+// compile-flags: --test -Cpanic=abort -Zpanic_abort_tests
+// run-flags: --test-threads=1
+
+// ignore-wasm no panic or subprocess support
+// ignore-emscripten no panic or subprocess support
+// ignore-sgx no subprocess support
+
+// Each test runs in its own subprocess here: the fixtures of a test must be set
+// up in that subprocess, and torn down even if the test panics.
+
+#![feature(test_fixture)]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SET_UP: AtomicBool = AtomicBool::new(false);
+
+const DIR_VAR: &str = "TEST_FIXTURE_PANIC_ABORT_DIR";
+
+fn log_path() -> PathBuf {
+    PathBuf::from(env::var_os(DIR_VAR).unwrap()).join("teardowns")
+}
+
+struct TempDir(PathBuf);
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Runs in the primary process, and is only seen by the subprocesses through the
+// environment they inherit.
+#[test_fixture(global)]
+fn temp_dir() -> TempDir {
+    let path = env::temp_dir().join(format!("test-fixture-panic-abort-{}", std::process::id()));
+    fs::create_dir_all(&path).unwrap();
+    env::set_var(DIR_VAR, &path);
+    TempDir(path)
+}
+
+struct Logged;
+
+impl Drop for Logged {
+    fn drop(&mut self) {
+        SET_UP.store(false, Ordering::SeqCst);
+        let mut log = fs::read_to_string(log_path()).unwrap_or_default();
+        log.push_str("torn down\n");
+        fs::write(log_path(), log).unwrap();
+    }
+}
+
+#[test_fixture]
+fn logged() -> Logged {
+    SET_UP.store(true, Ordering::SeqCst);
+    Logged
+}
+
+// The tests run in the order of their names.
+
+#[test]
+fn a_fixture_is_set_up_in_the_subprocess() {
+    assert!(SET_UP.load(Ordering::SeqCst));
+}
+
+#[test]
+#[should_panic]
+fn b_fixture_is_torn_down_after_panic() {
+    panic!();
+}
+
+#[test]
+fn c_fixtures_were_torn_down() {
+    assert_eq!(fs::read_to_string(log_path()).unwrap(), "torn down\ntorn down\n");
+}
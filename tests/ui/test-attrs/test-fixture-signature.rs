@@ -0,0 +1,21 @@
+// compile-flags: --test
+
+#![feature(test_fixture)]
+#![allow(dead_code)]
+
+#[test_fixture]
+fn ok() -> String {
+    String::new()
+}
+
+#[test_fixture]
+fn with_args(_val: i32) {} //~ ERROR functions used as test fixtures must have signature `fn() -> T`
+
+#[test_fixture]
+fn type_generic<T>() {} //~ ERROR functions used as test fixtures must have signature `fn() -> T`
+
+#[test_fixture(local)] //~ ERROR `#[test_fixture(..)]` only accepts `global`
+fn bad_scope() {}
+
+#[test_fixture] //~ ERROR the `#[test_fixture]` attribute may only be used on a non-associated function
+struct NotAFunction;
@@ -0,0 +1,66 @@
+// run-pass
+// compile-flags: --test
+// needs-unwind
+
+#![feature(test_fixture)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static GLOBAL: AtomicBool = AtomicBool::new(false);
+static IN_DB: AtomicUsize = AtomicUsize::new(0);
+
+struct Global;
+
+impl Drop for Global {
+    fn drop(&mut self) {
+        GLOBAL.store(false, Ordering::SeqCst);
+    }
+}
+
+#[test_fixture(global)]
+fn global() -> Global {
+    assert!(!GLOBAL.swap(true, Ordering::SeqCst), "global fixtures are only set up once");
+    Global
+}
+
+#[test]
+fn global_fixture_is_set_up() {
+    assert!(GLOBAL.load(Ordering::SeqCst));
+}
+
+mod db {
+    use super::*;
+
+    pub struct Connection;
+
+    impl Drop for Connection {
+        fn drop(&mut self) {
+            IN_DB.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test_fixture]
+    fn connect() -> Connection {
+        IN_DB.fetch_add(1, Ordering::SeqCst);
+        Connection
+    }
+
+    #[test]
+    fn module_fixture_is_set_up() {
+        assert!(IN_DB.load(Ordering::SeqCst) > 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn module_fixture_is_torn_down_after_panic() {
+        assert!(IN_DB.load(Ordering::SeqCst) > 0);
+        panic!();
+    }
+
+    mod nested {
+        #[test]
+        fn applies_to_submodules() {
+            assert!(super::IN_DB.load(super::Ordering::SeqCst) > 0);
+        }
+    }
+}
@@ -1,10 +1,12 @@
 // no-prefer-dynamic
 // compile-flags: --test
 // run-flags: --list --format json
-// run-fail
+// run-pass
 // check-run-results
+// normalize-stdout-test: "fake-test-src-base/test-attrs/" -> "$$DIR/"
+// normalize-stdout-test: "fake-test-src-base\\test-attrs\\" -> "$$DIR/"
 
-// Checks that --format json does not work without -Zunstable-options.
+// Checks that --format json works without -Zunstable-options.
 
 #![cfg(test)]
 #[test]